@@ -0,0 +1,173 @@
+//! On-disk cache of preprocessed models, so that repeated runs over the same input skip parsing.
+//!
+//! Models are stored with `bincode` in files named after a fingerprint of the input and of the
+//! executable that develops them. Rebuilding the code or changing the input changes the
+//! fingerprint, so stale entries are never read and get removed the next time the cache is written.
+//!
+//! Each file starts with a header holding the version of this crate and a digest of the whole input.
+//! A header that does not match the current run, as after a fingerprint collision or a change of the
+//! file format, makes the entry stale as well and the model is developed again.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use serde::{Deserialize, Serialize};
+use serde::de::DeserializeOwned;
+
+// =========================================== TYPE AND STRUCT DEFINITIONS ===========================================
+
+type Fingerprint = u64;
+
+const FNV_OFFSET_BASIS: Fingerprint = 0xcbf29ce484222325;
+const FNV_PRIME: Fingerprint = 0x100000001b3;
+
+const CACHE_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Start of every cache file, checked against the current run before the model is read.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+struct EntryHeader {
+    version: String,
+    input_length: u64,
+    input_digest: Fingerprint
+}
+
+/// Cache of the models developed by an executable, stored in one directory.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ModelCache {
+    directory: PathBuf,
+    executable: Option<PathBuf>,
+    bypass: bool
+}
+
+// =============================================== AUXILIARY FUNCTIONS ===============================================
+
+fn fingerprint_bytes(fingerprint: Fingerprint, bytes: &[u8]) -> Fingerprint {
+
+    return bytes.iter()
+        .fold(fingerprint, |fingerprint, byte| (fingerprint ^ *byte as Fingerprint).wrapping_mul(FNV_PRIME));
+}
+
+fn develop_header(encoded_input: &[u8]) -> EntryHeader {
+    EntryHeader {
+        version: CACHE_VERSION.to_owned(),
+        input_length: encoded_input.len() as u64,
+        input_digest: fingerprint_bytes(FNV_OFFSET_BASIS, encoded_input)
+    }
+}
+
+fn develop_fingerprint(executable: Option<&Path>, encoded_input: &[u8]) -> Fingerprint {
+
+    let mut fingerprint: Fingerprint = FNV_OFFSET_BASIS;
+
+    // Any rebuild of the executable produces a new file, so its size and modification time
+    // stand in for the version of the code that produced the cached model
+    if let Some(executable) = executable {
+
+        fingerprint = fingerprint_bytes(fingerprint, executable.to_string_lossy().as_bytes());
+        if let Ok(executable_metadata) = fs::metadata(executable) {
+
+            fingerprint = fingerprint_bytes(fingerprint, &executable_metadata.len().to_le_bytes());
+            if let Ok(modified) = executable_metadata.modified() {
+                fingerprint = fingerprint_bytes(fingerprint, format!("{:?}", modified).as_bytes());
+            }
+        }
+    }
+
+    return fingerprint_bytes(fingerprint, encoded_input);
+}
+
+fn load_entry<T: DeserializeOwned>(entry_path: &Path, header: &EntryHeader) -> Option<T> {
+
+    let encoded: Vec<u8> = fs::read(entry_path).ok()?;
+    let mut reader: &[u8] = &encoded;
+
+    let entry_header: EntryHeader = bincode::deserialize_from(&mut reader).ok()?;
+    if entry_header != *header { return None }
+    return bincode::deserialize_from(reader).ok();
+}
+
+fn remove_stale_entries(cache_directory: &Path, name: &str, current_entry: &Path) {
+
+    let entries: fs::ReadDir = match fs::read_dir(cache_directory) {
+        Ok(entries) => entries,
+        Err(_) => return
+    };
+
+    for entry in entries.filter_map(|entry| entry.ok()) {
+
+        let entry_path: PathBuf = entry.path();
+        let entry_name: String = entry.file_name().to_string_lossy().into_owned();
+        if entry_path != current_entry && entry_name.starts_with(&format!("{}-", name)) && entry_name.ends_with(".bin") {
+            let _ = fs::remove_file(entry_path);
+        }
+    }
+}
+
+fn store_entry<T: Serialize>(cache_directory: &Path, name: &str, entry_path: &Path, header: &EntryHeader, model: &T) -> Option<()> {
+
+    let mut encoded: Vec<u8> = bincode::serialize(header).ok()?;
+    encoded.extend(bincode::serialize(model).ok()?);
+    fs::create_dir_all(cache_directory).ok()?;
+
+    let temporary_path: PathBuf = entry_path.with_extension("tmp");
+    fs::write(&temporary_path, encoded).ok()?;
+    fs::rename(&temporary_path, entry_path).ok()?;
+
+    remove_stale_entries(cache_directory, name, entry_path);
+    return Some(());
+}
+
+/// Directory used by the binary for its cache: `AOC_CACHE_DIR` when set, `.cache` otherwise.
+pub fn default_cache_directory() -> PathBuf {
+
+    return match std::env::var_os("AOC_CACHE_DIR") {
+        Some(directory) => PathBuf::from(directory),
+        None => PathBuf::from(".cache")
+    };
+}
+
+// ================================================= IMPLEMENTATIONS =================================================
+
+impl ModelCache {
+
+    /// Cache in `directory` for the models of the running executable.
+    pub fn new(directory: &Path) -> ModelCache {
+        ModelCache {
+            directory: directory.to_path_buf(),
+            executable: std::env::current_exe().ok(),
+            bypass: false
+        }
+    }
+
+    /// Fingerprints the models with `executable` instead of the running executable.
+    pub fn with_executable(mut self, executable: &Path) -> ModelCache {
+        self.executable = Some(executable.to_path_buf());
+        return self;
+    }
+
+    /// When `bypass` is set, models are always developed from the input, then cached for later runs.
+    pub fn with_bypass(mut self, bypass: bool) -> ModelCache {
+        self.bypass = bypass;
+        return self;
+    }
+
+    /// Loads the model cached under `name` for this input, or develops it from the input and caches it.
+    ///
+    /// The cache is only an optimization: an entry that cannot be read or written, or whose header
+    /// does not match this run, is ignored and the model is developed from the input as usual.
+    pub fn load_or_develop<I, T, F>(&self, name: &str, input: &I, develop: F) -> T
+        where I: Serialize + ?Sized, T: Serialize + DeserializeOwned, F: FnOnce(&I) -> T {
+
+        let encoded_input: Vec<u8> = bincode::serialize(input).unwrap();
+        let header: EntryHeader = develop_header(&encoded_input);
+        let fingerprint: Fingerprint = develop_fingerprint(self.executable.as_deref(), &encoded_input);
+        let entry_path: PathBuf = self.directory.join(format!("{}-{:016x}.bin", name, fingerprint));
+
+        if ! self.bypass {
+            if let Some(cached_model) = load_entry(&entry_path, &header) { return cached_model }
+        }
+
+        let model: T = develop(input);
+        store_entry(&self.directory, name, &entry_path, &header, &model);
+        return model;
+    }
+}
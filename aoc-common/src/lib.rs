@@ -0,0 +1,10 @@
+//! Support code shared by the solvers of several days.
+//!
+//! `cache` keeps preprocessed models on disk between runs of a binary, and `trace` writes the
+//! `tracing` spans of a solver as JSON lines. Each module has a feature of the same name; both
+//! are on by default, and the days only turn on the ones they use.
+
+#[cfg(feature = "cache")]
+pub mod cache;
+#[cfg(feature = "trace")]
+pub mod trace;
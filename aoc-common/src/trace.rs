@@ -0,0 +1,41 @@
+//! Subscriber writing the spans and events of the solvers as JSON lines.
+//!
+//! Only built with the `trace` feature, which the days turn on through their own. Lines carry no
+//! timestamps or thread ids, so the logs of two runs, or of two implementations of the same
+//! solver, can be compared with a plain `diff`.
+
+use std::fs::File;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use tracing::Level;
+use tracing_subscriber::fmt::format::FmtSpan;
+
+// =============================================== AUXILIARY FUNCTIONS ===============================================
+
+/// File used by the binary for its trace log: `AOC_TRACE_FILE` when set, `trace.jsonl` otherwise.
+pub fn default_trace_file() -> PathBuf {
+
+    return match std::env::var_os("AOC_TRACE_FILE") {
+        Some(file) => PathBuf::from(file),
+        None => PathBuf::from("trace.jsonl")
+    };
+}
+
+/// Installs a global subscriber writing one JSON object per line to `path`, for every span entered
+/// and every event recorded down to the `TRACE` level.
+pub fn install_json_subscriber(path: &Path) -> io::Result<()> {
+
+    let file: File = File::create(path)?;
+    let subscriber = tracing_subscriber::fmt()
+        .json()
+        .with_writer(Mutex::new(file))
+        .with_max_level(Level::TRACE)
+        .with_span_events(FmtSpan::NEW)
+        .with_span_list(false)
+        .without_time()
+        .finish();
+
+    return tracing::subscriber::set_global_default(subscriber)
+        .map_err(io::Error::other);
+}
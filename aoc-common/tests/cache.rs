@@ -0,0 +1,192 @@
+use std::cell::Cell;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tempfile::TempDir;
+
+use aoc_common::cache::ModelCache;
+
+const INPUT: [&str; 3] = ["$ cd /", "$ ls", "dir a"];
+
+/// Model developed from the input, counting how many times it had to be developed.
+fn develop_counted<'a>(developed: &'a Cell<usize>) -> impl FnOnce(&[&str]) -> Vec<String> + 'a {
+
+    return move |input: &[&str]| {
+        developed.set(developed.get() + 1);
+        return input.iter().map(|line| line.to_uppercase()).collect();
+    };
+}
+
+fn entries(directory: &Path) -> Vec<PathBuf> {
+
+    let mut entries: Vec<PathBuf> = fs::read_dir(directory).unwrap()
+        .map(|entry| entry.unwrap().path())
+        .collect();
+    entries.sort();
+    return entries;
+}
+
+fn develop_executable(directory: &TempDir, content: &str) -> PathBuf {
+
+    let executable: PathBuf = directory.path().join("solver");
+    fs::write(&executable, content).unwrap();
+    return executable;
+}
+
+#[test]
+fn cached_model_is_loaded_on_later_runs() {
+
+    let directory: TempDir = TempDir::new().unwrap();
+    let cache: ModelCache = ModelCache::new(&directory.path().join("cache"));
+    let developed: Cell<usize> = Cell::new(0);
+
+    let first_run: Vec<String> = cache.load_or_develop("model", &INPUT[..], develop_counted(&developed));
+    let second_run: Vec<String> = cache.load_or_develop("model", &INPUT[..], develop_counted(&developed));
+
+    assert_eq!(first_run, vec!["$ CD /", "$ LS", "DIR A"]);
+    assert_eq!(second_run, first_run);
+    assert_eq!(developed.get(), 1);
+    assert_eq!(entries(&directory.path().join("cache")).len(), 1);
+}
+
+#[test]
+fn input_change_invalidates_the_cache() {
+
+    let directory: TempDir = TempDir::new().unwrap();
+    let cache: ModelCache = ModelCache::new(directory.path());
+    let developed: Cell<usize> = Cell::new(0);
+
+    cache.load_or_develop("model", &INPUT[..], develop_counted(&developed));
+    let changed: Vec<String> = cache.load_or_develop("model", &INPUT[.. 2], develop_counted(&developed));
+
+    assert_eq!(changed, vec!["$ CD /", "$ LS"]);
+    assert_eq!(developed.get(), 2);
+    // The entry of the previous input is stale, and removed when the new one is written
+    assert_eq!(entries(directory.path()).len(), 1);
+}
+
+#[test]
+fn executable_change_invalidates_the_cache() {
+
+    let directory: TempDir = TempDir::new().unwrap();
+    let cache_directory: PathBuf = directory.path().join("cache");
+    let developed: Cell<usize> = Cell::new(0);
+
+    let executable: PathBuf = develop_executable(&directory, "first build");
+    ModelCache::new(&cache_directory).with_executable(&executable)
+        .load_or_develop("model", &INPUT[..], develop_counted(&developed));
+    ModelCache::new(&cache_directory).with_executable(&executable)
+        .load_or_develop("model", &INPUT[..], develop_counted(&developed));
+    assert_eq!(developed.get(), 1);
+
+    let executable: PathBuf = develop_executable(&directory, "second, longer build");
+    ModelCache::new(&cache_directory).with_executable(&executable)
+        .load_or_develop("model", &INPUT[..], develop_counted(&developed));
+    assert_eq!(developed.get(), 2);
+    assert_eq!(entries(&cache_directory).len(), 1);
+}
+
+#[test]
+fn corrupt_entry_falls_back_to_developing() {
+
+    let directory: TempDir = TempDir::new().unwrap();
+    let cache: ModelCache = ModelCache::new(directory.path());
+    let developed: Cell<usize> = Cell::new(0);
+
+    let model: Vec<String> = cache.load_or_develop("model", &INPUT[..], develop_counted(&developed));
+    let entry: PathBuf = entries(directory.path()).remove(0);
+    fs::write(&entry, [0xff; 3]).unwrap();
+
+    assert_eq!(cache.load_or_develop("model", &INPUT[..], develop_counted(&developed)), model);
+    assert_eq!(developed.get(), 2);
+
+    // The entry was written again, and is read on the next run
+    assert_eq!(cache.load_or_develop("model", &INPUT[..], develop_counted(&developed)), model);
+    assert_eq!(developed.get(), 2);
+}
+
+#[test]
+fn entry_of_another_input_falls_back_to_developing() {
+
+    let directory: TempDir = TempDir::new().unwrap();
+    let cache: ModelCache = ModelCache::new(directory.path());
+    let developed: Cell<usize> = Cell::new(0);
+
+    // The entry of the shorter input is moved under the name of the full one, as a fingerprint collision would
+    cache.load_or_develop("model", &INPUT[..], develop_counted(&developed));
+    let entry: PathBuf = entries(directory.path()).remove(0);
+    let shorter: Vec<String> = cache.load_or_develop("model", &INPUT[.. 2], develop_counted(&developed));
+    fs::rename(entries(directory.path()).remove(0), &entry).unwrap();
+
+    let model: Vec<String> = cache.load_or_develop("model", &INPUT[..], develop_counted(&developed));
+    assert_eq!(model, vec!["$ CD /", "$ LS", "DIR A"]);
+    assert_ne!(model, shorter);
+    assert_eq!(developed.get(), 3);
+}
+
+#[test]
+fn entry_of_another_version_falls_back_to_developing() {
+
+    let directory: TempDir = TempDir::new().unwrap();
+    let cache: ModelCache = ModelCache::new(directory.path());
+    let developed: Cell<usize> = Cell::new(0);
+
+    let model: Vec<String> = cache.load_or_develop("model", &INPUT[..], develop_counted(&developed));
+    let entry: PathBuf = entries(directory.path()).remove(0);
+    let version: &[u8] = env!("CARGO_PKG_VERSION").as_bytes();
+    let mut encoded: Vec<u8> = fs::read(&entry).unwrap();
+    let version_start: usize = encoded.windows(version.len()).position(|window| window == version).unwrap();
+    encoded[version_start] = b'9';
+    fs::write(&entry, encoded).unwrap();
+
+    assert_eq!(cache.load_or_develop("model", &INPUT[..], develop_counted(&developed)), model);
+    assert_eq!(developed.get(), 2);
+    assert_eq!(cache.load_or_develop("model", &INPUT[..], develop_counted(&developed)), model);
+    assert_eq!(developed.get(), 2);
+}
+
+#[test]
+fn unwritable_directory_still_develops_the_model() {
+
+    let directory: TempDir = TempDir::new().unwrap();
+    let blocking_file: PathBuf = directory.path().join("file");
+    fs::write(&blocking_file, "not a directory").unwrap();
+
+    let cache: ModelCache = ModelCache::new(&blocking_file.join("cache"));
+    let developed: Cell<usize> = Cell::new(0);
+
+    cache.load_or_develop("model", &INPUT[..], develop_counted(&developed));
+    cache.load_or_develop("model", &INPUT[..], develop_counted(&developed));
+    assert_eq!(developed.get(), 2);
+}
+
+#[test]
+fn bypass_develops_every_time_but_still_caches() {
+
+    let directory: TempDir = TempDir::new().unwrap();
+    let developed: Cell<usize> = Cell::new(0);
+
+    ModelCache::new(directory.path()).with_bypass(true)
+        .load_or_develop("model", &INPUT[..], develop_counted(&developed));
+    ModelCache::new(directory.path()).with_bypass(true)
+        .load_or_develop("model", &INPUT[..], develop_counted(&developed));
+    assert_eq!(developed.get(), 2);
+
+    ModelCache::new(directory.path())
+        .load_or_develop("model", &INPUT[..], develop_counted(&developed));
+    assert_eq!(developed.get(), 2);
+}
+
+#[test]
+fn models_under_other_names_are_kept() {
+
+    let directory: TempDir = TempDir::new().unwrap();
+    let cache: ModelCache = ModelCache::new(directory.path());
+    let developed: Cell<usize> = Cell::new(0);
+
+    cache.load_or_develop("model", &INPUT[..], develop_counted(&developed));
+    cache.load_or_develop("other", &INPUT[.. 1], develop_counted(&developed));
+    cache.load_or_develop("model", &INPUT[..], develop_counted(&developed));
+
+    assert_eq!(developed.get(), 2);
+    assert_eq!(entries(directory.path()).len(), 2);
+}
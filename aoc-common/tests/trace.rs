@@ -0,0 +1,59 @@
+use std::fs;
+use serde_json::Value;
+use tempfile::TempDir;
+
+use aoc_common::trace::install_json_subscriber;
+
+/// A solver of a single step, recorded the way the days record theirs.
+fn solve_step(step: usize, crates: &str) -> usize {
+
+    let _span = tracing::debug_span!("crane_move", step, from = 1, to = 2).entered();
+    tracing::trace!(containers = %crates, "containers moved");
+    return crates.len();
+}
+
+fn read_lines(path: &std::path::Path) -> Vec<Value> {
+
+    return fs::read_to_string(path).unwrap()
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+}
+
+// The subscriber is global to the process, so everything is checked within a single test
+#[test]
+fn spans_and_events_are_written_as_json_lines() {
+
+    let directory: TempDir = TempDir::new().unwrap();
+    let path: std::path::PathBuf = directory.path().join("trace.jsonl");
+    install_json_subscriber(&path).unwrap();
+
+    assert_eq!(solve_step(1, "AB") + solve_step(2, "C"), 3);
+    let lines: Vec<Value> = read_lines(&path);
+
+    assert_eq!(lines.len(), 4, "{:#?}", lines);
+    for (position, step, crates) in [(0, 1, "AB"), (2, 2, "C")] {
+
+        let new_span: &Value = &lines[position];
+        assert_eq!(new_span["level"], "DEBUG");
+        assert_eq!(new_span["fields"]["message"], "new");
+        assert_eq!(new_span["span"]["name"], "crane_move");
+        assert_eq!(new_span["span"]["step"], step);
+        assert_eq!(new_span["span"]["from"], 1);
+        assert_eq!(new_span["span"]["to"], 2);
+
+        let event: &Value = &lines[position + 1];
+        assert_eq!(event["level"], "TRACE");
+        assert_eq!(event["fields"]["message"], "containers moved");
+        assert_eq!(event["fields"]["containers"], crates);
+        assert_eq!(event["span"]["step"], step);
+    }
+
+    // Without timestamps or thread ids, the same run gives the same lines
+    for line in lines.iter() {
+        assert!(line.get("timestamp").is_none() && line.get("threadId").is_none(), "{}", line);
+    }
+
+    // Only one subscriber can be installed
+    assert!(install_json_subscriber(&directory.path().join("other.jsonl")).is_err());
+}
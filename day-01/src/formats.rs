@@ -0,0 +1,164 @@
+//! Structured representations of the elves' inventory.
+//!
+//! In JSON the inventory is a list with the snack calories of each elf, e.g. `[[1000, 2000], [4000]]`.
+//! Named elves and labelled snacks are objects instead, e.g. `{"name": "Alice", "snacks": [{"calories": 3000, "label": "apple"}]}`.
+//! In CSV every row is a snack, with the `elf` carrying it and its `calories`, and optionally the
+//! `name` of the elf and the `label` of the snack. An elf without snacks has a single row with
+//! empty `calories`.
+//! Statistics reports are written to JSON with the field names of [`CalorieReport`].
+
+use std::collections::HashMap;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{Elf, ElfSnack, SnackCalories};
+use crate::statistics::CalorieReport;
+
+// =========================================== TYPE AND STRUCT DEFINITIONS ===========================================
+
+#[derive(Serialize, Deserialize)]
+struct SnackRecord {
+    elf: usize,
+    /// Empty for the row of an elf without snacks, though the column is required.
+    #[serde(deserialize_with = "Option::deserialize")]
+    calories: Option<SnackCalories>,
+    #[serde(default)]
+    name: Option<String>,
+    #[serde(default)]
+    label: Option<String>
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum ElfSnackRecord {
+    Plain(SnackCalories),
+    Labelled { calories: SnackCalories, label: String }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum ElfRecord {
+    Plain(Vec<ElfSnackRecord>),
+    Named { name: String, snacks: Vec<ElfSnackRecord> }
+}
+
+// ================================================= IMPLEMENTATIONS =================================================
+
+impl From<&ElfSnack> for ElfSnackRecord {
+
+    fn from(snack: &ElfSnack) -> ElfSnackRecord {
+
+        return match &snack.label {
+            Some(label) => ElfSnackRecord::Labelled { calories: snack.calories, label: label.clone() },
+            None => ElfSnackRecord::Plain(snack.calories)
+        };
+    }
+}
+
+impl From<ElfSnackRecord> for ElfSnack {
+
+    fn from(record: ElfSnackRecord) -> ElfSnack {
+
+        return match record {
+            ElfSnackRecord::Plain(calories) => ElfSnack::new(calories, None),
+            ElfSnackRecord::Labelled { calories, label } => ElfSnack::new(calories, Some(label))
+        };
+    }
+}
+
+impl Serialize for Elf {
+
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+
+        let snacks: Vec<ElfSnackRecord> = self.snacks.iter()
+            .map(ElfSnackRecord::from)
+            .collect();
+
+        return match &self.name {
+            Some(name) => ElfRecord::Named { name: name.clone(), snacks }.serialize(serializer),
+            None => ElfRecord::Plain(snacks).serialize(serializer)
+        };
+    }
+}
+
+impl<'de> Deserialize<'de> for Elf {
+
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Elf, D::Error> {
+
+        let (name, snacks) = match ElfRecord::deserialize(deserializer)? {
+            ElfRecord::Plain(snacks) => (None, snacks),
+            ElfRecord::Named { name, snacks } => (Some(name), snacks)
+        };
+
+        return Ok(Elf { name, snacks: snacks.into_iter().map(ElfSnack::from).collect() });
+    }
+}
+
+// =============================================== AUXILIARY FUNCTIONS ===============================================
+
+/// Reads the elves from a JSON list of snack calories per elf.
+pub fn elves_from_json(input: &str) -> serde_json::Result<Vec<Elf>> {
+    return serde_json::from_str(input);
+}
+
+/// Writes the elves as a JSON list of snack calories per elf.
+pub fn elves_to_json(elves: &[Elf]) -> serde_json::Result<String> {
+    return serde_json::to_string(elves);
+}
+
+/// Reads the elves from CSV rows of `elf,calories`, with optional `name` and `label` columns.
+///
+/// Elves are kept in the order they first show up, and their snacks in the order of the rows.
+/// The name of an elf is taken from the first of its rows that has one, and rows with empty
+/// `calories` add no snack.
+pub fn elves_from_csv(input: &str) -> csv::Result<Vec<Elf>> {
+
+    let mut reader = csv::Reader::from_reader(input.as_bytes());
+
+    let mut elves_names: Vec<Option<String>> = Vec::new();
+    let mut elves_snacks: Vec<Vec<(SnackCalories, Option<String>)>> = Vec::new();
+    let mut elves_positions: HashMap<usize, usize> = HashMap::new();
+
+    for record in reader.deserialize() {
+
+        let record: SnackRecord = record?;
+        let position: usize = *elves_positions.entry(record.elf)
+            .or_insert_with(|| {
+                elves_names.push(None);
+                elves_snacks.push(Vec::new());
+                elves_snacks.len() - 1
+            });
+
+        if elves_names[position].is_none() { elves_names[position] = record.name }
+        if let Some(calories) = record.calories {
+            elves_snacks[position].push((calories, record.label));
+        }
+    }
+
+    return Ok(elves_names.into_iter()
+        .zip(elves_snacks)
+        .map(|(name, snacks)| Elf::new_annotated(name, snacks))
+        .collect());
+}
+
+/// Writes the elves as CSV rows of `elf,calories,name,label`, numbering the elves by their position.
+pub fn elves_to_csv(elves: &[Elf]) -> csv::Result<String> {
+
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    for (elf, elf_info) in elves.iter().enumerate() {
+
+        if elf_info.snacks.is_empty() {
+            writer.serialize(SnackRecord { elf, calories: None, name: elf_info.name.clone(), label: None })?;
+        }
+        for snack in elf_info.snacks.iter() {
+            writer.serialize(SnackRecord { elf, calories: Some(snack.calories), name: elf_info.name.clone(), label: snack.label.clone() })?;
+        }
+    }
+
+    let output: Vec<u8> = writer.into_inner().map_err(|error| error.into_error())?;
+    return Ok(String::from_utf8(output).unwrap());
+}
+
+/// Writes a statistics report as a JSON object.
+pub fn report_to_json(report: &CalorieReport) -> serde_json::Result<String> {
+    return serde_json::to_string_pretty(report);
+}
\ No newline at end of file
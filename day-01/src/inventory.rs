@@ -0,0 +1,321 @@
+//! Queries over the elves of an inventory, built condition by condition or parsed from an expression.
+//!
+//! Expressions join conditions with `and`, each comparing a field of the elf with a value, e.g.
+//! `total > 50000 and snacks = 3`. The fields are `total` (calories carried), `snack` (calories of
+//! any one snack), `snacks` (number of snacks) and `name`, and the operators `<`, `<=`, `=`, `!=`,
+//! `>=` and `>`. Values holding spaces or the word `and` are written between double quotes, with
+//! a backslash before any quote or backslash inside them, e.g. `name = "Mary and Ann"`.
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{Elf, SnackCalories};
+
+// =========================================== TYPE AND STRUCT DEFINITIONS ===========================================
+
+/// The elves of an inventory, in the order of the input.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Inventory {
+    elves: Vec<Elf>
+}
+
+/// How a field of the elf is compared with the value of a condition.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Comparison {
+    Less,
+    LessOrEqual,
+    Equal,
+    NotEqual,
+    GreaterOrEqual,
+    Greater
+}
+
+/// Condition an elf must meet to be part of the result of a query.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum ElfCondition {
+    /// Compares the total calories carried by the elf.
+    TotalCalories(Comparison, SnackCalories),
+    /// Holds when at least one snack of the elf compares as requested.
+    AnySnackCalories(Comparison, SnackCalories),
+    /// Compares the number of snacks carried by the elf.
+    NumberSnacks(Comparison, usize),
+    /// Holds for the elf with exactly this name.
+    Name(String)
+}
+
+/// Conditions gathered so far over an inventory, every one of which an elf must meet.
+#[derive(Clone, Debug)]
+pub struct InventoryQuery<'a> {
+    inventory: &'a Inventory,
+    conditions: Vec<ElfCondition>
+}
+
+/// Reason why a query expression could not be read, along with the condition at fault.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct QueryParseError {
+    pub condition: String,
+    pub reason: String
+}
+
+/// A word of a query expression, with the byte range it was written in.
+struct QueryToken {
+    text: String,
+    quoted: bool,
+    start: usize,
+    end: usize
+}
+
+/// Word joining the conditions of an expression, unless written between quotes.
+const CONDITION_SEPARATOR: &str = "and";
+const QUOTE: char = '"';
+const ESCAPE: char = '\\';
+
+// =============================================== AUXILIARY FUNCTIONS ===============================================
+
+/// Splits an expression into words, keeping whatever is written between double quotes as one word.
+fn develop_query_tokens(expression: &str) -> Result<Vec<QueryToken>, QueryParseError> {
+
+    let mut tokens: Vec<QueryToken> = Vec::new();
+    let mut characters = expression.char_indices().peekable();
+
+    while let Some((start, character)) = characters.next() {
+
+        if character.is_whitespace() { continue }
+
+        let quoted: bool = character == QUOTE;
+        let mut text: String = String::new();
+        let mut end: Option<usize> = None;
+
+        if quoted {
+            while let Some((position, character)) = characters.next() {
+                match character {
+                    QUOTE => {
+                        end = Some(position + 1);
+                        break;
+                    },
+                    ESCAPE => if let Some((_, escaped)) = characters.next() { text.push(escaped) },
+                    _ => text.push(character)
+                }
+            }
+
+            if end.is_none() {
+                return Err(QueryParseError { condition: expression[start ..].to_owned(), reason: "missing closing quote".to_owned() });
+            }
+        }
+        else {
+            text.push(character);
+            while let Some((position, character)) = characters.peek().copied() {
+
+                if character.is_whitespace() {
+                    end = Some(position);
+                    break;
+                }
+                text.push(character);
+                characters.next();
+            }
+        }
+
+        tokens.push(QueryToken { text, quoted, start, end: end.unwrap_or(expression.len()) });
+    }
+
+    return Ok(tokens);
+}
+
+/// Reads a single `<field> <operator> <value>` condition from its words in `expression`.
+fn develop_condition(tokens: &[QueryToken], expression: &str) -> Result<ElfCondition, QueryParseError> {
+
+    let condition: &str = match (tokens.first(), tokens.last()) {
+        (Some(first), Some(last)) => &expression[first.start .. last.end],
+        _ => ""
+    };
+    let error = |reason: String| QueryParseError { condition: condition.to_owned(), reason };
+
+    let [field, operator, value] = tokens else {
+        return Err(error("expected '<field> <operator> <value>'".to_owned()));
+    };
+    let (field, operator, value): (&str, &str, &str) = (&field.text, &operator.text, &value.text);
+
+    let comparison: Comparison = operator.parse().map_err(error)?;
+    let parse_calories = || value.parse::<SnackCalories>()
+        .map_err(|_| error(format!("'{}' is not a number of calories", value)));
+
+    return match field {
+        "total" => Ok(ElfCondition::TotalCalories(comparison, parse_calories()?)),
+        "snack" => Ok(ElfCondition::AnySnackCalories(comparison, parse_calories()?)),
+        "snacks" => value.parse()
+            .map(|number_snacks| ElfCondition::NumberSnacks(comparison, number_snacks))
+            .map_err(|_| error(format!("'{}' is not a number of snacks", value))),
+        "name" if comparison == Comparison::Equal => Ok(ElfCondition::Name(value.to_owned())),
+        "name" => Err(error("names can only be compared with '='".to_owned())),
+        _ => Err(error(format!("unknown field '{}'", field)))
+    };
+}
+
+/// Reads a query expression into the list of its conditions.
+pub fn parse_query_expression(expression: &str) -> Result<Vec<ElfCondition>, QueryParseError> {
+
+    return develop_query_tokens(expression)?
+        .split(|token| ! token.quoted && token.text == CONDITION_SEPARATOR)
+        .map(|tokens| develop_condition(tokens, expression))
+        .collect();
+}
+
+// ================================================= IMPLEMENTATIONS =================================================
+
+impl Comparison {
+
+    /// Whether `left` compares with `right` as requested.
+    pub fn holds<T: PartialOrd>(&self, left: T, right: T) -> bool {
+
+        return match *self {
+            Comparison::Less => left < right,
+            Comparison::LessOrEqual => left <= right,
+            Comparison::Equal => left == right,
+            Comparison::NotEqual => left != right,
+            Comparison::GreaterOrEqual => left >= right,
+            Comparison::Greater => left > right
+        };
+    }
+}
+
+impl FromStr for Comparison {
+
+    type Err = String;
+
+    fn from_str(operator: &str) -> Result<Comparison, String> {
+
+        return match operator {
+            "<" => Ok(Comparison::Less),
+            "<=" => Ok(Comparison::LessOrEqual),
+            "=" | "==" => Ok(Comparison::Equal),
+            "!=" => Ok(Comparison::NotEqual),
+            ">=" => Ok(Comparison::GreaterOrEqual),
+            ">" => Ok(Comparison::Greater),
+            _ => Err(format!("unknown operator '{}'", operator))
+        };
+    }
+}
+
+impl ElfCondition {
+
+    /// Whether the elf meets the condition.
+    pub fn matches(&self, elf: &Elf) -> bool {
+
+        return match self {
+            ElfCondition::TotalCalories(comparison, calories) => comparison.holds(elf.get_total_snack_calories(), *calories),
+            ElfCondition::AnySnackCalories(comparison, calories) => elf.get_snacks().iter()
+                .any(|snack| comparison.holds(snack.get_calories(), *calories)),
+            ElfCondition::NumberSnacks(comparison, number_snacks) => comparison.holds(elf.get_number_snacks(), *number_snacks),
+            ElfCondition::Name(name) => elf.get_name() == Some(name.as_str())
+        };
+    }
+}
+
+impl FromStr for ElfCondition {
+
+    type Err = QueryParseError;
+
+    /// Reads a single `<field> <operator> <value>` condition.
+    fn from_str(condition: &str) -> Result<ElfCondition, QueryParseError> {
+        return develop_condition(&develop_query_tokens(condition)?, condition);
+    }
+}
+
+impl fmt::Display for QueryParseError {
+
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        return write!(formatter, "invalid condition '{}': {}", self.condition, self.reason);
+    }
+}
+
+impl std::error::Error for QueryParseError {}
+
+impl Inventory {
+
+    pub fn new(elves: Vec<Elf>) -> Inventory {
+        Inventory {
+            elves
+        }
+    }
+
+    /// Every elf of the inventory, in order.
+    pub fn get_elves(&self) -> &[Elf] {
+        return &self.elves;
+    }
+
+    /// Starts a query matching every elf, to be narrowed down with further conditions.
+    pub fn query(&self) -> InventoryQuery<'_> {
+        InventoryQuery {
+            inventory: self,
+            conditions: Vec::new()
+        }
+    }
+
+    /// Starts a query with the conditions of a query expression.
+    pub fn query_expression(&self, expression: &str) -> Result<InventoryQuery<'_>, QueryParseError> {
+
+        let conditions: Vec<ElfCondition> = parse_query_expression(expression)?;
+        return Ok(conditions.into_iter()
+            .fold(self.query(), |query, condition| query.matching(condition)));
+    }
+}
+
+impl From<Vec<Elf>> for Inventory {
+
+    fn from(elves: Vec<Elf>) -> Inventory {
+        return Inventory::new(elves);
+    }
+}
+
+impl<'a> InventoryQuery<'a> {
+
+    /// Keeps the elves that also meet `condition`.
+    pub fn matching(mut self, condition: ElfCondition) -> InventoryQuery<'a> {
+
+        self.conditions.push(condition);
+        return self;
+    }
+
+    /// Keeps the elves carrying more than `calories` in total.
+    pub fn total_calories_above(self, calories: SnackCalories) -> InventoryQuery<'a> {
+        return self.matching(ElfCondition::TotalCalories(Comparison::Greater, calories));
+    }
+
+    /// Keeps the elves carrying less than `calories` in total.
+    pub fn total_calories_below(self, calories: SnackCalories) -> InventoryQuery<'a> {
+        return self.matching(ElfCondition::TotalCalories(Comparison::Less, calories));
+    }
+
+    /// Keeps the elves carrying at least one snack worth more than `calories`.
+    pub fn any_snack_above(self, calories: SnackCalories) -> InventoryQuery<'a> {
+        return self.matching(ElfCondition::AnySnackCalories(Comparison::Greater, calories));
+    }
+
+    /// Keeps the elves carrying exactly `number_snacks` snacks.
+    pub fn number_snacks(self, number_snacks: usize) -> InventoryQuery<'a> {
+        return self.matching(ElfCondition::NumberSnacks(Comparison::Equal, number_snacks));
+    }
+
+    /// Keeps the elf named `name`.
+    pub fn named(self, name: &str) -> InventoryQuery<'a> {
+        return self.matching(ElfCondition::Name(name.to_owned()));
+    }
+
+    /// Elves meeting every condition, along with their position in the inventory.
+    pub fn elves(&self) -> Vec<(usize, &'a Elf)> {
+
+        let inventory: &'a Inventory = self.inventory;
+        return inventory.elves.iter()
+            .enumerate()
+            .filter(|(_, elf)| self.conditions.iter().all(|condition| condition.matches(elf)))
+            .collect();
+    }
+
+    /// Number of elves meeting every condition.
+    pub fn count(&self) -> usize {
+
+        return self.inventory.elves.iter()
+            .filter(|elf| self.conditions.iter().all(|condition| condition.matches(elf)))
+            .count();
+    }
+}
\ No newline at end of file
@@ -1,68 +1,253 @@
+//! Calorie counting (Advent of Code 2022, day 01).
+//!
+//! Every elf carries a list of snacks, and each snack is worth a number of calories.
+//! The input lists the calories of each snack, one per line, with a blank line between elves.
+//! Elves may also be named with a `# Alice` header, snacks labelled as in `3000 apple`, and any line
+//! annotated with a `//` comment, see [`convert_input_to_inventory`].
+//! The same inventory can also be read from, and written to, JSON or CSV through [`formats`],
+//! summarized through [`statistics`], rebalanced through [`redistribution`], and searched
+//! through [`inventory`].
+
+use serde::Serialize;
+use std::borrow::Borrow;
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+use std::fmt;
+
+pub mod formats;
+pub mod inventory;
+pub mod redistribution;
+pub mod statistics;
 
 // =========================================== TYPE AND STRUCT DEFINITIONS ===========================================
 
+/// Calories provided by a single snack.
 pub type SnackCalories = i32;
 
-struct ElfSnack {
-    calories: SnackCalories
+/// A snack carried by an elf, with its label when the input gives one.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ElfSnack {
+    calories: SnackCalories,
+    label: Option<String>
 }
 
+/// An elf and the snacks they are carrying.
+///
+/// In JSON an unnamed elf is the list of its snacks, and a named one an object with its `name` and
+/// `snacks`. Snacks are their calories, or an object with their `calories` and `label`.
+#[derive(Clone, PartialEq, Eq, Debug)]
 pub struct Elf {
+    name: Option<String>,
     snacks: Vec<ElfSnack>
 }
 
+/// Total calories carried by an elf, along with the position and name of the elf in the inventory.
+#[derive(Clone, PartialEq, Eq, Debug, Serialize)]
+pub struct ElfTotal {
+    pub index: usize,
+    pub name: Option<String>,
+    pub total: SnackCalories
+}
+
+/// Line of the input whose snack calories could not be read.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct InventoryParseError {
+    /// Number of the line, starting from 1.
+    pub line: usize,
+    /// Text found where the calories should be.
+    pub calories: String
+}
+
+/// Marker starting a comment, up to the end of the line.
+const COMMENT_MARKER: &str = "//";
+/// Marker starting the header that names an elf.
+const NAME_MARKER: char = '#';
+
 // =============================================== AUXILIARY FUNCTIONS ===============================================
 
+/// Groups the raw input lines into the list of snack calories carried by each elf.
+///
+/// Names, labels and comments of the annotated format are accepted and left out.
 pub fn convert_input_to_correct_format(input_lines: Vec<String>) -> Vec<Vec<SnackCalories>> {
 
-    let mut input_formatted: Vec<Vec<SnackCalories>> = Vec::new();
+    return convert_input_to_inventory(&input_lines).unwrap_or_else(|error| panic!("🚨 {}", error)).iter()
+        .map(|elf| elf.get_snack_calories())
+        .collect();
+}
+
+/// Reads the elves from the raw input lines, in the plain or the annotated format.
+///
+/// A blank line ends the current elf, as in the plain format. A `# Alice` header names the elf
+/// that follows it, ending the current one first if it already holds snacks. Snack lines hold
+/// their calories, optionally followed by a label, and anything after `//` is a comment. A snack
+/// line whose calories are not a number is returned as an error.
+pub fn convert_input_to_inventory(input_lines: &[String]) -> Result<Vec<Elf>, InventoryParseError> {
+
+    let mut elves: Vec<Elf> = Vec::new();
+
+    let mut current_elf: Elf = Elf::new(Vec::new());
+    for (line_number, line) in input_lines.iter().enumerate() {
+
+        let content: &str = match line.find(COMMENT_MARKER) {
+            Some(comment_start) => line[.. comment_start].trim(),
+            None => line.trim()
+        };
+
+        if line.trim().is_empty() {
+
+            elves.push(current_elf);
+            current_elf = Elf::new(Vec::new());
+
+        } else if let Some(name) = content.strip_prefix(NAME_MARKER) {
 
-    let mut current_list: Vec<SnackCalories> = Vec::new();
-    for line in input_lines.into_iter() {
+            if ! current_elf.snacks.is_empty() || current_elf.name.is_some() {
+                elves.push(current_elf);
+                current_elf = Elf::new(Vec::new());
+            }
+            current_elf.name = Some(name.trim().to_owned());
 
-        if line.is_empty() {
+        } else if ! content.is_empty() {
 
-            input_formatted.push(current_list);
-            current_list = Vec::new();
+            let (calories, label) = content.split_once(char::is_whitespace)
+                .map_or((content, None), |(calories, label)| (calories, Some(label.trim().to_owned())));
 
-        } else {
+            let new_snack_calories: SnackCalories = calories.parse()
+                .map_err(|_| InventoryParseError { line: line_number + 1, calories: calories.to_owned() })?;
+            current_elf.snacks.push(ElfSnack::new(new_snack_calories, label));
+        }
+    }
+
+    elves.push(current_elf);
+    return Ok(elves);
+}
+
+/// The `k` elves carrying the most calories, from the most to the least loaded.
+///
+/// The elves are read in a single pass, keeping at most `k` of them in a heap, so the inventory
+/// never has to be collected or sorted. Ties go to the elf that comes first, and when there are
+/// fewer than `k` elves all of them are returned.
+pub fn top_k_elves<I, E>(elves: I, k: usize) -> Vec<ElfTotal>
+    where I: IntoIterator<Item = E>, E: Borrow<Elf> {
+
+    if k == 0 { return Vec::new() }
+
+    // Min-heap on the total, where the later elf is the smaller one among equal totals
+    let mut top_elves: BinaryHeap<Reverse<(SnackCalories, Reverse<usize>)>> = BinaryHeap::with_capacity(k);
+    let mut top_names: HashMap<usize, Option<String>> = HashMap::with_capacity(k);
 
-            let new_snack_calories: SnackCalories = line.parse().unwrap();
-            current_list.push(new_snack_calories);
+    for (index, elf) in elves.into_iter().enumerate() {
 
+        let candidate: (SnackCalories, Reverse<usize>) = (elf.borrow().get_total_snack_calories(), Reverse(index));
+        if top_elves.len() == k {
+
+            if top_elves.peek().is_some_and(|Reverse(smallest)| candidate <= *smallest) { continue }
+            let Reverse((_, Reverse(removed_index))) = top_elves.pop().unwrap();
+            top_names.remove(&removed_index);
         }
+
+        top_elves.push(Reverse(candidate));
+        top_names.insert(index, elf.borrow().get_name().map(|name| name.to_owned()));
     }
 
-    input_formatted.push(current_list);
-    return input_formatted;
+    return top_elves.into_sorted_vec().into_iter()
+        .map(|Reverse((total, Reverse(index)))| ElfTotal { index, name: top_names.remove(&index).unwrap(), total })
+        .collect();
 }
 
 // ================================================= IMPLEMENTATIONS =================================================
 
 impl ElfSnack {
     
-    pub fn new(calories: SnackCalories) -> ElfSnack {
+    /// Creates a snack worth `calories`, labelled or not.
+    pub fn new(calories: SnackCalories, label: Option<String>) -> ElfSnack {
         ElfSnack {
-            calories: calories
+            calories,
+            label
         }
     }
+
+    /// Calories provided by the snack.
+    pub fn get_calories(&self) -> SnackCalories {
+        return self.calories;
+    }
+
+    /// Label given to the snack in the input, if any.
+    pub fn get_label(&self) -> Option<&str> {
+        return self.label.as_deref();
+    }
 }
 
 
 impl Elf {
     
+    /// Creates an unnamed elf carrying one unlabelled snack per entry in `snacks_calories`.
     pub fn new(snacks_calories: Vec<SnackCalories>) -> Elf {
         Elf {
+            name: None,
             snacks: snacks_calories.into_iter()
-            .map(|calories| ElfSnack::new(calories))
+            .map(|calories| ElfSnack::new(calories, None))
             .collect()
         }
     }
 
+    /// Creates an elf, named or not, carrying one snack per pair of calories and optional label.
+    pub fn new_annotated(name: Option<String>, snacks: Vec<(SnackCalories, Option<String>)>) -> Elf {
+        Elf {
+            name,
+            snacks: snacks.into_iter()
+            .map(|(calories, label)| ElfSnack::new(calories, label))
+            .collect()
+        }
+    }
+
+    /// Name given to the elf in the input, if any.
+    pub fn get_name(&self) -> Option<&str> {
+        return self.name.as_deref();
+    }
+
+    /// Every snack carried by the elf, in order.
+    pub fn get_snacks(&self) -> &[ElfSnack] {
+        return &self.snacks;
+    }
+
+    /// Calories of every snack carried by the elf, in order.
+    pub fn get_snack_calories(&self) -> Vec<SnackCalories> {
+
+        return self.snacks.iter()
+            .map(|snack| snack.calories)
+            .collect();
+    }
+
+    /// Number of snacks carried by the elf.
+    pub fn get_number_snacks(&self) -> usize {
+        return self.snacks.len();
+    }
+
+    /// Sum of the calories of every snack carried by the elf.
     pub fn get_total_snack_calories(&self) -> SnackCalories {
 
         return self.snacks.iter()
             .map(|snack| snack.calories)
             .sum();
     }
-}
\ No newline at end of file
+}
+
+impl fmt::Display for ElfTotal {
+
+    /// Writes the name of the elf, or its position in the inventory when it has none.
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+
+        return match &self.name {
+            Some(name) => write!(formatter, "{}", name),
+            None => write!(formatter, "{}", self.index)
+        };
+    }
+}
+impl fmt::Display for InventoryParseError {
+
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        return write!(formatter, "line {}: expected the calories of a snack, found '{}'", self.line, self.calories);
+    }
+}
+
+impl std::error::Error for InventoryParseError {}
@@ -1,31 +1,63 @@
 mod read;
-mod lib;
 
-use crate::lib::SnackCalories;
-use crate::lib::Elf;
-use crate::lib::convert_input_to_correct_format;
+use day_01::SnackCalories;
+use day_01::{Elf, ElfTotal};
+use day_01::{convert_input_to_inventory, top_k_elves};
+use day_01::formats::report_to_json;
+use day_01::inventory::{Inventory, InventoryQuery};
+use day_01::redistribution::{plan_redistribution, PartitionMethod, RedistributionObjective, RedistributionPlan};
+use day_01::statistics::CalorieReport;
 
 fn main() {
 
     let input = read::read_lines("input.txt".to_owned());
-    let input_formatted : Vec<Vec<SnackCalories>> = convert_input_to_correct_format(input);
-
-    let elfes : Vec<Elf> = input_formatted.into_iter()
-        .map(|snacks| Elf::new(snacks))
+    let elfes : Vec<Elf> = convert_input_to_inventory(&input).unwrap_or_else(|error| panic!("🚨 {}", error));
+    let input_formatted : Vec<Vec<SnackCalories>> = elfes.iter()
+        .map(|elf| elf.get_snack_calories())
         .collect();
+    let elf_name = |index: usize| elfes[index].get_name().map_or(index.to_string(), |name| name.to_owned());
+
+    let top_elves: Vec<ElfTotal> = top_k_elves(&elfes, 3);
 
-    let mut sum_calories_by_elf : Vec<SnackCalories> = elfes.iter()
-        .map(|elf| elf.get_total_snack_calories())
-        .collect();
-    sum_calories_by_elf.sort_by(|a, b| b.cmp(a));
-    
     // Part 1
-    let maximum_one_elf_sum: SnackCalories = *sum_calories_by_elf.first().unwrap();
+    let maximum_one_elf_sum: SnackCalories = top_elves.first()
+        .map_or(0, |elf_total| elf_total.total);
     println!("\r🍗 Number of calories of top 1 Elves: '{}' (Part 1)", maximum_one_elf_sum);
     
     // Part 2
-    let maximum_three_elf_sum: SnackCalories = sum_calories_by_elf[0..3]
-        .iter()
+    let maximum_three_elf_sum: SnackCalories = top_elves.iter()
+        .map(|elf_total| elf_total.total)
         .sum();
     println!("\r🍗 Number of calories of top 3 Elves: '{}' (Part 2)", maximum_three_elf_sum);
+
+    // Statistics, redistribution and queries, on request
+    let report: Option<CalorieReport> = CalorieReport::new(&elfes, 10);
+    match (std::env::args().nth(1).as_deref(), report) {
+        (Some("--report"), Some(report)) => println!("\n{}", report),
+        (Some("--report-json"), Some(report)) => println!("{}", report_to_json(&report).unwrap()),
+        (Some("--redistribute"), _) => {
+
+            let plan: RedistributionPlan = plan_redistribution(&input_formatted, PartitionMethod::Auto, RedistributionObjective::MaximumCalories);
+            for transfer in plan.transfers.iter() {
+                println!("{} -> {}: snack {} ({} calories)", elf_name(transfer.from_elf), elf_name(transfer.to_elf), transfer.snack, transfer.calories);
+            }
+            println!("\r🍗 Number of calories of the most loaded Elf after '{}' transfers: '{}'",
+                plan.transfers.len(), plan.totals.iter().max().unwrap_or(&0));
+        },
+        (Some("--query"), _) => {
+
+            let expression: String = std::env::args().nth(2).unwrap_or_default();
+            let inventory: Inventory = Inventory::new(elfes.clone());
+            let query: InventoryQuery = match inventory.query_expression(&expression) {
+                Ok(query) => query,
+                Err(error) => panic!("🚨 {}", error)
+            };
+
+            for (index, elf) in query.elves() {
+                println!("{}: {} snacks, {} calories", elf_name(index), elf.get_number_snacks(), elf.get_total_snack_calories());
+            }
+            println!("\r🍗 Number of Elves matching '{}': '{}'", expression, query.count());
+        },
+        _ => ()
+    }
 }
\ No newline at end of file
@@ -0,0 +1,326 @@
+//! Planner moving snacks between elves so that the calories end up as evenly spread as possible.
+//!
+//! Balancing the loads is a multiway number partitioning problem: the snacks are split into as
+//! many bins as there are elves, and the bins are then handed to the elves so that as many snacks
+//! as possible stay with the elf already carrying them, and only the others change hands.
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+use crate::SnackCalories;
+
+// =========================================== TYPE AND STRUCT DEFINITIONS ===========================================
+
+/// Largest number of snacks for which [`PartitionMethod::Auto`] searches for the optimal plan.
+pub const EXACT_SNACK_LIMIT: usize = 12;
+
+/// Quantity the planner keeps as low as possible.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum RedistributionObjective {
+    /// Calories of the most loaded elf.
+    MaximumCalories,
+    /// Variance of the calories carried by each elf.
+    Variance
+}
+
+/// Algorithm used to split the snacks between the elves.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PartitionMethod {
+    /// Longest processing time first: every snack, from the largest, goes to the least loaded elf.
+    Greedy,
+    /// Largest differencing method, merging the two most unbalanced partial partitions each time.
+    KarmarkarKarp,
+    /// Branch and bound over every assignment, only practical for a handful of snacks. Partial
+    /// assignments are only pruned when no snack has negative calories, so that loads never shrink.
+    Exact,
+    /// The exact search up to [`EXACT_SNACK_LIMIT`] snacks, the best of both heuristics otherwise.
+    Auto
+}
+
+/// A snack handed from one elf to another, `snack` being its position in the original list of `from_elf`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct SnackTransfer {
+    pub from_elf: usize,
+    pub to_elf: usize,
+    pub snack: usize,
+    pub calories: SnackCalories
+}
+
+/// Transfers to perform, and the calories carried by each elf once they are done.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct RedistributionPlan {
+    pub transfers: Vec<SnackTransfer>,
+    pub totals: Vec<SnackCalories>
+}
+
+/// Snack identified by the elf carrying it and its position in their list.
+type SnackPosition = (usize, usize);
+type Bin = Vec<SnackPosition>;
+
+// =============================================== AUXILIARY FUNCTIONS ===============================================
+
+fn compute_bin_total(elves_snacks: &[Vec<SnackCalories>], bin: &Bin) -> SnackCalories {
+
+    return bin.iter()
+        .map(|(elf, snack)| elves_snacks[*elf][*snack])
+        .sum();
+}
+
+fn develop_snacks_decreasing(elves_snacks: &[Vec<SnackCalories>]) -> Vec<SnackPosition> {
+
+    let mut snacks: Vec<SnackPosition> = elves_snacks.iter()
+        .enumerate()
+        .flat_map(|(elf, snacks)| (0 .. snacks.len()).map(move |snack| (elf, snack)))
+        .collect();
+
+    snacks.sort_by_key(|(elf, snack)| Reverse(elves_snacks[*elf][*snack]));
+    return snacks;
+}
+
+fn partition_greedy(elves_snacks: &[Vec<SnackCalories>]) -> Vec<Bin> {
+
+    let number_bins: usize = elves_snacks.len();
+    let mut bins: Vec<Bin> = vec![Vec::new(); number_bins];
+    let mut loads: BinaryHeap<Reverse<(SnackCalories, usize)>> = (0 .. number_bins)
+        .map(|bin| Reverse((0, bin)))
+        .collect();
+
+    for (elf, snack) in develop_snacks_decreasing(elves_snacks) {
+
+        let Reverse((load, bin)) = loads.pop().unwrap();
+        bins[bin].push((elf, snack));
+        loads.push(Reverse((load + elves_snacks[elf][snack], bin)));
+    }
+
+    return bins;
+}
+
+fn partition_karmarkar_karp(elves_snacks: &[Vec<SnackCalories>]) -> Vec<Bin> {
+
+    let number_bins: usize = elves_snacks.len();
+
+    // Every partial partition starts as a single snack, and keeps its bins sorted by decreasing load
+    let mut partitions: Vec<Option<Vec<(SnackCalories, Bin)>>> = Vec::new();
+    let mut differences: BinaryHeap<(SnackCalories, usize)> = BinaryHeap::new();
+
+    for (elf, snack) in develop_snacks_decreasing(elves_snacks) {
+
+        let calories: SnackCalories = elves_snacks[elf][snack];
+        let mut partition: Vec<(SnackCalories, Bin)> = vec![(0, Vec::new()); number_bins];
+        partition[0] = (calories, vec![(elf, snack)]);
+
+        differences.push((calories, partitions.len()));
+        partitions.push(Some(partition));
+    }
+
+    while differences.len() > 1 {
+
+        let (_, first) = differences.pop().unwrap();
+        let (_, second) = differences.pop().unwrap();
+        let first_partition: Vec<(SnackCalories, Bin)> = partitions[first].take().unwrap();
+        let second_partition: Vec<(SnackCalories, Bin)> = partitions[second].take().unwrap();
+
+        // The heaviest bins of one partition go with the lightest bins of the other
+        let mut merged_partition: Vec<(SnackCalories, Bin)> = first_partition.into_iter()
+            .zip(second_partition.into_iter().rev())
+            .map(|((first_load, mut first_bin), (second_load, second_bin))| {
+                first_bin.extend(second_bin);
+                (first_load + second_load, first_bin)
+            })
+            .collect();
+        merged_partition.sort_by_key(|(load, _)| Reverse(*load));
+
+        let difference: SnackCalories = merged_partition.first().unwrap().0 - merged_partition.last().unwrap().0;
+        differences.push((difference, partitions.len()));
+        partitions.push(Some(merged_partition));
+    }
+
+    return match differences.pop() {
+        Some((_, last)) => partitions[last].take().unwrap().into_iter()
+            .map(|(_, bin)| bin)
+            .collect(),
+        None => vec![Vec::new(); number_bins]
+    };
+}
+
+fn search_exact_partition(elves_snacks: &[Vec<SnackCalories>], snacks: &[SnackPosition], objective: RedistributionObjective,
+    prune: bool, bins: &mut Vec<Bin>, loads: &mut Vec<SnackCalories>, best: &mut Option<(i64, Vec<Bin>)>) {
+
+    // Without negative snacks loads only grow as snacks are added, so a partial assignment
+    // already as bad as the best one is dropped
+    let current_cost: i64 = objective.evaluate(loads);
+    if best.as_ref().is_some_and(|(best_cost, _)| (prune || snacks.is_empty()) && current_cost >= *best_cost) { return }
+
+    let Some(((elf, snack), remaining_snacks)) = snacks.split_first() else {
+        *best = Some((current_cost, bins.clone()));
+        return;
+    };
+
+    for bin in 0 .. bins.len() {
+
+        // Empty bins are interchangeable, so trying the first one of them is enough
+        if bins[bin].is_empty() && bins[.. bin].iter().any(|previous_bin| previous_bin.is_empty()) { continue }
+
+        bins[bin].push((*elf, *snack));
+        loads[bin] += elves_snacks[*elf][*snack];
+        search_exact_partition(elves_snacks, remaining_snacks, objective, prune, bins, loads, best);
+        loads[bin] -= elves_snacks[*elf][*snack];
+        bins[bin].pop();
+    }
+}
+
+fn partition_exact(elves_snacks: &[Vec<SnackCalories>], objective: RedistributionObjective) -> Vec<Bin> {
+
+    let number_bins: usize = elves_snacks.len();
+    let snacks: Vec<SnackPosition> = develop_snacks_decreasing(elves_snacks);
+    let prune: bool = elves_snacks.iter().flatten().all(|calories| *calories >= 0);
+
+    let mut best: Option<(i64, Vec<Bin>)> = None;
+    search_exact_partition(elves_snacks, &snacks, objective, prune,
+        &mut vec![Vec::new(); number_bins], &mut vec![0; number_bins], &mut best);
+
+    return best.map_or_else(|| vec![Vec::new(); number_bins], |(_, bins)| bins);
+}
+
+/// Hands every bin to an elf so that the elves keep as many of their own snacks as possible.
+///
+/// This is a maximum-weight assignment between bins and elves, solved with the Hungarian
+/// algorithm over the number of snacks each elf would give away, in `O(n³)` for `n` elves.
+fn assign_bins_to_elves(bins: &[Bin]) -> Vec<usize> {
+
+    let size: usize = bins.len();
+    let mut given_away: Vec<Vec<i64>> = vec![vec![0; size]; size];
+    for (bin, snacks) in bins.iter().enumerate() {
+
+        given_away[bin].iter_mut().for_each(|count| *count = snacks.len() as i64);
+        for (elf, _) in snacks.iter() {
+            given_away[bin][*elf] -= 1;
+        }
+    }
+
+    // Potentials of the bins and the elves, with position 0 standing for no bin or no elf, so
+    // that `elf_bins[elf]` is the bin handed to `elf`, counting from 1
+    let mut bin_potentials: Vec<i64> = vec![0; size + 1];
+    let mut elf_potentials: Vec<i64> = vec![0; size + 1];
+    let mut elf_bins: Vec<usize> = vec![0; size + 1];
+    let mut previous_elves: Vec<usize> = vec![0; size + 1];
+
+    for bin in 1 ..= size {
+
+        elf_bins[0] = bin;
+        let mut elf: usize = 0;
+        let mut slack: Vec<i64> = vec![i64::MAX; size + 1];
+        let mut visited: Vec<bool> = vec![false; size + 1];
+
+        // Grows a tree of tight edges from the new bin until it reaches an elf without a bin
+        while elf_bins[elf] != 0 {
+
+            visited[elf] = true;
+            let current_bin: usize = elf_bins[elf];
+            let mut delta: i64 = i64::MAX;
+            let mut next_elf: usize = 0;
+
+            for other in 1 ..= size {
+
+                if visited[other] { continue }
+                let reduced: i64 = given_away[current_bin - 1][other - 1] - bin_potentials[current_bin] - elf_potentials[other];
+                if reduced < slack[other] {
+                    slack[other] = reduced;
+                    previous_elves[other] = elf;
+                }
+                if slack[other] < delta {
+                    delta = slack[other];
+                    next_elf = other;
+                }
+            }
+
+            for other in 0 ..= size {
+                match visited[other] {
+                    true => {
+                        bin_potentials[elf_bins[other]] += delta;
+                        elf_potentials[other] -= delta;
+                    },
+                    false => slack[other] -= delta
+                }
+            }
+            elf = next_elf;
+        }
+
+        // Flips the augmenting path back to the new bin
+        while elf != 0 {
+            let previous_elf: usize = previous_elves[elf];
+            elf_bins[elf] = elf_bins[previous_elf];
+            elf = previous_elf;
+        }
+    }
+
+    let mut bin_owners: Vec<usize> = vec![0; size];
+    for elf in 1 ..= size {
+        bin_owners[elf_bins[elf] - 1] = elf - 1;
+    }
+
+    return bin_owners;
+}
+
+/// Plans which snacks should change hands so that the elves' calories are balanced according to `objective`.
+///
+/// Each inner list holds the snack calories of one elf, as returned by [`crate::convert_input_to_correct_format`].
+/// Among the ways of handing the bins of the chosen partition to the elves, the plan moves the
+/// fewest snacks.
+pub fn plan_redistribution(elves_snacks: &[Vec<SnackCalories>], method: PartitionMethod,
+    objective: RedistributionObjective) -> RedistributionPlan {
+
+    let number_snacks: usize = elves_snacks.iter().map(|snacks| snacks.len()).sum();
+    let bins: Vec<Bin> = match method {
+        PartitionMethod::Greedy => partition_greedy(elves_snacks),
+        PartitionMethod::KarmarkarKarp => partition_karmarkar_karp(elves_snacks),
+        PartitionMethod::Exact => partition_exact(elves_snacks, objective),
+        PartitionMethod::Auto if number_snacks <= EXACT_SNACK_LIMIT => partition_exact(elves_snacks, objective),
+        PartitionMethod::Auto => {
+
+            let greedy_bins: Vec<Bin> = partition_greedy(elves_snacks);
+            let karmarkar_karp_bins: Vec<Bin> = partition_karmarkar_karp(elves_snacks);
+            let cost = |bins: &Vec<Bin>| objective.evaluate(&bins.iter()
+                .map(|bin| compute_bin_total(elves_snacks, bin))
+                .collect::<Vec<SnackCalories>>());
+
+            if cost(&karmarkar_karp_bins) < cost(&greedy_bins) { karmarkar_karp_bins } else { greedy_bins }
+        }
+    };
+
+    let bin_owners: Vec<usize> = assign_bins_to_elves(&bins);
+    let mut totals: Vec<SnackCalories> = vec![0; elves_snacks.len()];
+    let mut transfers: Vec<SnackTransfer> = Vec::new();
+
+    for (bin, snacks) in bins.iter().enumerate() {
+
+        let to_elf: usize = bin_owners[bin];
+        totals[to_elf] = compute_bin_total(elves_snacks, snacks);
+
+        for (from_elf, snack) in snacks.iter() {
+            if *from_elf != to_elf {
+                transfers.push(SnackTransfer { from_elf: *from_elf, to_elf, snack: *snack, calories: elves_snacks[*from_elf][*snack] });
+            }
+        }
+    }
+
+    transfers.sort_by_key(|transfer| (transfer.from_elf, transfer.snack));
+    return RedistributionPlan { transfers, totals };
+}
+
+// ================================================= IMPLEMENTATIONS =================================================
+
+impl RedistributionObjective {
+
+    /// Cost of the given loads, lower being better.
+    ///
+    /// The variance is measured as the sum of squared loads, which orders partitions of the same
+    /// snacks the same way since their mean is fixed.
+    pub fn evaluate(&self, loads: &[SnackCalories]) -> i64 {
+
+        return match *self {
+            RedistributionObjective::MaximumCalories => loads.iter().copied().max().unwrap_or(0) as i64,
+            RedistributionObjective::Variance => loads.iter().map(|load| (*load as i64).pow(2)).sum()
+        };
+    }
+}
\ No newline at end of file
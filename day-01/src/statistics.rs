@@ -0,0 +1,179 @@
+//! Descriptive statistics over the total calories carried by each elf.
+//!
+//! A [`CalorieReport`] prints as a plain text table, and serializes to JSON through
+//! [`crate::formats::report_to_json`].
+
+use std::collections::BTreeMap;
+use std::fmt;
+use serde::Serialize;
+
+use crate::{Elf, ElfTotal, SnackCalories};
+
+// =========================================== TYPE AND STRUCT DEFINITIONS ===========================================
+
+/// Percentiles included in every report.
+pub const REPORT_PERCENTILES: [u8; 5] = [10, 25, 75, 90, 99];
+
+/// Distance from the quartiles, in interquartile ranges, beyond which an elf is an outlier.
+const OUTLIER_FENCE: f64 = 1.5;
+
+/// Value below which `rank` percent of the totals fall.
+#[derive(Clone, Copy, PartialEq, Debug, Serialize)]
+pub struct Percentile {
+    pub rank: u8,
+    pub value: f64
+}
+
+/// Number of elves whose total falls within `[start, end)`.
+///
+/// The bounds are wider than the totals, as the last bin may end past the largest of them.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize)]
+pub struct HistogramBin {
+    pub start: i64,
+    pub end: i64,
+    pub elves: usize
+}
+
+/// Summary of the calories carried across the whole inventory.
+#[derive(Clone, PartialEq, Debug, Serialize)]
+pub struct CalorieReport {
+    pub elves: usize,
+    pub mean: f64,
+    pub median: f64,
+    pub standard_deviation: f64,
+    pub percentiles: Vec<Percentile>,
+    /// Number of elves carrying each number of snacks.
+    pub snack_counts: BTreeMap<usize, usize>,
+    pub histogram: Vec<HistogramBin>,
+    /// Elves whose total lies more than 1.5 interquartile ranges away from the quartiles.
+    pub outliers: Vec<ElfTotal>
+}
+
+// =============================================== AUXILIARY FUNCTIONS ===============================================
+
+/// Percentile of already sorted totals, interpolating linearly between the closest ranks.
+fn compute_percentile(sorted_totals: &[SnackCalories], rank: f64) -> f64 {
+
+    let position: f64 = rank / 100.0 * (sorted_totals.len() - 1) as f64;
+    let lower: usize = position.floor() as usize;
+    let upper: usize = position.ceil() as usize;
+
+    let lower_value: f64 = sorted_totals[lower] as f64;
+    let upper_value: f64 = sorted_totals[upper] as f64;
+    return lower_value + (upper_value - lower_value) * (position - lower as f64);
+}
+
+/// Splits the totals into at most `number_bins` bins of the same width, from the smallest total
+/// to the largest.
+///
+/// Bounds are computed on 64 bits, where the span between any two totals fits.
+fn develop_histogram(sorted_totals: &[SnackCalories], number_bins: usize) -> Vec<HistogramBin> {
+
+    let minimum: i64 = i64::from(*sorted_totals.first().unwrap());
+    let maximum: i64 = i64::from(*sorted_totals.last().unwrap());
+
+    let range: u64 = (maximum - minimum) as u64 + 1;
+    let bin_width: u64 = range.div_ceil((number_bins as u64).clamp(1, range));
+
+    let mut histogram: Vec<HistogramBin> = (0 .. range.div_ceil(bin_width))
+        .map(|bin| HistogramBin {
+            start: minimum + (bin * bin_width) as i64,
+            end: minimum + ((bin + 1) * bin_width) as i64,
+            elves: 0
+        })
+        .collect();
+
+    for total in sorted_totals {
+        histogram[((i64::from(*total) - minimum) as u64 / bin_width) as usize].elves += 1;
+    }
+
+    return histogram;
+}
+
+// ================================================= IMPLEMENTATIONS =================================================
+
+impl CalorieReport {
+
+    /// Report over the totals of every elf, with their distribution split into `histogram_bins` bins.
+    ///
+    /// Returns `None` for an empty inventory, where none of the statistics are defined.
+    pub fn new(elves: &[Elf], histogram_bins: usize) -> Option<CalorieReport> {
+
+        if elves.is_empty() { return None }
+
+        let totals: Vec<SnackCalories> = elves.iter()
+            .map(|elf| elf.get_total_snack_calories())
+            .collect();
+        let mut sorted_totals: Vec<SnackCalories> = totals.clone();
+        sorted_totals.sort();
+
+        let number_elves: f64 = totals.len() as f64;
+        let mean: f64 = totals.iter().map(|total| *total as f64).sum::<f64>() / number_elves;
+        let variance: f64 = totals.iter()
+            .map(|total| (*total as f64 - mean).powi(2))
+            .sum::<f64>() / number_elves;
+
+        let mut snack_counts: BTreeMap<usize, usize> = BTreeMap::new();
+        for elf in elves.iter() {
+            *snack_counts.entry(elf.get_number_snacks()).or_insert(0) += 1;
+        }
+
+        let first_quartile: f64 = compute_percentile(&sorted_totals, 25.0);
+        let third_quartile: f64 = compute_percentile(&sorted_totals, 75.0);
+        let fence: f64 = OUTLIER_FENCE * (third_quartile - first_quartile);
+
+        let outliers: Vec<ElfTotal> = totals.iter()
+            .enumerate()
+            .filter(|(_, total)| (**total as f64) < first_quartile - fence || (**total as f64) > third_quartile + fence)
+            .map(|(index, total)| ElfTotal { index, name: elves[index].get_name().map(|name| name.to_owned()), total: *total })
+            .collect();
+
+        return Some(CalorieReport {
+            elves: totals.len(),
+            mean,
+            median: compute_percentile(&sorted_totals, 50.0),
+            standard_deviation: variance.sqrt(),
+            percentiles: REPORT_PERCENTILES.iter()
+                .map(|rank| Percentile { rank: *rank, value: compute_percentile(&sorted_totals, *rank as f64) })
+                .collect(),
+            snack_counts,
+            histogram: develop_histogram(&sorted_totals, histogram_bins),
+            outliers
+        });
+    }
+}
+
+impl fmt::Display for CalorieReport {
+
+    /// Writes the report as a two column table, followed by the distributions.
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+
+        writeln!(formatter, "{:<24} {:>12}", "Elves", self.elves)?;
+        writeln!(formatter, "{:<24} {:>12.2}", "Mean", self.mean)?;
+        writeln!(formatter, "{:<24} {:>12.2}", "Median", self.median)?;
+        writeln!(formatter, "{:<24} {:>12.2}", "Standard deviation", self.standard_deviation)?;
+        for percentile in self.percentiles.iter() {
+            writeln!(formatter, "{:<24} {:>12.2}", format!("Percentile {}", percentile.rank), percentile.value)?;
+        }
+
+        writeln!(formatter)?;
+        writeln!(formatter, "{:<24} {:>12}", "Snacks", "Elves")?;
+        for (snacks, elves) in self.snack_counts.iter() {
+            writeln!(formatter, "{:<24} {:>12}", snacks, elves)?;
+        }
+
+        writeln!(formatter)?;
+        writeln!(formatter, "{:<24} {:>12}", "Calories", "Elves")?;
+        for bin in self.histogram.iter() {
+            writeln!(formatter, "{:<24} {:>12}", format!("{} - {}", bin.start, bin.end - 1), bin.elves)?;
+        }
+
+        writeln!(formatter)?;
+        writeln!(formatter, "{:<24} {:>12}", "Outlier elf", "Calories")?;
+        for outlier in self.outliers.iter() {
+            writeln!(formatter, "{:<24} {:>12}", outlier.to_string(), outlier.total)?;
+        }
+
+        return Ok(());
+    }
+}
\ No newline at end of file
@@ -0,0 +1,50 @@
+use day_01::Elf;
+use day_01::formats::{elves_from_csv, elves_from_json, elves_to_csv, elves_to_json};
+
+fn develop_elves() -> Vec<Elf> {
+
+    return vec![
+        Elf::new(vec![1000, 2000, 3000]),
+        Elf::new_annotated(Some("Alice".to_owned()), vec![(4000, Some("apple".to_owned())), (500, None)]),
+        Elf::new_annotated(None, vec![(6000, Some("pie, with cream".to_owned()))])
+    ];
+}
+
+#[test]
+fn json_round_trip() {
+
+    let elves: Vec<Elf> = develop_elves();
+    let json: String = elves_to_json(&elves).unwrap();
+    assert_eq!(json, r#"[[1000,2000,3000],{"name":"Alice","snacks":[{"calories":4000,"label":"apple"},500]},[{"calories":6000,"label":"pie, with cream"}]]"#);
+    assert_eq!(elves_from_json(&json).unwrap(), elves);
+
+    // An elf without snacks is an empty list
+    assert_eq!(elves_from_json(&elves_to_json(&[Elf::new(Vec::new())]).unwrap()).unwrap(), vec![Elf::new(Vec::new())]);
+}
+
+#[test]
+fn csv_round_trip() {
+
+    let elves: Vec<Elf> = develop_elves();
+    let csv: String = elves_to_csv(&elves).unwrap();
+    assert_eq!(csv, "elf,calories,name,label\n0,1000,,\n0,2000,,\n0,3000,,\n1,4000,Alice,apple\n1,500,Alice,\n2,6000,,\"pie, with cream\"\n");
+    assert_eq!(elves_from_csv(&csv).unwrap(), elves);
+
+    // Rows only need the elf and the calories, in any order of the elves
+    assert_eq!(elves_from_csv("elf,calories\n7,10\n3,20\n7,30\n").unwrap(), vec![Elf::new(vec![10, 30]), Elf::new(vec![20])]);
+
+    // Elves without snacks keep their row, their name and their position
+    let elves: Vec<Elf> = vec![Elf::new(vec![10]), Elf::new_annotated(Some("Bob".to_owned()), Vec::new()), Elf::new(Vec::new()), Elf::new(vec![20])];
+    let csv: String = elves_to_csv(&elves).unwrap();
+    assert_eq!(csv, "elf,calories,name,label\n0,10,,\n1,,Bob,\n2,,,\n3,20,,\n");
+    assert_eq!(elves_from_csv(&csv).unwrap(), elves);
+}
+
+#[test]
+fn malformed_records_are_errors() {
+
+    assert!(elves_from_json("[[1000, \"two\"]]").is_err());
+    assert!(elves_from_json("{\"snacks\": [1]}").is_err());
+    assert!(elves_from_csv("elf,calories\n0,lots\n").is_err());
+    assert!(elves_from_csv("elf\n0\n").is_err());
+}
@@ -0,0 +1,56 @@
+use proptest::prelude::*;
+
+use day_01::{Elf, InventoryParseError, SnackCalories, convert_input_to_correct_format, convert_input_to_inventory};
+
+fn develop_lines(lines: &[&str]) -> Vec<String> {
+    return lines.iter().map(|line| line.to_string()).collect();
+}
+
+/// Input of the plain format: the calories of each snack, with a blank line between elves.
+fn develop_plain_input(elves_snacks: &[Vec<SnackCalories>]) -> Vec<String> {
+
+    return elves_snacks.iter()
+        .map(|snacks| snacks.iter().map(|calories| calories.to_string()).collect::<Vec<String>>())
+        .collect::<Vec<Vec<String>>>()
+        .join(&String::new());
+}
+
+#[test]
+fn example_plain_input() {
+
+    let lines: Vec<String> = develop_lines(&["1000", "2000", "3000", "", "4000", "", "5000", "6000", "", "7000", "8000", "9000", "", "10000"]);
+    let elves_snacks: Vec<Vec<SnackCalories>> = vec![vec![1000, 2000, 3000], vec![4000], vec![5000, 6000], vec![7000, 8000, 9000], vec![10000]];
+
+    assert_eq!(convert_input_to_correct_format(lines.clone()), elves_snacks);
+    assert_eq!(convert_input_to_inventory(&lines), Ok(elves_snacks.into_iter().map(Elf::new).collect()));
+}
+
+#[test]
+fn snack_lines_without_calories_are_errors() {
+
+    assert_eq!(convert_input_to_inventory(&develop_lines(&["1000", "abc"])), Err(InventoryParseError { line: 2, calories: "abc".to_owned() }));
+    assert_eq!(convert_input_to_inventory(&develop_lines(&["# Alice", "", "12x apple // lunch"])), Err(InventoryParseError { line: 3, calories: "12x".to_owned() }));
+    assert_eq!(convert_input_to_inventory(&develop_lines(&["99999999999"])), Err(InventoryParseError { line: 1, calories: "99999999999".to_owned() }));
+    assert_eq!(InventoryParseError { line: 2, calories: "abc".to_owned() }.to_string(), "line 2: expected the calories of a snack, found 'abc'");
+
+    // Lines holding only a comment are skipped
+    let elves: Vec<Elf> = convert_input_to_inventory(&develop_lines(&["// nothing yet", "-5 // owed", "  "])).unwrap();
+    assert_eq!(elves, vec![Elf::new(vec![-5]), Elf::new(Vec::new())]);
+}
+
+#[test]
+#[should_panic(expected = "line 1: expected the calories of a snack, found 'x'")]
+fn plain_format_panics_on_errors() {
+    convert_input_to_correct_format(develop_lines(&["x"]));
+}
+
+proptest! {
+
+    #[test]
+    fn plain_input_reads_back(elves_snacks in prop::collection::vec(prop::collection::vec(any::<SnackCalories>(), 1 .. 6), 1 .. 10)) {
+
+        let lines: Vec<String> = develop_plain_input(&elves_snacks);
+        prop_assert_eq!(convert_input_to_correct_format(lines.clone()), elves_snacks.clone());
+        prop_assert_eq!(convert_input_to_inventory(&lines), Ok(elves_snacks.into_iter().map(Elf::new).collect::<Vec<Elf>>()));
+    }
+}
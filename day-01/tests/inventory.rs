@@ -0,0 +1,118 @@
+use day_01::{Elf, convert_input_to_inventory};
+use day_01::inventory::{Comparison, ElfCondition, Inventory, QueryParseError, parse_query_expression};
+
+const INPUT: [&str; 14] = [
+    "# Alice", "1000 apple", "2000", "",
+    "# Mary Ann", "4000",
+    "# Bread and Butter", "5000", "6000", "",
+    "7000", "8000", "9000", "# \"Q\" \\o/"
+];
+
+fn develop_inventory() -> Inventory {
+    return Inventory::new(convert_input_to_inventory(&INPUT.map(|line| line.to_owned())).unwrap());
+}
+
+fn matching_positions(inventory: &Inventory, expression: &str) -> Vec<usize> {
+
+    return inventory.query_expression(expression).unwrap().elves().into_iter()
+        .map(|(position, _)| position)
+        .collect();
+}
+
+fn parse_error(expression: &str) -> QueryParseError {
+    return parse_query_expression(expression).unwrap_err();
+}
+
+#[test]
+fn every_field_and_operator() {
+
+    assert_eq!(parse_query_expression("total > 50000 and snack <= 3 and snacks != 2 and name = Alice"), Ok(vec![
+        ElfCondition::TotalCalories(Comparison::Greater, 50000),
+        ElfCondition::AnySnackCalories(Comparison::LessOrEqual, 3),
+        ElfCondition::NumberSnacks(Comparison::NotEqual, 2),
+        ElfCondition::Name("Alice".to_owned())
+    ]));
+
+    for (operator, comparison) in [("<", Comparison::Less), ("<=", Comparison::LessOrEqual), ("=", Comparison::Equal), ("==", Comparison::Equal),
+        ("!=", Comparison::NotEqual), (">=", Comparison::GreaterOrEqual), (">", Comparison::Greater)] {
+        assert_eq!(format!("total {} -5", operator).parse(), Ok(ElfCondition::TotalCalories(comparison, -5)));
+    }
+}
+
+#[test]
+fn expressions_match_the_elves_of_the_inventory() {
+
+    let inventory: Inventory = develop_inventory();
+    assert_eq!(inventory.get_elves().len(), 5);
+
+    assert_eq!(matching_positions(&inventory, "total > 4000"), vec![2, 3]);
+    assert_eq!(matching_positions(&inventory, "total >= 3000 and snacks = 1"), vec![1]);
+    assert_eq!(matching_positions(&inventory, "snack > 8000"), vec![3]);
+    assert_eq!(matching_positions(&inventory, "snacks < 2"), vec![1, 4]);
+    assert_eq!(matching_positions(&inventory, "name = Alice"), vec![0]);
+    assert_eq!(matching_positions(&inventory, "name = Bob"), Vec::<usize>::new());
+    assert_eq!(inventory.query_expression("total < 100000 and snacks > 0").unwrap().count(), 4);
+}
+
+#[test]
+fn quoted_names_hold_spaces_and_the_separator() {
+
+    let inventory: Inventory = develop_inventory();
+
+    assert_eq!(matching_positions(&inventory, r#"name = "Mary Ann""#), vec![1]);
+    assert_eq!(matching_positions(&inventory, r#"name = "Bread and Butter""#), vec![2]);
+    assert_eq!(matching_positions(&inventory, r#"name = "Bread and Butter" and total = 11000"#), vec![2]);
+    assert_eq!(matching_positions(&inventory, r#"total = 11000 and name = "Mary Ann""#), Vec::<usize>::new());
+    assert_eq!(matching_positions(&inventory, r#"name = "\"Q\" \\o/""#), vec![4]);
+    assert_eq!(matching_positions(&inventory, r#"name = "" "#), Vec::<usize>::new());
+
+    // Quotes work for any value, and an unquoted `and` inside a word is not a separator
+    assert_eq!(parse_query_expression(r#"total > "10" and name = android"#), Ok(vec![
+        ElfCondition::TotalCalories(Comparison::Greater, 10),
+        ElfCondition::Name("android".to_owned())
+    ]));
+    assert_eq!(r#"name = "and""#.parse(), Ok(ElfCondition::Name("and".to_owned())));
+}
+
+#[test]
+fn unquoted_names_with_spaces_are_errors() {
+
+    assert_eq!(parse_error("name = Mary Ann"), QueryParseError {
+        condition: "name = Mary Ann".to_owned(),
+        reason: "expected '<field> <operator> <value>'".to_owned()
+    });
+    assert_eq!(parse_error("total > 5 and name = Bread and Butter").condition, "Butter");
+}
+
+#[test]
+fn malformed_expressions_are_errors() {
+
+    for (expression, condition, reason) in [
+        ("", "", "expected '<field> <operator> <value>'"),
+        ("total > 5 and", "", "expected '<field> <operator> <value>'"),
+        ("  total  ~  5 ", "total  ~  5", "unknown operator '~'"),
+        ("weight > 5", "weight > 5", "unknown field 'weight'"),
+        ("total > many", "total > many", "'many' is not a number of calories"),
+        ("snack > 1.5", "snack > 1.5", "'1.5' is not a number of calories"),
+        ("snacks = -1", "snacks = -1", "'-1' is not a number of snacks"),
+        ("name != Alice", "name != Alice", "names can only be compared with '='"),
+        (r#"name = "Alice"#, r#""Alice"#, "missing closing quote"),
+        (r#"name = "Alice\""#, r#""Alice\""#, "missing closing quote")
+    ] {
+        assert_eq!(parse_error(expression), QueryParseError { condition: condition.to_owned(), reason: reason.to_owned() }, "{}", expression);
+    }
+
+    assert_eq!(parse_error("total > x").to_string(), "invalid condition 'total > x': 'x' is not a number of calories");
+}
+
+#[test]
+fn builder_matches_the_expressions() {
+
+    let inventory: Inventory = develop_inventory();
+
+    let built: Vec<(usize, &Elf)> = inventory.query().total_calories_above(4000).any_snack_above(5500).elves();
+    assert_eq!(built, inventory.query_expression("total > 4000 and snack > 5500").unwrap().elves());
+    assert_eq!(inventory.query().total_calories_below(4000).count(), 2);
+    assert_eq!(inventory.query().number_snacks(2).named("Bread and Butter").count(), 1);
+    assert_eq!(inventory.query().count(), 5);
+}
@@ -0,0 +1,163 @@
+use proptest::prelude::*;
+
+use day_01::SnackCalories;
+use day_01::redistribution::{EXACT_SNACK_LIMIT, PartitionMethod, RedistributionObjective, RedistributionPlan, plan_redistribution};
+
+const OBJECTIVES: [RedistributionObjective; 2] = [RedistributionObjective::MaximumCalories, RedistributionObjective::Variance];
+
+fn cost(plan: &RedistributionPlan, objective: RedistributionObjective) -> i64 {
+    return objective.evaluate(&plan.totals);
+}
+
+/// Snacks each elf ends up with, as positions in the original lists, rebuilt from the transfers.
+fn develop_final_snacks(elves_snacks: &[Vec<SnackCalories>], plan: &RedistributionPlan) -> Vec<Vec<(usize, usize)>> {
+
+    let mut final_snacks: Vec<Vec<(usize, usize)>> = vec![Vec::new(); elves_snacks.len()];
+    for (elf, snacks) in elves_snacks.iter().enumerate() {
+        for snack in 0 .. snacks.len() {
+
+            let owner: usize = plan.transfers.iter()
+                .find(|transfer| transfer.from_elf == elf && transfer.snack == snack)
+                .map_or(elf, |transfer| transfer.to_elf);
+            final_snacks[owner].push((elf, snack));
+        }
+    }
+
+    return final_snacks;
+}
+
+/// Largest number of snacks the elves can keep when the same bins are handed out in any order.
+fn brute_force_kept_snacks(bins: &[Vec<(usize, usize)>]) -> usize {
+
+    fn search(bins: &[Vec<(usize, usize)>], bin: usize, used: &mut Vec<bool>) -> usize {
+
+        if bin == bins.len() { return 0 }
+
+        let mut most_kept: usize = 0;
+        for elf in 0 .. bins.len() {
+
+            if used[elf] { continue }
+            used[elf] = true;
+            let kept: usize = bins[bin].iter().filter(|(owner, _)| *owner == elf).count() + search(bins, bin + 1, used);
+            most_kept = most_kept.max(kept);
+            used[elf] = false;
+        }
+
+        return most_kept;
+    }
+
+    return search(bins, 0, &mut vec![false; bins.len()]);
+}
+
+fn check_plan(elves_snacks: &[Vec<SnackCalories>], plan: &RedistributionPlan) {
+
+    let final_snacks: Vec<Vec<(usize, usize)>> = develop_final_snacks(elves_snacks, plan);
+    for (elf, snacks) in final_snacks.iter().enumerate() {
+        assert_eq!(plan.totals[elf], snacks.iter().map(|(owner, snack)| elves_snacks[*owner][*snack]).sum::<SnackCalories>());
+    }
+
+    for transfer in plan.transfers.iter() {
+        assert_ne!(transfer.from_elf, transfer.to_elf);
+        assert_eq!(transfer.calories, elves_snacks[transfer.from_elf][transfer.snack]);
+    }
+
+    let number_snacks: usize = elves_snacks.iter().map(|snacks| snacks.len()).sum();
+    assert_eq!(number_snacks - plan.transfers.len(), brute_force_kept_snacks(&final_snacks));
+}
+
+fn small_inventory(calories: std::ops::Range<SnackCalories>) -> impl Strategy<Value = Vec<Vec<SnackCalories>>> {
+    return prop::collection::vec(prop::collection::vec(calories, 0 .. 4), 1 .. 5)
+        .prop_filter("at most the exact limit of snacks", |elves| elves.iter().map(|snacks| snacks.len()).sum::<usize>() <= 9);
+}
+
+#[test]
+fn balanced_example() {
+
+    let elves_snacks: Vec<Vec<SnackCalories>> = vec![vec![9, 1], vec![5], vec![3, 2]];
+    let plan: RedistributionPlan = plan_redistribution(&elves_snacks, PartitionMethod::Exact, RedistributionObjective::MaximumCalories);
+
+    // 9 on its own, 5 + 3 + 1 and 2
+    check_plan(&elves_snacks, &plan);
+    assert_eq!(plan.totals.iter().max(), Some(&9));
+    assert_eq!(plan.totals.iter().sum::<SnackCalories>(), 20);
+}
+
+#[test]
+fn assignment_beats_pairing_the_largest_shares_first() {
+
+    // Equal snacks split into bins mixing the elves, where pairing first the elf and bin that
+    // share the most snacks leaves the other elves with bins they share nothing with
+    let elves_snacks: Vec<Vec<SnackCalories>> = vec![vec![4, 4, 4], vec![4, 4, 4], vec![0]];
+    for method in [PartitionMethod::Greedy, PartitionMethod::KarmarkarKarp, PartitionMethod::Exact] {
+        check_plan(&elves_snacks, &plan_redistribution(&elves_snacks, method, RedistributionObjective::Variance));
+    }
+}
+
+#[test]
+fn exact_handles_negative_snacks() {
+
+    // Every partial assignment holds 10 in a bin, so pruning on the partial loads would keep the
+    // first complete one, 6 against 0, and miss 10 + 1 - 8 against 3
+    let elves_snacks: Vec<Vec<SnackCalories>> = vec![vec![10, -8], vec![3, 1]];
+    let plan: RedistributionPlan = plan_redistribution(&elves_snacks, PartitionMethod::Exact, RedistributionObjective::MaximumCalories);
+
+    check_plan(&elves_snacks, &plan);
+    assert_eq!(plan.totals.iter().max(), Some(&3));
+}
+
+#[test]
+fn auto_searches_exactly_up_to_the_limit() {
+
+    for number_snacks in [EXACT_SNACK_LIMIT, EXACT_SNACK_LIMIT + 1] {
+
+        // Both heuristics split 3, 3, 2, 2, 2 as 7 against 5 instead of 6 against 6
+        let mut snacks: Vec<SnackCalories> = vec![3, 3, 2, 2, 2];
+        snacks.resize(number_snacks, 0);
+        let elves_snacks: Vec<Vec<SnackCalories>> = vec![snacks, Vec::new()];
+
+        let exact: RedistributionPlan = plan_redistribution(&elves_snacks, PartitionMethod::Exact, RedistributionObjective::MaximumCalories);
+        let best_heuristic: i64 = [PartitionMethod::Greedy, PartitionMethod::KarmarkarKarp].into_iter()
+            .map(|method| cost(&plan_redistribution(&elves_snacks, method, RedistributionObjective::MaximumCalories), RedistributionObjective::MaximumCalories))
+            .min()
+            .unwrap();
+        assert_eq!((cost(&exact, RedistributionObjective::MaximumCalories), best_heuristic), (6, 7));
+
+        let auto: RedistributionPlan = plan_redistribution(&elves_snacks, PartitionMethod::Auto, RedistributionObjective::MaximumCalories);
+        check_plan(&elves_snacks, &auto);
+        assert_eq!(cost(&auto, RedistributionObjective::MaximumCalories), if number_snacks <= EXACT_SNACK_LIMIT { 6 } else { 7 });
+    }
+}
+
+proptest! {
+
+    #[test]
+    fn exact_is_never_worse_than_the_heuristics(elves_snacks in small_inventory(0 .. 50)) {
+
+        for objective in OBJECTIVES {
+
+            let exact: RedistributionPlan = plan_redistribution(&elves_snacks, PartitionMethod::Exact, objective);
+            check_plan(&elves_snacks, &exact);
+
+            for method in [PartitionMethod::Greedy, PartitionMethod::KarmarkarKarp, PartitionMethod::Auto] {
+
+                let heuristic: RedistributionPlan = plan_redistribution(&elves_snacks, method, objective);
+                check_plan(&elves_snacks, &heuristic);
+                prop_assert!(cost(&exact, objective) <= cost(&heuristic, objective), "{:?} {:?}", method, objective);
+            }
+        }
+    }
+
+    #[test]
+    fn exact_is_never_worse_with_negative_snacks(elves_snacks in small_inventory(-20 .. 20)) {
+
+        for objective in OBJECTIVES {
+
+            let exact: RedistributionPlan = plan_redistribution(&elves_snacks, PartitionMethod::Exact, objective);
+            check_plan(&elves_snacks, &exact);
+
+            for method in [PartitionMethod::Greedy, PartitionMethod::KarmarkarKarp] {
+                prop_assert!(cost(&exact, objective) <= cost(&plan_redistribution(&elves_snacks, method, objective), objective));
+            }
+        }
+    }
+}
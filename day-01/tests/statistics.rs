@@ -0,0 +1,87 @@
+use proptest::prelude::*;
+
+use day_01::{Elf, SnackCalories};
+use day_01::statistics::{CalorieReport, HistogramBin, Percentile, REPORT_PERCENTILES};
+
+fn develop_elves(totals: &[SnackCalories]) -> Vec<Elf> {
+    return totals.iter().map(|total| Elf::new(vec![*total])).collect();
+}
+
+fn develop_bins(bins: &[(i64, i64, usize)]) -> Vec<HistogramBin> {
+    return bins.iter().map(|(start, end, elves)| HistogramBin { start: *start, end: *end, elves: *elves }).collect();
+}
+
+#[test]
+fn percentiles_interpolate_between_ranks() {
+
+    let report: CalorieReport = CalorieReport::new(&develop_elves(&[4000, 1000, 3000, 2000]), 10).unwrap();
+    assert_eq!(report.median, 2500.0);
+    assert_eq!(report.percentiles, REPORT_PERCENTILES.iter().zip([1300.0, 1750.0, 3250.0, 3700.0, 3970.0])
+        .map(|(rank, value)| Percentile { rank: *rank, value })
+        .collect::<Vec<Percentile>>());
+
+    // A single elf is every percentile
+    let report: CalorieReport = CalorieReport::new(&develop_elves(&[-7]), 10).unwrap();
+    assert!(report.percentiles.iter().all(|percentile| percentile.value == -7.0));
+    assert_eq!((report.median, report.standard_deviation), (-7.0, 0.0));
+    assert!(CalorieReport::new(&[], 10).is_none());
+}
+
+#[test]
+fn histogram_edges() {
+
+    // Ten totals in ten bins give a bin to each, and an eleventh widens them to two calories
+    let report: CalorieReport = CalorieReport::new(&develop_elves(&(0 .. 10).collect::<Vec<SnackCalories>>()), 10).unwrap();
+    assert_eq!(report.histogram, (0 .. 10).map(|start| HistogramBin { start, end: start + 1, elves: 1 }).collect::<Vec<HistogramBin>>());
+    let report: CalorieReport = CalorieReport::new(&develop_elves(&[0, 10]), 10).unwrap();
+    assert_eq!(report.histogram, develop_bins(&[(0, 2, 1), (2, 4, 0), (4, 6, 0), (6, 8, 0), (8, 10, 0), (10, 12, 1)]));
+
+    // Asking for no bins, or for more bins than calories, still covers every total
+    assert_eq!(CalorieReport::new(&develop_elves(&[3, 5]), 0).unwrap().histogram, develop_bins(&[(3, 6, 2)]));
+    assert_eq!(CalorieReport::new(&develop_elves(&[3, 5]), usize::MAX).unwrap().histogram, develop_bins(&[(3, 4, 1), (4, 5, 0), (5, 6, 1)]));
+}
+
+#[test]
+fn histogram_over_every_total() {
+
+    let report: CalorieReport = CalorieReport::new(&develop_elves(&[SnackCalories::MIN, 0, SnackCalories::MAX]), 4).unwrap();
+    let quarter: i64 = 1 << 30;
+    assert_eq!(report.histogram, develop_bins(&[(-2 * quarter, -quarter, 1), (-quarter, 0, 0), (0, quarter, 1), (quarter, 2 * quarter, 1)]));
+
+    // The last bin ends one past the largest total
+    assert_eq!(report.histogram[3].end, i64::from(SnackCalories::MAX) + 1);
+    assert!(report.to_string().contains(&format!("{} - {}", quarter, SnackCalories::MAX)));
+}
+
+proptest! {
+
+    #[test]
+    fn histogram_bins_hold_every_total(totals in prop::collection::vec(any::<SnackCalories>(), 1 .. 30), number_bins in 1usize .. 20) {
+
+        let histogram: Vec<HistogramBin> = CalorieReport::new(&develop_elves(&totals), number_bins).unwrap().histogram;
+        let bin_width: i64 = histogram[0].end - histogram[0].start;
+
+        prop_assert!(histogram.len() <= number_bins);
+        prop_assert!(histogram.iter().all(|bin| bin.end - bin.start == bin_width));
+        prop_assert!(histogram.windows(2).all(|pair| pair[0].end == pair[1].start));
+        prop_assert_eq!(histogram[0].start, i64::from(*totals.iter().min().unwrap()));
+        prop_assert!(histogram.last().unwrap().end > i64::from(*totals.iter().max().unwrap()));
+
+        for bin in histogram.iter() {
+            let elves: usize = totals.iter().filter(|total| bin.start <= i64::from(**total) && i64::from(**total) < bin.end).count();
+            prop_assert_eq!(bin.elves, elves);
+        }
+    }
+
+    #[test]
+    fn percentiles_stay_between_the_totals(totals in prop::collection::vec(-1_000_000i32 .. 1_000_000, 1 .. 30)) {
+
+        let report: CalorieReport = CalorieReport::new(&develop_elves(&totals), 10).unwrap();
+        let minimum: f64 = f64::from(*totals.iter().min().unwrap());
+        let maximum: f64 = f64::from(*totals.iter().max().unwrap());
+
+        prop_assert!(report.percentiles.windows(2).all(|pair| pair[0].value <= pair[1].value));
+        prop_assert!(report.percentiles.iter().all(|percentile| minimum <= percentile.value && percentile.value <= maximum));
+        prop_assert!(report.percentiles[1].value <= report.median && report.median <= report.percentiles[2].value);
+    }
+}
@@ -0,0 +1,61 @@
+use proptest::prelude::*;
+
+use day_01::{Elf, ElfTotal, SnackCalories, top_k_elves};
+
+fn develop_positions(top_elves: &[ElfTotal]) -> Vec<(usize, SnackCalories)> {
+    return top_elves.iter().map(|elf_total| (elf_total.index, elf_total.total)).collect();
+}
+
+#[test]
+fn example_top_elves() {
+
+    let elves: Vec<Elf> = [vec![1000, 2000, 3000], vec![4000], vec![5000, 6000], vec![7000, 8000, 9000], vec![10000]]
+        .into_iter()
+        .map(Elf::new)
+        .collect();
+
+    assert_eq!(develop_positions(&top_k_elves(&elves, 1)), vec![(3, 24000)]);
+    assert_eq!(develop_positions(&top_k_elves(&elves, 3)), vec![(3, 24000), (2, 11000), (4, 10000)]);
+}
+
+#[test]
+fn more_elves_asked_than_carried() {
+
+    let elves: Vec<Elf> = vec![Elf::new(vec![5]), Elf::new_annotated(Some("Alice".to_owned()), vec![(9, None)])];
+    let top_elves: Vec<ElfTotal> = top_k_elves(&elves, 10);
+    assert_eq!(develop_positions(&top_elves), vec![(1, 9), (0, 5)]);
+    assert_eq!(top_elves[0].name.as_deref(), Some("Alice"));
+
+    assert!(top_k_elves(Vec::<Elf>::new(), 3).is_empty());
+}
+
+#[test]
+fn no_elves_asked() {
+
+    let elves: Vec<Elf> = vec![Elf::new(vec![5]), Elf::new(vec![9])];
+    assert!(top_k_elves(&elves, 0).is_empty());
+}
+
+#[test]
+fn ties_go_to_the_first_elf() {
+
+    let elves: Vec<Elf> = [vec![3], vec![1, 2], vec![5], vec![2, 1], vec![3]].into_iter().map(Elf::new).collect();
+    assert_eq!(develop_positions(&top_k_elves(&elves, 2)), vec![(2, 5), (0, 3)]);
+    assert_eq!(develop_positions(&top_k_elves(&elves, 3)), vec![(2, 5), (0, 3), (1, 3)]);
+    assert_eq!(develop_positions(&top_k_elves(&elves, 5)), vec![(2, 5), (0, 3), (1, 3), (3, 3), (4, 3)]);
+}
+
+proptest! {
+
+    #[test]
+    fn top_elves_match_a_stable_sort(totals in prop::collection::vec(-20i32 .. 20, 0 .. 30), k in 0usize .. 35) {
+
+        let elves: Vec<Elf> = totals.iter().map(|total| Elf::new(vec![*total])).collect();
+
+        let mut sorted_elves: Vec<(usize, SnackCalories)> = totals.iter().copied().enumerate().collect();
+        sorted_elves.sort_by_key(|(_, total)| std::cmp::Reverse(*total));
+        sorted_elves.truncate(k);
+
+        prop_assert_eq!(develop_positions(&top_k_elves(&elves, k)), sorted_elves);
+    }
+}
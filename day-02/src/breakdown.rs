@@ -0,0 +1,169 @@
+//! Round by round account of how a strategy guide scores, to audit it.
+//!
+//! Traces and their summary are written to CSV through [`crate::formats::round_traces_to_csv`]
+//! and [`crate::formats::guide_summary_to_csv`].
+
+use std::fmt;
+use serde::Serialize;
+
+use crate::{GameResult, GuideError, GuideRound, Score};
+use crate::{resolve_round_setting_outcome, resolve_round_setting_play};
+use crate::engine::{CyclicGame, MoveID};
+use crate::symbols::GuideSymbols;
+
+// =========================================== TYPE AND STRUCT DEFINITIONS ===========================================
+
+/// How a single round of the guide was played and scored.
+#[derive(Clone, PartialEq, Eq, Debug, Serialize)]
+pub struct RoundTrace {
+    pub line: usize,
+    pub opponent_move: String,
+    pub chosen_move: String,
+    /// Move of the game behind `chosen_move`, which tells apart moves sharing a name.
+    #[serde(skip)]
+    pub chosen_move_id: MoveID,
+    pub outcome: GameResult,
+    pub shape_points: Score,
+    pub outcome_points: Score,
+    /// Score of the guide up to this round included.
+    pub running_total: Score
+}
+
+/// Rounds played with one move, and the points they brought.
+#[derive(Clone, PartialEq, Eq, Debug, Serialize)]
+pub struct MoveContribution {
+    pub chosen_move: String,
+    pub rounds: usize,
+    pub wins: usize,
+    pub draws: usize,
+    pub losses: usize,
+    pub shape_points: Score,
+    pub outcome_points: Score
+}
+
+/// Outcomes over the whole guide, and the points brought by each move of the game.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct GuideSummary {
+    pub wins: usize,
+    pub draws: usize,
+    pub losses: usize,
+    pub total: Score,
+    pub move_contributions: Vec<MoveContribution>
+}
+
+/// A traced round whose chosen move is not a move of the summarized game.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct UnknownMoveError {
+    pub line: usize,
+    pub chosen_move: String
+}
+
+// =============================================== AUXILIARY FUNCTIONS ===============================================
+
+/// Move of the game chosen in the trace: its id when the game names it as the trace does, or
+/// else the first move of the game with the traced name.
+fn resolve_chosen_move(game: &CyclicGame, trace: &RoundTrace) -> Result<MoveID, UnknownMoveError> {
+
+    if trace.chosen_move_id < game.get_number_moves() && game.get_move_name(trace.chosen_move_id) == trace.chosen_move {
+        return Ok(trace.chosen_move_id);
+    }
+
+    return game.get_move_id(&trace.chosen_move)
+        .ok_or_else(|| UnknownMoveError { line: trace.line, chosen_move: trace.chosen_move.clone() });
+}
+
+fn develop_round_trace(game: &CyclicGame, round: &GuideRound, play_other: MoveID, play_self: MoveID, outcome: GameResult, previous_total: Score) -> RoundTrace {
+
+    let shape_points: Score = game.get_move_score(play_self);
+    let outcome_points: Score = game.get_outcome_scores().get_score(outcome);
+
+    return RoundTrace {
+        line: round.line,
+        opponent_move: game.get_move_name(play_other).to_owned(),
+        chosen_move: game.get_move_name(play_self).to_owned(),
+        chosen_move_id: play_self,
+        outcome,
+        shape_points,
+        outcome_points,
+        running_total: previous_total + shape_points + outcome_points
+    };
+}
+
+fn develop_round_traces<F>(game: &CyclicGame, rounds: &[GuideRound], resolve_round: F) -> Result<Vec<RoundTrace>, GuideError>
+    where F: Fn(&GuideRound) -> Result<(MoveID, MoveID, GameResult), GuideError> {
+
+    let mut traces: Vec<RoundTrace> = Vec::with_capacity(rounds.len());
+    for round in rounds.iter() {
+
+        let (play_other, play_self, outcome) = resolve_round(round)?;
+        let previous_total: Score = traces.last().map_or(0, |trace| trace.running_total);
+        traces.push(develop_round_trace(game, round, play_other, play_self, outcome, previous_total));
+    }
+
+    return Ok(traces);
+}
+
+/// Every round of the guide when its second column is the move to play.
+pub fn trace_guide_setting_play(game: &CyclicGame, symbols: &GuideSymbols, rounds: &[GuideRound]) -> Result<Vec<RoundTrace>, GuideError> {
+    return develop_round_traces(game, rounds, |round| resolve_round_setting_play(game, symbols, round));
+}
+
+/// Every round of the guide when its second column is the outcome the round must have.
+pub fn trace_guide_setting_outcome(game: &CyclicGame, symbols: &GuideSymbols, rounds: &[GuideRound]) -> Result<Vec<RoundTrace>, GuideError> {
+    return develop_round_traces(game, rounds, |round| resolve_round_setting_outcome(game, symbols, round));
+}
+
+// ================================================= IMPLEMENTATIONS =================================================
+
+impl GuideSummary {
+
+    /// Adds up the traced rounds, listing the moves in the order of the game, played or not.
+    ///
+    /// Rounds are told apart by their move id as long as the game gives it the traced name, and
+    /// looked up by name otherwise, so traces built by hand or for another game are still counted.
+    pub fn new(game: &CyclicGame, traces: &[RoundTrace]) -> Result<GuideSummary, UnknownMoveError> {
+
+        let mut move_contributions: Vec<MoveContribution> = (0 .. game.get_number_moves())
+            .map(|move_id| MoveContribution {
+                chosen_move: game.get_move_name(move_id).to_owned(),
+                rounds: 0,
+                wins: 0,
+                draws: 0,
+                losses: 0,
+                shape_points: 0,
+                outcome_points: 0
+            })
+            .collect();
+
+        for trace in traces.iter() {
+
+            let contribution: &mut MoveContribution = &mut move_contributions[resolve_chosen_move(game, trace)?];
+            contribution.rounds += 1;
+            contribution.shape_points += trace.shape_points;
+            contribution.outcome_points += trace.outcome_points;
+
+            match trace.outcome {
+                GameResult::Win => contribution.wins += 1,
+                GameResult::Draw => contribution.draws += 1,
+                GameResult::Lose => contribution.losses += 1
+            }
+        }
+
+        return Ok(GuideSummary {
+            wins: move_contributions.iter().map(|contribution| contribution.wins).sum(),
+            draws: move_contributions.iter().map(|contribution| contribution.draws).sum(),
+            losses: move_contributions.iter().map(|contribution| contribution.losses).sum(),
+            total: traces.last().map_or(0, |trace| trace.running_total),
+            move_contributions
+        });
+    }
+}
+
+impl fmt::Display for UnknownMoveError {
+
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        return write!(formatter, "line {}: '{}' is not a move of the game", self.line, self.chosen_move);
+    }
+}
+
+impl std::error::Error for UnknownMoveError {}
\ No newline at end of file
@@ -0,0 +1,131 @@
+//! Search over the possible meanings of the second column of a strategy guide.
+//!
+//! The second column is read either as the move to play, under any assignment of its symbols to
+//! distinct moves, or as the outcome of the round, under any assignment of its symbols to distinct
+//! outcomes. Every such reading is scored, so that the most rewarding ones, or the ones matching
+//! a known total, can be picked.
+
+use std::collections::{BTreeSet, HashMap};
+
+use crate::{GameResult, GuideError, GuideRound, Score};
+use crate::{play_guide_setting_outcome, play_guide_setting_play};
+use crate::engine::{CyclicGame, MoveID};
+use crate::symbols::GuideSymbols;
+
+// =========================================== TYPE AND STRUCT DEFINITIONS ===========================================
+
+/// How the second column of the guide is read.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum GuideInterpretation {
+    SettingPlay,
+    SettingOutcome
+}
+
+/// A reading of the second column and the total score the guide gives under it.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct DecryptionCandidate {
+    pub interpretation: GuideInterpretation,
+    /// Symbols used to score the guide, where only the mapping of the second column under
+    /// `interpretation` differs between candidates.
+    pub symbols: GuideSymbols,
+    pub score: Score
+}
+
+const OUTCOMES: [GameResult; 3] = [GameResult::Lose, GameResult::Draw, GameResult::Win];
+
+// =============================================== AUXILIARY FUNCTIONS ===============================================
+
+/// Every way of assigning `number_symbols` symbols to distinct values among `number_values`.
+fn develop_injections(number_symbols: usize, number_values: usize) -> Vec<Vec<usize>> {
+
+    if number_symbols == 0 { return vec![Vec::new()] }
+
+    let mut injections: Vec<Vec<usize>> = Vec::new();
+    for partial_injection in develop_injections(number_symbols - 1, number_values) {
+        for value in (0 .. number_values).filter(|value| ! partial_injection.contains(value)) {
+
+            let mut injection: Vec<usize> = partial_injection.clone();
+            injection.push(value);
+            injections.push(injection);
+        }
+    }
+
+    return injections;
+}
+
+/// Scores the guide under every reading of its second column, from the highest score to the lowest.
+///
+/// The first column keeps the meaning given by `symbols`. Readings that ask for an outcome that
+/// no move can give are left out; any other error, such as an unknown symbol in the first column
+/// or more symbols in the second one than both moves and outcomes, is returned.
+pub fn rank_decryptions(game: &CyclicGame, symbols: &GuideSymbols, rounds: &[GuideRound]) -> Result<Vec<DecryptionCandidate>, GuideError> {
+
+    let response_symbols: Vec<String> = rounds.iter()
+        .map(|round| round.response.clone())
+        .collect::<BTreeSet<String>>()
+        .into_iter()
+        .collect();
+
+    if response_symbols.len() > game.get_number_moves() && response_symbols.len() > OUTCOMES.len() {
+        return Err(GuideError::TooManyResponseSymbols { symbols: response_symbols.len(), moves: game.get_number_moves(), outcomes: OUTCOMES.len() });
+    }
+
+    let mut candidates: Vec<DecryptionCandidate> = Vec::new();
+
+    for injection in develop_injections(response_symbols.len(), game.get_number_moves()) {
+
+        let self_moves: HashMap<String, MoveID> = response_symbols.iter().cloned()
+            .zip(injection)
+            .collect();
+        let candidate_symbols: GuideSymbols = GuideSymbols { self_moves, ..symbols.clone() };
+
+        let score: Score = play_guide_setting_play(game, &candidate_symbols, rounds)?;
+        candidates.push(DecryptionCandidate { interpretation: GuideInterpretation::SettingPlay, symbols: candidate_symbols, score });
+    }
+
+    for injection in develop_injections(response_symbols.len(), OUTCOMES.len()) {
+
+        let outcomes: HashMap<String, GameResult> = response_symbols.iter().cloned()
+            .zip(injection.into_iter().map(|outcome| OUTCOMES[outcome]))
+            .collect();
+        let candidate_symbols: GuideSymbols = GuideSymbols { outcomes, ..symbols.clone() };
+
+        match play_guide_setting_outcome(game, &candidate_symbols, rounds) {
+            Ok(score) => candidates.push(DecryptionCandidate { interpretation: GuideInterpretation::SettingOutcome, symbols: candidate_symbols, score }),
+            Err(GuideError::ImpossibleOutcome { .. }) => (),
+            Err(error) => return Err(error)
+        }
+    }
+
+    candidates.sort_by_key(|candidate| std::cmp::Reverse(candidate.score));
+    return Ok(candidates);
+}
+
+/// Readings of the second column under which the guide scores exactly `target`.
+pub fn infer_decryptions(game: &CyclicGame, symbols: &GuideSymbols, rounds: &[GuideRound], target: Score) -> Result<Vec<DecryptionCandidate>, GuideError> {
+
+    return Ok(rank_decryptions(game, symbols, rounds)?.into_iter()
+        .filter(|candidate| candidate.score == target)
+        .collect());
+}
+
+// ================================================= IMPLEMENTATIONS =================================================
+
+impl DecryptionCandidate {
+
+    /// Meaning of each symbol of the second column under this reading, sorted by symbol.
+    pub fn describe_mapping(&self, game: &CyclicGame) -> Vec<(String, String)> {
+
+        let mut mapping: Vec<(String, String)> = match self.interpretation {
+            GuideInterpretation::SettingPlay => self.symbols.self_moves.iter()
+                .map(|(symbol, move_id)| (symbol.clone(), game.get_move_name(*move_id).to_owned()))
+                .collect(),
+            GuideInterpretation::SettingOutcome => self.symbols.outcomes.iter()
+                .map(|(symbol, outcome)| (symbol.clone(), format!("{:?}", outcome)))
+                .collect()
+        };
+
+        mapping.sort();
+        return mapping;
+    }
+}
\ No newline at end of file
@@ -0,0 +1,228 @@
+//! Engine for any game where each player picks a move and a "beats" relation decides the round.
+//!
+//! Rock Paper Scissors is the smallest of the cyclic games with an odd number of moves, where
+//! each move beats the half of the other moves that come just before it in the cycle. Rock
+//! Paper Scissors Lizard Spock is the next one, and [`CyclicGame::cyclic`] builds any of them.
+
+use std::fmt;
+
+use crate::{GameResult, Score};
+
+// =========================================== TYPE AND STRUCT DEFINITIONS ===========================================
+
+/// A move of a game, as its position in the list of moves of the game.
+pub type MoveID = usize;
+
+/// Points awarded for each outcome of a round.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct OutcomeScores {
+    pub win: Score,
+    pub draw: Score,
+    pub lose: Score
+}
+
+/// A game defined by its moves, which move beats which, and how rounds are scored.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct CyclicGame {
+    moves: Vec<String>,
+    beats: Vec<Vec<bool>>,
+    move_scores: Vec<Score>,
+    outcome_scores: OutcomeScores
+}
+
+/// Reason why a game cannot be built from the given moves and relation.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum GameDefinitionError {
+    /// The game has no moves at all.
+    NoMoves,
+    /// A cyclic game needs an odd number of moves for every move to beat as many as it loses to.
+    EvenNumberOfMoves(usize),
+    /// The relation refers to a move past the end of the list of moves.
+    UnknownMove(MoveID),
+    /// A move is said to beat itself.
+    MoveBeatsItself(MoveID),
+    /// Two moves are said to beat each other.
+    MovesBeatEachOther(MoveID, MoveID),
+    /// The number of move scores does not match the number of moves.
+    WrongNumberOfScores { moves: usize, scores: usize }
+}
+
+// =============================================== AUXILIARY FUNCTIONS ===============================================
+
+fn develop_move_names(moves: &[&str]) -> Vec<String> {
+
+    return moves.iter()
+        .map(|move_name| move_name.to_string())
+        .collect();
+}
+
+// ================================================= IMPLEMENTATIONS =================================================
+
+impl Default for OutcomeScores {
+
+    /// Scores of the puzzle: 6 points for a win, 3 for a draw and none for a loss.
+    fn default() -> OutcomeScores {
+        OutcomeScores {
+            win: 6,
+            draw: 3,
+            lose: 0
+        }
+    }
+}
+
+impl OutcomeScores {
+
+    /// Points awarded for `outcome`.
+    pub fn get_score(&self, outcome: GameResult) -> Score {
+
+        return match outcome {
+            GameResult::Win => self.win,
+            GameResult::Draw => self.draw,
+            GameResult::Lose => self.lose
+        };
+    }
+}
+
+impl CyclicGame {
+
+    /// Game over `moves` where each `(winner, loser)` pair of `beats` says which move wins, and any
+    /// other pair of moves draws.
+    ///
+    /// Moves score one point more than the move before them, starting at 1, and outcomes score as
+    /// in the puzzle; both can be changed with [`CyclicGame::with_move_scores`] and
+    /// [`CyclicGame::with_outcome_scores`].
+    pub fn new(moves: Vec<String>, beats: &[(MoveID, MoveID)]) -> Result<CyclicGame, GameDefinitionError> {
+
+        if moves.is_empty() { return Err(GameDefinitionError::NoMoves) }
+
+        let number_moves: usize = moves.len();
+        let mut beats_matrix: Vec<Vec<bool>> = vec![vec![false; number_moves]; number_moves];
+        for (winner, loser) in beats.iter().copied() {
+
+            if winner >= number_moves { return Err(GameDefinitionError::UnknownMove(winner)) }
+            if loser >= number_moves { return Err(GameDefinitionError::UnknownMove(loser)) }
+            if winner == loser { return Err(GameDefinitionError::MoveBeatsItself(winner)) }
+            if beats_matrix[loser][winner] { return Err(GameDefinitionError::MovesBeatEachOther(winner, loser)) }
+
+            beats_matrix[winner][loser] = true;
+        }
+
+        return Ok(CyclicGame {
+            moves,
+            beats: beats_matrix,
+            move_scores: (1 ..= number_moves).collect(),
+            outcome_scores: OutcomeScores::default()
+        });
+    }
+
+    /// Balanced game over an odd number of `moves`, listed so that each one beats the half of the
+    /// other moves that come just before it, wrapping around the list.
+    pub fn cyclic(moves: Vec<String>) -> Result<CyclicGame, GameDefinitionError> {
+
+        let number_moves: usize = moves.len();
+        if moves.is_empty() { return Err(GameDefinitionError::NoMoves) }
+        if number_moves.is_multiple_of(2) { return Err(GameDefinitionError::EvenNumberOfMoves(number_moves)) }
+
+        let beats: Vec<(MoveID, MoveID)> = (0 .. number_moves)
+            .flat_map(|winner| (1 ..= number_moves / 2)
+                .map(move |distance| (winner, (winner + number_moves - distance) % number_moves)))
+            .collect();
+
+        return CyclicGame::new(moves, &beats);
+    }
+
+    /// Rock Paper Scissors, with Rock, Paper and Scissors worth 1, 2 and 3 points.
+    pub fn rock_paper_scissors() -> CyclicGame {
+        return CyclicGame::cyclic(develop_move_names(&["Rock", "Paper", "Scissors"])).unwrap();
+    }
+
+    /// Rock Paper Scissors Lizard Spock, with the moves worth 1 to 5 points in the order
+    /// Rock, Spock, Paper, Lizard, Scissors.
+    pub fn rock_paper_scissors_lizard_spock() -> CyclicGame {
+        return CyclicGame::cyclic(develop_move_names(&["Rock", "Spock", "Paper", "Lizard", "Scissors"])).unwrap();
+    }
+
+    /// Same game, with each move worth the score at its position in `move_scores`.
+    pub fn with_move_scores(mut self, move_scores: Vec<Score>) -> Result<CyclicGame, GameDefinitionError> {
+
+        if move_scores.len() != self.moves.len() {
+            return Err(GameDefinitionError::WrongNumberOfScores { moves: self.moves.len(), scores: move_scores.len() });
+        }
+
+        self.move_scores = move_scores;
+        return Ok(self);
+    }
+
+    /// Same game, with each outcome worth the given scores.
+    pub fn with_outcome_scores(mut self, outcome_scores: OutcomeScores) -> CyclicGame {
+
+        self.outcome_scores = outcome_scores;
+        return self;
+    }
+
+    pub fn get_number_moves(&self) -> usize {
+        return self.moves.len();
+    }
+
+    pub fn get_move_name(&self, move_id: MoveID) -> &str {
+        return &self.moves[move_id];
+    }
+
+    /// Move with the given name, if the game has one.
+    pub fn get_move_id(&self, move_name: &str) -> Option<MoveID> {
+        return self.moves.iter().position(|name| name == move_name);
+    }
+
+    /// Points for choosing `move_id`, whatever the outcome.
+    pub fn get_move_score(&self, move_id: MoveID) -> Score {
+        return self.move_scores[move_id];
+    }
+
+    pub fn get_outcome_scores(&self) -> OutcomeScores {
+        return self.outcome_scores;
+    }
+
+    /// Whether `winner` beats `loser`.
+    pub fn beats(&self, winner: MoveID, loser: MoveID) -> bool {
+        return self.beats[winner][loser];
+    }
+
+    /// Outcome of a round for the player choosing `self_play`.
+    pub fn play_match(&self, self_play: MoveID, opponent_play: MoveID) -> GameResult {
+
+        if self.beats(self_play, opponent_play) { return GameResult::Win }
+        if self.beats(opponent_play, self_play) { return GameResult::Lose }
+        return GameResult::Draw;
+    }
+
+    /// Move that gives `outcome` against `opponent_play`, preferring the one worth the most points
+    /// when several do. Returns `None` when no move of the game gives that outcome.
+    pub fn predict_match(&self, outcome: GameResult, opponent_play: MoveID) -> Option<MoveID> {
+
+        return (0 .. self.moves.len())
+            .filter(|self_play| self.play_match(*self_play, opponent_play) == outcome)
+            .max_by_key(|self_play| (self.move_scores[*self_play], std::cmp::Reverse(*self_play)));
+    }
+
+    /// Points of a round where `play` was chosen and the round ended with `outcome`.
+    pub fn compute_round_score(&self, play: MoveID, outcome: GameResult) -> Score {
+        return self.move_scores[play] + self.outcome_scores.get_score(outcome);
+    }
+}
+
+impl fmt::Display for GameDefinitionError {
+
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+
+        return match self {
+            GameDefinitionError::NoMoves => write!(formatter, "the game has no moves"),
+            GameDefinitionError::EvenNumberOfMoves(number_moves) => write!(formatter, "a cyclic game needs an odd number of moves, not {}", number_moves),
+            GameDefinitionError::UnknownMove(move_id) => write!(formatter, "move {} is not part of the game", move_id),
+            GameDefinitionError::MoveBeatsItself(move_id) => write!(formatter, "move {} cannot beat itself", move_id),
+            GameDefinitionError::MovesBeatEachOther(first, second) => write!(formatter, "moves {} and {} cannot beat each other", first, second),
+            GameDefinitionError::WrongNumberOfScores { moves, scores } => write!(formatter, "{} moves were given {} scores", moves, scores)
+        };
+    }
+}
+
+impl std::error::Error for GameDefinitionError {}
\ No newline at end of file
@@ -0,0 +1,257 @@
+//! Mixed strategies, where each move is played with some probability, and their expected scores.
+//!
+//! The payoff of a round is the score the player following the guide gets for it. Treating the
+//! opponent as trying to keep that score as low as possible makes the game a zero-sum matrix
+//! game, whose optimal mixed strategies are found by linear programming.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::{GuideError, GuideRound, map_other_player_choice};
+use crate::engine::{CyclicGame, MoveID};
+use crate::symbols::GuideSymbols;
+
+// =========================================== TYPE AND STRUCT DEFINITIONS ===========================================
+
+pub type Probability = f64;
+
+/// Probability of playing each move of the game, in the order of the game.
+pub type MixedStrategy = Vec<Probability>;
+
+/// Score of the row move against the column move, for the player choosing the row.
+pub type PayoffMatrix = Vec<Vec<f64>>;
+
+/// Optimal mixed strategies of both players, and the score they guarantee.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Equilibrium {
+    pub payoff_matrix: PayoffMatrix,
+    /// Strategy maximizing the lowest expected score the opponent can hold the player to.
+    pub self_strategy: MixedStrategy,
+    /// Strategy minimizing the highest expected score the player can reach.
+    pub opponent_strategy: MixedStrategy,
+    /// Expected score of a round when both players follow their strategy.
+    pub value: f64
+}
+
+/// Side of the payoff matrix a mixed strategy picks from: rows for the player, columns for the opponent.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum MatrixSide {
+    Rows,
+    Columns
+}
+
+/// Reason why a payoff matrix is not a game that can be solved, or a strategy cannot be played in it.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum PayoffMatrixError {
+    /// The matrix has no rows or no columns, so one of the players has no move to play.
+    NoMoves,
+    /// The row does not have as many payoffs as the first one.
+    RaggedRow { row: usize, expected: usize, found: usize },
+    /// The payoff is infinite or not a number.
+    NonFinitePayoff { row: usize, column: usize },
+    /// The strategy does not have a probability for each move on its side of the matrix.
+    StrategyLength { side: MatrixSide, expected: usize, found: usize },
+    /// The strategy has a negative or non-finite probability, or its probabilities do not add up to 1.
+    NotADistribution { side: MatrixSide }
+}
+
+const EPSILON: f64 = 1e-9;
+
+/// Leeway allowed on the sum of the probabilities of a strategy, for the rounding of their computation.
+const PROBABILITY_TOLERANCE: f64 = 1e-6;
+
+// =============================================== AUXILIARY FUNCTIONS ===============================================
+
+/// Payoffs of every pair of moves, scored as by [`CyclicGame::compute_round_score`].
+pub fn develop_payoff_matrix(game: &CyclicGame) -> PayoffMatrix {
+
+    return (0 .. game.get_number_moves())
+        .map(|self_move| (0 .. game.get_number_moves())
+            .map(|opponent_move| game.compute_round_score(self_move, game.play_match(self_move, opponent_move)) as f64)
+            .collect())
+        .collect();
+}
+
+/// Share of the rounds of the guide where the opponent played each move.
+pub fn observe_opponent_frequencies(game: &CyclicGame, symbols: &GuideSymbols, rounds: &[GuideRound]) -> Result<MixedStrategy, GuideError> {
+
+    let mut counts: HashMap<MoveID, usize> = HashMap::new();
+    for round in rounds.iter() {
+        *counts.entry(map_other_player_choice(symbols, round)?).or_insert(0) += 1;
+    }
+
+    return Ok((0 .. game.get_number_moves())
+        .map(|opponent_move| *counts.get(&opponent_move).unwrap_or(&0) as Probability / rounds.len().max(1) as Probability)
+        .collect());
+}
+
+/// Checks that the matrix is rectangular, with finite payoffs and at least one move on each side.
+fn validate_payoff_matrix(payoff_matrix: &PayoffMatrix) -> Result<(), PayoffMatrixError> {
+
+    let number_columns: usize = payoff_matrix.first().map_or(0, |payoffs| payoffs.len());
+
+    // Without moves on both sides there is nothing to normalize, the weights would all be zero
+    if number_columns == 0 { return Err(PayoffMatrixError::NoMoves) }
+    for (row, payoffs) in payoff_matrix.iter().enumerate() {
+
+        if payoffs.len() != number_columns {
+            return Err(PayoffMatrixError::RaggedRow { row, expected: number_columns, found: payoffs.len() });
+        }
+        if let Some(column) = payoffs.iter().position(|payoff| ! payoff.is_finite()) {
+            return Err(PayoffMatrixError::NonFinitePayoff { row, column });
+        }
+    }
+
+    return Ok(());
+}
+
+fn validate_strategy(strategy: &[Probability], side: MatrixSide, number_moves: usize) -> Result<(), PayoffMatrixError> {
+
+    if strategy.len() != number_moves {
+        return Err(PayoffMatrixError::StrategyLength { side, expected: number_moves, found: strategy.len() });
+    }
+    if strategy.iter().any(|probability| ! probability.is_finite() || *probability < -PROBABILITY_TOLERANCE)
+        || (strategy.iter().sum::<Probability>() - 1.0).abs() > PROBABILITY_TOLERANCE {
+        return Err(PayoffMatrixError::NotADistribution { side });
+    }
+
+    return Ok(());
+}
+
+/// Expected score of a round where each player follows their mixed strategy.
+///
+/// The matrix must be one [`solve_zero_sum_game`] accepts, and each strategy must have a
+/// probability for every move on its side, non-negative and adding up to 1 up to rounding.
+pub fn compute_expected_score(payoff_matrix: &PayoffMatrix, self_strategy: &[Probability], opponent_strategy: &[Probability]) -> Result<f64, PayoffMatrixError> {
+
+    validate_payoff_matrix(payoff_matrix)?;
+    validate_strategy(self_strategy, MatrixSide::Rows, payoff_matrix.len())?;
+    validate_strategy(opponent_strategy, MatrixSide::Columns, payoff_matrix[0].len())?;
+
+    return Ok(payoff_matrix.iter()
+        .zip(self_strategy)
+        .map(|(payoffs, self_probability)| payoffs.iter()
+            .zip(opponent_strategy)
+            .map(|(payoff, opponent_probability)| self_probability * opponent_probability * payoff)
+            .sum::<f64>())
+        .sum());
+}
+
+/// Move with the highest expected score against the opponent's mixed strategy, and that score.
+///
+/// Some pure move is always among the best responses, so no mixed strategy does better.
+pub fn find_best_response(payoff_matrix: &PayoffMatrix, opponent_strategy: &[Probability]) -> (MoveID, f64) {
+
+    return payoff_matrix.iter()
+        .map(|payoffs| payoffs.iter().zip(opponent_strategy).map(|(payoff, probability)| payoff * probability).sum::<f64>())
+        .enumerate()
+        .fold((0, f64::NEG_INFINITY), |best, (self_move, score)| if score > best.1 + EPSILON { (self_move, score) } else { best });
+}
+
+fn pivot(tableau: &mut [Vec<f64>], pivot_row: usize, pivot_column: usize) {
+
+    let pivot_value: f64 = tableau[pivot_row][pivot_column];
+    tableau[pivot_row].iter_mut().for_each(|value| *value /= pivot_value);
+
+    let pivot_values: Vec<f64> = tableau[pivot_row].clone();
+    for (row, values) in tableau.iter_mut().enumerate() {
+
+        let factor: f64 = values[pivot_column];
+        if row == pivot_row || factor.abs() < EPSILON { continue }
+        values.iter_mut().zip(pivot_values.iter()).for_each(|(value, pivot_value)| *value -= factor * pivot_value);
+    }
+}
+
+/// Solves the zero-sum game given by the payoff matrix, the player choosing rows and the opponent columns.
+///
+/// The payoffs are shifted to be positive, and the opponent's side is solved as the linear
+/// program maximizing `sum(u)` under `A u <= 1` with the simplex method. The opponent plays `u`
+/// normalized, the player the normalized dual prices, and the value of the game is `1 / sum(u)`
+/// before undoing the shift. The matrix must be rectangular, with finite payoffs and at least one
+/// move on each side.
+pub fn solve_zero_sum_game(payoff_matrix: &PayoffMatrix) -> Result<Equilibrium, PayoffMatrixError> {
+
+    validate_payoff_matrix(payoff_matrix)?;
+    let number_rows: usize = payoff_matrix.len();
+    let number_columns: usize = payoff_matrix[0].len();
+
+    let minimum_payoff: f64 = payoff_matrix.iter().flatten().copied().fold(f64::INFINITY, f64::min);
+    let shift: f64 = 1.0 - minimum_payoff.min(1.0);
+
+    // Rows are the constraints followed by the objective, columns the variables `u`, the slacks and the bound
+    let width: usize = number_columns + number_rows + 1;
+    let mut tableau: Vec<Vec<f64>> = vec![vec![0.0; width]; number_rows + 1];
+    let mut basis: Vec<usize> = (number_columns .. number_columns + number_rows).collect();
+
+    for (row, payoffs) in payoff_matrix.iter().enumerate() {
+
+        payoffs.iter().enumerate().for_each(|(column, payoff)| tableau[row][column] = payoff + shift);
+        tableau[row][number_columns + row] = 1.0;
+        tableau[row][width - 1] = 1.0;
+    }
+    (0 .. number_columns).for_each(|column| tableau[number_rows][column] = -1.0);
+
+    // Bland's rule: the first improving column enters, and ties on the ratio go to the lowest basic variable
+    while let Some(pivot_column) = (0 .. width - 1).find(|column| tableau[number_rows][*column] < -EPSILON) {
+
+        let pivot_row: Option<usize> = (0 .. number_rows)
+            .filter(|row| tableau[*row][pivot_column] > EPSILON)
+            .min_by(|first, second| {
+                let first_ratio: f64 = tableau[*first][width - 1] / tableau[*first][pivot_column];
+                let second_ratio: f64 = tableau[*second][width - 1] / tableau[*second][pivot_column];
+                first_ratio.total_cmp(&second_ratio).then(basis[*first].cmp(&basis[*second]))
+            });
+
+        // The feasible region is bounded by the positive payoffs, so this cannot happen
+        let Some(pivot_row) = pivot_row else { break };
+        pivot(&mut tableau, pivot_row, pivot_column);
+        basis[pivot_row] = pivot_column;
+    }
+
+    let mut opponent_weights: Vec<f64> = vec![0.0; number_columns];
+    for (row, variable) in basis.iter().enumerate() {
+        if *variable < number_columns { opponent_weights[*variable] = tableau[row][width - 1] }
+    }
+    let self_weights: Vec<f64> = (0 .. number_rows)
+        .map(|row| tableau[number_rows][number_columns + row])
+        .collect();
+
+    // Both sums are equal at the optimum, by strong duality
+    let opponent_total_weight: f64 = opponent_weights.iter().sum();
+    let self_total_weight: f64 = self_weights.iter().sum();
+    return Ok(Equilibrium {
+        payoff_matrix: payoff_matrix.clone(),
+        self_strategy: self_weights.iter().map(|weight| weight / self_total_weight).collect(),
+        opponent_strategy: opponent_weights.iter().map(|weight| weight / opponent_total_weight).collect(),
+        value: 1.0 / opponent_total_weight - shift
+    });
+}
+
+// ================================================= IMPLEMENTATIONS =================================================
+
+impl fmt::Display for PayoffMatrixError {
+
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+
+        return match self {
+            PayoffMatrixError::NoMoves => write!(formatter, "the payoff matrix needs at least one row and one column"),
+            PayoffMatrixError::RaggedRow { row, expected, found } => write!(formatter, "row {} of the payoff matrix has {} payoffs instead of {}", row, found, expected),
+            PayoffMatrixError::NonFinitePayoff { row, column } => write!(formatter, "the payoff at row {} and column {} is not a finite number", row, column),
+            PayoffMatrixError::StrategyLength { side, expected, found } => write!(formatter, "the strategy over the {} has {} probabilities instead of {}", side, found, expected),
+            PayoffMatrixError::NotADistribution { side } => write!(formatter, "the probabilities of the strategy over the {} are not a distribution adding up to 1", side)
+        };
+    }
+}
+
+impl fmt::Display for MatrixSide {
+
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+
+        return match self {
+            MatrixSide::Rows => write!(formatter, "rows"),
+            MatrixSide::Columns => write!(formatter, "columns")
+        };
+    }
+}
+
+impl std::error::Error for PayoffMatrixError {}
@@ -0,0 +1,82 @@
+//! Structured representations of the strategy guide.
+//!
+//! Each round is a record with the `opponent` symbol and the `response` symbol of the second
+//! column, e.g. `[{"opponent": "A", "response": "Y"}]` in JSON or `A,Y` rows in CSV.
+//! Scoring breakdowns are written to CSV with a row per round, or a row per move for their summary.
+
+use serde::{Deserialize, Serialize};
+
+use crate::breakdown::{GuideSummary, RoundTrace};
+
+// =========================================== TYPE AND STRUCT DEFINITIONS ===========================================
+
+#[derive(Serialize, Deserialize)]
+struct RoundRecord {
+    opponent: char,
+    response: char
+}
+
+// =============================================== AUXILIARY FUNCTIONS ===============================================
+
+/// Reads the rounds of the guide from a JSON list of records.
+pub fn strategy_guide_from_json(input: &str) -> serde_json::Result<Vec<(char, char)>> {
+
+    let records: Vec<RoundRecord> = serde_json::from_str(input)?;
+    return Ok(records.into_iter()
+        .map(|record| (record.opponent, record.response))
+        .collect());
+}
+
+/// Writes the rounds of the guide as a JSON list of records.
+pub fn strategy_guide_to_json(plays: &[(char, char)]) -> serde_json::Result<String> {
+
+    let records: Vec<RoundRecord> = plays.iter()
+        .map(|&(opponent, response)| RoundRecord { opponent, response })
+        .collect();
+    return serde_json::to_string(&records);
+}
+
+/// Reads the rounds of the guide from CSV rows of `opponent,response`.
+pub fn strategy_guide_from_csv(input: &str) -> csv::Result<Vec<(char, char)>> {
+
+    let mut reader = csv::Reader::from_reader(input.as_bytes());
+    return reader.deserialize()
+        .map(|record| record.map(|record: RoundRecord| (record.opponent, record.response)))
+        .collect();
+}
+
+/// Writes the rounds of the guide as CSV rows of `opponent,response`.
+pub fn strategy_guide_to_csv(plays: &[(char, char)]) -> csv::Result<String> {
+
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    for &(opponent, response) in plays.iter() {
+        writer.serialize(RoundRecord { opponent, response })?;
+    }
+
+    let output: Vec<u8> = writer.into_inner().map_err(|error| error.into_error())?;
+    return Ok(String::from_utf8(output).unwrap());
+}
+
+/// Writes the traced rounds as CSV rows, with the running total after each of them.
+pub fn round_traces_to_csv(traces: &[RoundTrace]) -> csv::Result<String> {
+
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    for trace in traces.iter() {
+        writer.serialize(trace)?;
+    }
+
+    let output: Vec<u8> = writer.into_inner().map_err(|error| error.into_error())?;
+    return Ok(String::from_utf8(output).unwrap());
+}
+
+/// Writes the summary of a guide as CSV rows with the outcomes and points of each move.
+pub fn guide_summary_to_csv(summary: &GuideSummary) -> csv::Result<String> {
+
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    for contribution in summary.move_contributions.iter() {
+        writer.serialize(contribution)?;
+    }
+
+    let output: Vec<u8> = writer.into_inner().map_err(|error| error.into_error())?;
+    return Ok(String::from_utf8(output).unwrap());
+}
@@ -1,139 +1,213 @@
+//! Rock Paper Scissors (Advent of Code 2022, day 02).
+//!
+//! The input is an encrypted strategy guide: each line holds the opponent's move and a second
+//! column that is read either as the move to play or as the outcome the round should have.
+//! The rules are those of any game built with [`engine`], Rock Paper Scissors being the default,
+//! the meaning of the second column can be searched for through [`decryption`], and the score of
+//! each round detailed through [`breakdown`]. Strategies, the guide among them, can also be
+//! pitted against each other through [`tournament`], or played as mixed strategies through
+//! [`equilibrium`].
+//! The symbols of each column can be configured through [`symbols`], and the guide can also be
+//! read from, and written to, JSON or CSV through [`formats`].
+
+pub mod breakdown;
+pub mod decryption;
+pub mod engine;
+pub mod equilibrium;
+pub mod formats;
+pub mod symbols;
+pub mod tournament;
+
+use std::fmt;
+use serde::{Deserialize, Serialize};
+
+use engine::{CyclicGame, MoveID};
+use symbols::GuideSymbols;
 
 // =========================================== TYPE AND STRUCT DEFINITIONS ===========================================
 
+/// Points accumulated over one or more rounds.
 pub type Score = usize;
 
-#[derive(PartialEq, Eq, Clone, Copy)]
-enum GameChoice {
+/// A shape that can be played in a round.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum GameChoice {
     Rock,
     Paper,
     Scissors
 }
 
-#[derive(PartialEq, Eq, Clone, Copy)]
-enum GameResult {
+/// Outcome of a round, from the point of view of the player following the guide.
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Serialize, Deserialize)]
+pub enum GameResult {
     Win,
     Lose,
     Draw
 }
 
-// =============================================== AUXILIARY FUNCTIONS ===============================================
-
-fn map_other_player_choice(play_char: &char) -> GameChoice {
-    
-    return match play_char {
-        'A' => GameChoice::Rock,
-        'B' => GameChoice::Paper,
-        'C' => GameChoice::Scissors,
-        _ => panic!("🚨 Symbol not recognized as an other player's game choice!")
-    }
+/// A round of the strategy guide, with the symbols of its two columns and the line it comes from.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct GuideRound {
+    pub line: usize,
+    pub opponent: String,
+    pub response: String
 }
 
-fn map_self_player_choice(play_char: &char) -> GameChoice {
-    
-    return match play_char {
-        'X' => GameChoice::Rock,
-        'Y' => GameChoice::Paper,
-        'Z' => GameChoice::Scissors,
-        _ => panic!("🚨 Symbol not recognized as a self player's game choice!")
-    }
+/// Reason why a strategy guide cannot be scored, naming the line at fault.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum GuideError {
+    /// The line does not hold exactly two symbols.
+    MalformedLine { line: usize, content: String },
+    /// The symbol of the first column stands for no move.
+    UnknownOpponentSymbol { line: usize, symbol: String },
+    /// The symbol of the second column, read as a move, stands for no move.
+    UnknownResponseSymbol { line: usize, symbol: String },
+    /// The symbol of the second column, read as an outcome, stands for no outcome.
+    UnknownOutcomeSymbol { line: usize, symbol: String },
+    /// No move of the game gives the requested outcome against the opponent's move.
+    ImpossibleOutcome { line: usize, outcome: GameResult, opponent_move: String },
+    /// The second column has more distinct symbols than there are moves and outcomes, so no
+    /// reading can give each of them its own meaning.
+    TooManyResponseSymbols { symbols: usize, moves: usize, outcomes: usize }
 }
 
-fn map_outcome(outcome_char: &char) -> GameResult {
-    
-    return match outcome_char {
-        'X' => GameResult::Lose,
-        'Y' => GameResult::Draw,
-        'Z' => GameResult::Win,
-        _ => panic!("🚨 Symbol not recognized as a game result!")
-    }
-}
+// =============================================== AUXILIARY FUNCTIONS ===============================================
 
-fn play_match(self_play: GameChoice, opponent_play: GameChoice) -> GameResult {
+/// Reads each line of the guide as the pair of symbols in its two columns.
+pub fn convert_input_to_correct_format(input_lines: Vec<String>) -> Vec<(char, char)> {
 
-    return match (self_play, opponent_play) {
-        (GameChoice::Rock, GameChoice::Scissors) => GameResult::Win,
-        (GameChoice::Paper, GameChoice::Rock) => GameResult::Win,
-        (GameChoice::Scissors, GameChoice::Paper) => GameResult::Win,
-        (GameChoice::Scissors, GameChoice::Rock) => GameResult::Lose,
-        (GameChoice::Rock, GameChoice::Paper) => GameResult::Lose,
-        (GameChoice::Paper, GameChoice::Scissors) => GameResult::Lose,
+    return input_lines.into_iter()
+        .map(|line| line.chars().collect::<Vec<char>>())
+        .map(|line| (*line.first().unwrap(), *line.get(2).unwrap()))
+        .collect();
+}
 
-        (GameChoice::Rock, GameChoice::Rock) | (GameChoice::Paper, GameChoice::Paper) | (GameChoice::Scissors, GameChoice::Scissors) => GameResult::Draw
-    }
+/// Reads each line of the guide as the words in its two columns, skipping blank lines.
+pub fn convert_input_to_rounds(input_lines: &[String]) -> Result<Vec<GuideRound>, GuideError> {
+
+    return input_lines.iter()
+        .enumerate()
+        .filter(|(_, line)| ! line.trim().is_empty())
+        .map(|(line_index, line)| match line.split_whitespace().collect::<Vec<&str>>()[..] {
+            [opponent, response] => Ok(GuideRound { line: line_index + 1, opponent: opponent.to_owned(), response: response.to_owned() }),
+            _ => Err(GuideError::MalformedLine { line: line_index + 1, content: line.to_owned() })
+        })
+        .collect();
 }
 
-fn predict_match(outcome: GameResult, opponent_play: GameChoice) -> GameChoice {
+/// Rounds of a guide read by [`convert_input_to_correct_format`], one per line.
+pub fn convert_plays_to_rounds(plays: &[(char, char)]) -> Vec<GuideRound> {
 
-    return match (outcome, opponent_play) {
-        (GameResult::Win, GameChoice::Rock) => GameChoice::Paper,
-        (GameResult::Win, GameChoice::Paper) => GameChoice::Scissors,
-        (GameResult::Win, GameChoice::Scissors) => GameChoice::Rock,
-        (GameResult::Lose, GameChoice::Rock) => GameChoice::Scissors,
-        (GameResult::Lose, GameChoice::Paper) => GameChoice::Rock,
-        (GameResult::Lose, GameChoice::Scissors) => GameChoice::Paper,
+    return plays.iter()
+        .enumerate()
+        .map(|(line_index, (opponent, response))| GuideRound { line: line_index + 1, opponent: opponent.to_string(), response: response.to_string() })
+        .collect();
+}
 
-        (GameResult::Draw, opponent_play) => opponent_play
-    }
+pub(crate) fn map_other_player_choice(symbols: &GuideSymbols, round: &GuideRound) -> Result<MoveID, GuideError> {
+    
+    return symbols.opponent_moves.get(&round.opponent).copied()
+        .ok_or_else(|| GuideError::UnknownOpponentSymbol { line: round.line, symbol: round.opponent.clone() });
 }
 
-fn compute_round_score(play: GameChoice, outcome: GameResult) -> Score {
+pub(crate) fn map_self_player_choice(symbols: &GuideSymbols, round: &GuideRound) -> Result<MoveID, GuideError> {
+    
+    return symbols.self_moves.get(&round.response).copied()
+        .ok_or_else(|| GuideError::UnknownResponseSymbol { line: round.line, symbol: round.response.clone() });
+}
 
-    let mut round_score = 0;
+pub(crate) fn map_outcome(symbols: &GuideSymbols, round: &GuideRound) -> Result<GameResult, GuideError> {
+    
+    return symbols.outcomes.get(&round.response).copied()
+        .ok_or_else(|| GuideError::UnknownOutcomeSymbol { line: round.line, symbol: round.response.clone() });
+}
 
-    // Intrepert user's play as points
-    match play {
-        GameChoice::Rock => round_score = round_score + 1,
-        GameChoice::Paper => round_score = round_score + 2,
-        GameChoice::Scissors => round_score = round_score + 3,
-    }
+/// Moves of the opponent and of the player, and the outcome, of a round whose second column is
+/// the move to play.
+pub(crate) fn resolve_round_setting_play(game: &CyclicGame, symbols: &GuideSymbols, round: &GuideRound) -> Result<(MoveID, MoveID, GameResult), GuideError> {
 
-    // Intrepert match outcome as points
-    match outcome {
-        GameResult::Win => round_score = round_score + 6,
-        GameResult::Draw => round_score = round_score + 3,
-        GameResult::Lose => round_score = round_score + 0,
-    }
+    let play_other: MoveID = map_other_player_choice(symbols, round)?;
+    let play_self: MoveID = map_self_player_choice(symbols, round)?;
+    return Ok((play_other, play_self, game.play_match(play_self, play_other)));
+}
 
-    return round_score;
+/// Moves of the opponent and of the player, and the outcome, of a round whose second column is
+/// the outcome it must have.
+pub(crate) fn resolve_round_setting_outcome(game: &CyclicGame, symbols: &GuideSymbols, round: &GuideRound) -> Result<(MoveID, MoveID, GameResult), GuideError> {
 
+    let play_other: MoveID = map_other_player_choice(symbols, round)?;
+    let outcome: GameResult = map_outcome(symbols, round)?;
+    let play_self: MoveID = game.predict_match(outcome, play_other)
+        .ok_or_else(|| GuideError::ImpossibleOutcome { line: round.line, outcome, opponent_move: game.get_move_name(play_other).to_owned() })?;
+    return Ok((play_other, play_self, outcome));
 }
 
-pub fn play_game_setting_play(plays: &Vec<(char, char)>) -> Score {
+/// Total score in `game` when the second column of the guide is the move to play.
+///
+/// The score of each round is detailed by [`breakdown::trace_guide_setting_play`].
+pub fn play_guide_setting_play(game: &CyclicGame, symbols: &GuideSymbols, rounds: &[GuideRound]) -> Result<Score, GuideError> {
 
-    let mut final_score: Score = 0;
-    for (play_other_char, play_self_char) in plays.iter() {
+    return rounds.iter()
+        .map(|round| resolve_round_setting_play(game, symbols, round)
+            .map(|(_, play_self, outcome)| game.compute_round_score(play_self, outcome)))
+        .sum();
+}
 
-        let play_other: GameChoice = map_other_player_choice(play_other_char);
-        let play_self: GameChoice = map_self_player_choice(play_self_char);
-        let outcome: GameResult = play_match(play_self, play_other);
+/// Total score in `game` when the second column of the guide is the outcome the round must have.
+///
+/// The score of each round is detailed by [`breakdown::trace_guide_setting_outcome`].
+pub fn play_guide_setting_outcome(game: &CyclicGame, symbols: &GuideSymbols, rounds: &[GuideRound]) -> Result<Score, GuideError> {
 
-        let round_score: Score = compute_round_score(play_self, outcome);
-        final_score = final_score + round_score;
-    
-    } 
+    return rounds.iter()
+        .map(|round| resolve_round_setting_outcome(game, symbols, round)
+            .map(|(_, play_self, outcome)| game.compute_round_score(play_self, outcome)))
+        .sum();
+}
 
+/// Total score when the second column of the guide is the shape to play.
+pub fn play_game_setting_play(plays: &[(char, char)]) -> Score {
 
-    return final_score;
+    let game: CyclicGame = CyclicGame::rock_paper_scissors();
+    return play_guide_setting_play(&game, &GuideSymbols::for_game(&game).unwrap(), &convert_plays_to_rounds(plays))
+        .unwrap_or_else(|error| panic!("🚨 {}", error));
 }
 
-pub fn play_game_setting_outcome(plays: &Vec<(char, char)>) -> Score {
+/// Total score when the second column of the guide is the outcome the round must have.
+pub fn play_game_setting_outcome(plays: &[(char, char)]) -> Score {
 
-    let mut final_score: Score = 0;
-    for (play_other_char, play_outcome_char) in plays.iter() {
+    let game: CyclicGame = CyclicGame::rock_paper_scissors();
+    return play_guide_setting_outcome(&game, &GuideSymbols::for_game(&game).unwrap(), &convert_plays_to_rounds(plays))
+        .unwrap_or_else(|error| panic!("🚨 {}", error));
+}
 
-        let play_other: GameChoice = map_other_player_choice(play_other_char);
-        let outcome: GameResult = map_outcome(play_outcome_char);
-        let play_self: GameChoice = predict_match(outcome, play_other);
+// ================================================= IMPLEMENTATIONS =================================================
 
-        let round_score: Score = compute_round_score(play_self, outcome);
-        final_score = final_score + round_score;
-    
-    } 
+impl GameChoice {
 
+    /// The same shape, as a move of [`CyclicGame::rock_paper_scissors`].
+    pub fn to_move_id(&self) -> MoveID {
 
-    return final_score;
+        return match *self {
+            GameChoice::Rock => 0,
+            GameChoice::Paper => 1,
+            GameChoice::Scissors => 2
+        };
+    }
 }
 
-// ================================================= IMPLEMENTATIONS =================================================
+impl fmt::Display for GuideError {
+
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+
+        return match self {
+            GuideError::MalformedLine { line, content } => write!(formatter, "line {}: expected two symbols, found '{}'", line, content),
+            GuideError::UnknownOpponentSymbol { line, symbol } => write!(formatter, "line {}: symbol '{}' not recognized as an other player's game choice", line, symbol),
+            GuideError::UnknownResponseSymbol { line, symbol } => write!(formatter, "line {}: symbol '{}' not recognized as a self player's game choice", line, symbol),
+            GuideError::UnknownOutcomeSymbol { line, symbol } => write!(formatter, "line {}: symbol '{}' not recognized as a game result", line, symbol),
+            GuideError::ImpossibleOutcome { line, outcome, opponent_move } => write!(formatter, "line {}: no move gives a {:?} against {}", line, outcome, opponent_move),
+            GuideError::TooManyResponseSymbols { symbols, moves, outcomes } => write!(formatter, "{} distinct symbols in the second column, but only {} moves and {} outcomes to read them as", symbols, moves, outcomes)
+        };
+    }
+}
 
+impl std::error::Error for GuideError {}
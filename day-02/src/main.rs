@@ -1,19 +1,128 @@
 mod read;
-mod lib;
+
+use day_02::Score;
+use day_02::{convert_input_to_correct_format, play_game_setting_play, play_game_setting_outcome};
+use day_02::{convert_input_to_rounds, play_guide_setting_play, play_guide_setting_outcome, GuideRound};
+use day_02::breakdown::{trace_guide_setting_outcome, trace_guide_setting_play, GuideSummary, RoundTrace};
+use day_02::decryption::{rank_decryptions, DecryptionCandidate};
+use day_02::engine::CyclicGame;
+use day_02::equilibrium::{compute_expected_score, develop_payoff_matrix, find_best_response, observe_opponent_frequencies, solve_zero_sum_game, Equilibrium, MixedStrategy};
+use day_02::formats::{guide_summary_to_csv, round_traces_to_csv};
+use day_02::symbols::GuideSymbols;
+use day_02::tournament::{FixedGuide, FrequencyCounter, MarkovPredictor, RandomPlayer, Standing, Strategy, Tournament, WinStayLoseShift};
 
 fn main() {
 
     let input = read::read_lines("input.txt".to_owned());
-    let input_formatted : Vec<(char, char)> = input.into_iter()
-        .map(|line| line.chars().collect::<Vec<char>>())
-        .map(|line| (*line.get(0).unwrap(), *line.get(2).unwrap()))
-        .collect();
+
+    // Guides with another encoding, on request
+    if let (Some("--symbols"), Some(config_path)) = (std::env::args().nth(1).as_deref(), std::env::args().nth(2)) {
+
+        let game: CyclicGame = CyclicGame::rock_paper_scissors();
+        let config: String = std::fs::read_to_string(config_path).unwrap();
+        let symbols: GuideSymbols = GuideSymbols::from_toml(&game, &config).unwrap_or_else(|error| panic!("🚨 {}", error));
+        let rounds: Vec<GuideRound> = convert_input_to_rounds(&input).unwrap_or_else(|error| panic!("🚨 {}", error));
+
+        match play_guide_setting_play(&game, &symbols, &rounds) {
+            Ok(score) => println!("\r✂️  Prediction for score achieved by setting play: '{}' (Part 1)", score),
+            Err(error) => println!("\r🚨 {} (Part 1)", error)
+        }
+        match play_guide_setting_outcome(&game, &symbols, &rounds) {
+            Ok(score) => println!("\r✂️  Prediction for score achieved by setting outcome: '{}' (Part 2)", score),
+            Err(error) => println!("\r🚨 {} (Part 2)", error)
+        }
+        return;
+    }
+
+    // Readings of the second column, on request, keeping those reaching the target total if given
+    if let Some("--decrypt") = std::env::args().nth(1).as_deref() {
+
+        let game: CyclicGame = CyclicGame::rock_paper_scissors();
+        let rounds: Vec<GuideRound> = convert_input_to_rounds(&input).unwrap_or_else(|error| panic!("🚨 {}", error));
+        let target: Option<usize> = std::env::args().nth(2).map(|target| target.parse().unwrap());
+
+        let candidates: Vec<DecryptionCandidate> = rank_decryptions(&game, &GuideSymbols::for_game(&game).unwrap(), &rounds)
+            .unwrap_or_else(|error| panic!("🚨 {}", error));
+        for candidate in candidates.iter().filter(|candidate| target.is_none_or(|target| candidate.score == target)) {
+            println!("\r✂️  {:?} {:?}: '{}'", candidate.interpretation, candidate.describe_mapping(&game), candidate.score);
+        }
+        return;
+    }
+
+    // Round by round breakdown as CSV, on request, followed by its summary per move
+    if let Some("--breakdown") = std::env::args().nth(1).as_deref() {
+
+        let game: CyclicGame = CyclicGame::rock_paper_scissors();
+        let symbols: GuideSymbols = GuideSymbols::for_game(&game).unwrap();
+        let rounds: Vec<GuideRound> = convert_input_to_rounds(&input).unwrap_or_else(|error| panic!("🚨 {}", error));
+        let traces: Vec<RoundTrace> = match std::env::args().nth(2).as_deref() {
+            Some("outcome") => trace_guide_setting_outcome(&game, &symbols, &rounds),
+            _ => trace_guide_setting_play(&game, &symbols, &rounds)
+        }.unwrap_or_else(|error| panic!("🚨 {}", error));
+
+        print!("{}", round_traces_to_csv(&traces).unwrap());
+        println!();
+        let summary: GuideSummary = GuideSummary::new(&game, &traces).unwrap_or_else(|error| panic!("🚨 {}", error));
+        print!("{}", guide_summary_to_csv(&summary).unwrap());
+        return;
+    }
+
+    // Round-robin tournament between the guide and other strategies, on request
+    if let Some("--tournament") = std::env::args().nth(1).as_deref() {
+
+        let game: CyclicGame = CyclicGame::rock_paper_scissors();
+        let rounds: Vec<GuideRound> = convert_input_to_rounds(&input).unwrap_or_else(|error| panic!("🚨 {}", error));
+        let traces: Vec<RoundTrace> = trace_guide_setting_outcome(&game, &GuideSymbols::for_game(&game).unwrap(), &rounds)
+            .unwrap_or_else(|error| panic!("🚨 {}", error));
+
+        let rounds_per_match: usize = std::env::args().nth(2).map_or(1000, |rounds| rounds.parse().unwrap());
+        let seed: u64 = std::env::args().nth(3).map_or(2022, |seed| seed.parse().unwrap());
+        let mut players: Vec<Box<dyn Strategy>> = vec![
+            Box::new(FixedGuide::from_traces(&traces)),
+            Box::new(RandomPlayer),
+            Box::new(FrequencyCounter::new()),
+            Box::new(WinStayLoseShift::new()),
+            Box::new(MarkovPredictor::new())
+        ];
+
+        let standings: Vec<Standing> = Tournament::new(game, rounds_per_match, seed).run(&mut players);
+        for (position, standing) in standings.iter().enumerate() {
+            println!("\r✂️  {}. {}: '{}' matches won, '{}' drawn, '{}' lost, '{}' points",
+                position + 1, standing.name, standing.matches_won, standing.matches_drawn, standing.matches_lost, standing.score);
+        }
+        return;
+    }
+
+    // Payoff matrix, best response to the guide's opponent and equilibrium, on request
+    if let Some("--equilibrium") = std::env::args().nth(1).as_deref() {
+
+        let game: CyclicGame = CyclicGame::rock_paper_scissors();
+        let rounds: Vec<GuideRound> = convert_input_to_rounds(&input).unwrap_or_else(|error| panic!("🚨 {}", error));
+        let opponent_frequencies: MixedStrategy = observe_opponent_frequencies(&game, &GuideSymbols::for_game(&game).unwrap(), &rounds)
+            .unwrap_or_else(|error| panic!("🚨 {}", error));
+
+        let equilibrium: Equilibrium = solve_zero_sum_game(&develop_payoff_matrix(&game))
+            .unwrap_or_else(|error| panic!("🚨 {}", error));
+        let (best_response, best_response_score) = find_best_response(&equilibrium.payoff_matrix, &opponent_frequencies);
+
+        println!("\r✂️  Payoff matrix: {:?}", equilibrium.payoff_matrix);
+        println!("\r✂️  Opponent frequencies: {:?}", opponent_frequencies);
+        println!("\r✂️  Best response: '{}' with an expected '{:.3}' per round", game.get_move_name(best_response), best_response_score);
+        println!("\r✂️  Equilibrium: {:?} against {:?}, worth '{:.3}' per round",
+            equilibrium.self_strategy, equilibrium.opponent_strategy, equilibrium.value);
+        println!("\r✂️  Equilibrium against the opponent frequencies: '{:.3}' per round",
+            compute_expected_score(&equilibrium.payoff_matrix, &equilibrium.self_strategy, &opponent_frequencies)
+                .unwrap_or_else(|error| panic!("🚨 {}", error)));
+        return;
+    }
+
+    let input_formatted : Vec<(char, char)> = convert_input_to_correct_format(input);
     
     // Part 1
-    let score_prediction_play: lib::Score = lib::play_game_setting_play(&input_formatted);
+    let score_prediction_play: Score = play_game_setting_play(&input_formatted);
     println!("\r✂️  Prediction for score achieved by setting play: '{}' (Part 1)", score_prediction_play);
     
     // Part 2
-    let score_prediction_outcome: lib::Score = lib::play_game_setting_outcome(&input_formatted);
+    let score_prediction_outcome: Score = play_game_setting_outcome(&input_formatted);
     println!("\r✂️  Prediction for score achieved by setting outcome: '{}' (Part 2)", score_prediction_outcome);
 }
\ No newline at end of file
@@ -0,0 +1,134 @@
+//! What the symbols of a strategy guide stand for, so that guides written with other encodings can be scored.
+//!
+//! Encodings are loaded from TOML, with a table per column mapping each symbol to a move name of
+//! the game, or to an outcome for the second column read as outcomes. Symbols can be characters
+//! or whole words:
+//!
+//! ```toml
+//! [opponent]
+//! A = "Rock"
+//! B = "Paper"
+//! C = "Scissors"
+//!
+//! [response]
+//! rock = "Rock"
+//! paper = "Paper"
+//! scissors = "Scissors"
+//!
+//! [outcome]
+//! lose = "Lose"
+//! draw = "Draw"
+//! win = "Win"
+//! ```
+
+use std::collections::HashMap;
+use std::fmt;
+use serde::Deserialize;
+
+use crate::GameResult;
+use crate::engine::{CyclicGame, MoveID};
+
+// =========================================== TYPE AND STRUCT DEFINITIONS ===========================================
+
+/// Largest number of moves [`GuideSymbols::for_game`] has letters for.
+pub const MAX_LETTER_MOVES: usize = 26;
+
+/// What the symbols of each column of the strategy guide stand for in a game.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct GuideSymbols {
+    /// Move of the game behind each symbol of the first column.
+    pub opponent_moves: HashMap<String, MoveID>,
+    /// Move of the game behind each symbol of the second column, when it is read as a move.
+    pub self_moves: HashMap<String, MoveID>,
+    /// Outcome behind each symbol of the second column, when it is read as an outcome.
+    pub outcomes: HashMap<String, GameResult>
+}
+
+/// Reason why an encoding cannot be loaded.
+#[derive(Debug)]
+pub enum SymbolConfigError {
+    /// The configuration is not valid TOML, or lacks one of the columns.
+    Parse(toml::de::Error),
+    /// A symbol of `column` stands for a move that the game does not have.
+    UnknownMove { column: &'static str, symbol: String, move_name: String },
+    /// The game has more moves than there are letters to write them with.
+    TooManyMoves { number_moves: usize }
+}
+
+#[derive(Deserialize)]
+struct SymbolConfigRecord {
+    opponent: HashMap<String, String>,
+    response: HashMap<String, String>,
+    outcome: HashMap<String, GameResult>
+}
+
+// =============================================== AUXILIARY FUNCTIONS ===============================================
+
+fn develop_move_symbols(game: &CyclicGame, column: &'static str, symbols: HashMap<String, String>) -> Result<HashMap<String, MoveID>, SymbolConfigError> {
+
+    return symbols.into_iter()
+        .map(|(symbol, move_name)| match game.get_move_id(&move_name) {
+            Some(move_id) => Ok((symbol, move_id)),
+            None => Err(SymbolConfigError::UnknownMove { column, symbol, move_name })
+        })
+        .collect();
+}
+
+// ================================================= IMPLEMENTATIONS =================================================
+
+impl GuideSymbols {
+
+    /// Symbols of the puzzle, extended to games with more moves: the opponent's moves are `A`, `B`,
+    /// `C` and so on, the moves to play the last letters of the alphabet up to `Z`, and the outcomes
+    /// `X` for a loss, `Y` for a draw and `Z` for a win. Only games of up to [`MAX_LETTER_MOVES`]
+    /// moves have symbols.
+    pub fn for_game(game: &CyclicGame) -> Result<GuideSymbols, SymbolConfigError> {
+
+        let number_moves: usize = game.get_number_moves();
+        if number_moves > MAX_LETTER_MOVES { return Err(SymbolConfigError::TooManyMoves { number_moves }) }
+
+        let number_moves: u8 = number_moves as u8;
+        return Ok(GuideSymbols {
+            opponent_moves: (b'A' .. b'A' + number_moves)
+                .enumerate()
+                .map(|(move_id, symbol)| (char::from(symbol).to_string(), move_id))
+                .collect(),
+            self_moves: (b'Z' + 1 - number_moves ..= b'Z')
+                .enumerate()
+                .map(|(move_id, symbol)| (char::from(symbol).to_string(), move_id))
+                .collect(),
+            outcomes: HashMap::from([
+                ("X".to_owned(), GameResult::Lose),
+                ("Y".to_owned(), GameResult::Draw),
+                ("Z".to_owned(), GameResult::Win)
+            ])
+        });
+    }
+
+    /// Reads an encoding from its TOML configuration, naming the moves as `game` does.
+    pub fn from_toml(game: &CyclicGame, config: &str) -> Result<GuideSymbols, SymbolConfigError> {
+
+        let record: SymbolConfigRecord = toml::from_str(config).map_err(SymbolConfigError::Parse)?;
+        return Ok(GuideSymbols {
+            opponent_moves: develop_move_symbols(game, "opponent", record.opponent)?,
+            self_moves: develop_move_symbols(game, "response", record.response)?,
+            outcomes: record.outcome
+        });
+    }
+}
+
+impl fmt::Display for SymbolConfigError {
+
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+
+        return match self {
+            SymbolConfigError::Parse(error) => write!(formatter, "invalid symbol configuration: {}", error),
+            SymbolConfigError::UnknownMove { column, symbol, move_name } =>
+                write!(formatter, "symbol '{}' of the {} column stands for '{}', which is not a move of the game", symbol, column, move_name),
+            SymbolConfigError::TooManyMoves { number_moves } =>
+                write!(formatter, "the game has {} moves, but only {} letters are available for them", number_moves, MAX_LETTER_MOVES)
+        };
+    }
+}
+
+impl std::error::Error for SymbolConfigError {}
\ No newline at end of file
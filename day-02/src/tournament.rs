@@ -0,0 +1,403 @@
+//! Round-robin tournaments between strategies playing any game of the engine.
+//!
+//! Every pair of players meets once, for a fixed number of rounds. Strategies only see the moves
+//! played so far in their current match, and draw any randomness from a generator of their own,
+//! seeded for each match from the seed of the tournament and the positions of both players. The
+//! same seed always gives the same standings, and no match depends on the ones played before it.
+
+use crate::{GameResult, Score};
+use crate::breakdown::RoundTrace;
+use crate::engine::{CyclicGame, MoveID};
+
+// =========================================== TYPE AND STRUCT DEFINITIONS ===========================================
+
+/// Deterministic random number generator (SplitMix64), small enough to need no dependency.
+#[derive(Clone, Debug)]
+pub struct SeededRandom {
+    state: u64
+}
+
+/// A way of picking moves during a match.
+pub trait Strategy {
+
+    /// Name of the strategy in the standings.
+    fn get_name(&self) -> String;
+    /// Move to play in the next round.
+    fn choose_move(&mut self, game: &CyclicGame, random: &mut SeededRandom) -> MoveID;
+    /// Learns from the round just played.
+    fn observe(&mut self, game: &CyclicGame, own_move: MoveID, opponent_move: MoveID, outcome: GameResult);
+    /// Forgets everything learnt, before a new match starts.
+    fn reset(&mut self);
+}
+
+/// Plays the moves of a strategy guide in order, starting over once they run out.
+pub struct FixedGuide {
+    moves: Vec<MoveID>,
+    next_round: usize
+}
+
+/// Plays any move with the same probability.
+pub struct RandomPlayer;
+
+/// Counters the move the opponent has played the most so far.
+pub struct FrequencyCounter {
+    opponent_counts: Vec<usize>
+}
+
+/// Keeps its move after a win, and otherwise switches to the move that beats the opponent's last one.
+pub struct WinStayLoseShift {
+    last_round: Option<(MoveID, MoveID, GameResult)>
+}
+
+/// Counters the move the opponent most often played after their last one.
+pub struct MarkovPredictor {
+    transitions: Vec<Vec<usize>>,
+    last_opponent_move: Option<MoveID>
+}
+
+/// Settings of a tournament.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Tournament {
+    game: CyclicGame,
+    rounds_per_match: usize,
+    seed: u64
+}
+
+/// Record of a player over the whole tournament.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Standing {
+    pub name: String,
+    pub matches_won: usize,
+    pub matches_drawn: usize,
+    pub matches_lost: usize,
+    pub rounds_won: usize,
+    pub rounds_drawn: usize,
+    pub rounds_lost: usize,
+    /// Points scored over every round, as in the puzzle.
+    pub score: Score
+}
+
+// =============================================== AUXILIARY FUNCTIONS ===============================================
+
+/// Move beating `opponent_move`, or a random one if there is none.
+fn counter_move(game: &CyclicGame, opponent_move: MoveID, random: &mut SeededRandom) -> MoveID {
+
+    return game.predict_match(GameResult::Win, opponent_move)
+        .unwrap_or_else(|| random.below(game.get_number_moves()));
+}
+
+/// Position of the largest count, the first one among ties, or `None` if every count is zero.
+fn most_frequent(counts: &[usize]) -> Option<MoveID> {
+
+    let maximum: usize = *counts.iter().max()?;
+    if maximum == 0 { return None }
+    return counts.iter().position(|count| *count == maximum);
+}
+
+/// Seed of the generator the player at position `player` draws from in its match against the
+/// player at position `opponent`.
+fn develop_match_seed(seed: u64, player: usize, opponent: usize) -> u64 {
+
+    let players: u64 = SeededRandom::new(((player as u64) << 32) | opponent as u64).next_u64();
+    return SeededRandom::new(seed ^ players).next_u64();
+}
+
+fn develop_standing(name: String) -> Standing {
+
+    return Standing {
+        name,
+        matches_won: 0,
+        matches_drawn: 0,
+        matches_lost: 0,
+        rounds_won: 0,
+        rounds_drawn: 0,
+        rounds_lost: 0,
+        score: 0
+    };
+}
+
+fn record_round(standing: &mut Standing, outcome: GameResult, score: Score) {
+
+    standing.score += score;
+    match outcome {
+        GameResult::Win => standing.rounds_won += 1,
+        GameResult::Draw => standing.rounds_drawn += 1,
+        GameResult::Lose => standing.rounds_lost += 1
+    }
+}
+
+// ================================================= IMPLEMENTATIONS =================================================
+
+impl SeededRandom {
+
+    pub fn new(seed: u64) -> SeededRandom {
+        SeededRandom {
+            state: seed
+        }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+
+        self.state = self.state.wrapping_add(0x9E3779B97F4A7C15);
+        let mut mixed: u64 = self.state;
+        mixed = (mixed ^ (mixed >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        mixed = (mixed ^ (mixed >> 27)).wrapping_mul(0x94D049BB133111EB);
+        return mixed ^ (mixed >> 31);
+    }
+
+    /// Number drawn uniformly from `0 .. bound`.
+    ///
+    /// Uses Lemire's method: the high half of a 64-bit draw times `bound` is the result, and the
+    /// draws whose low half falls under `2^64 % bound` are rejected, as they would favour the
+    /// lowest numbers.
+    pub fn below(&mut self, bound: usize) -> usize {
+
+        if bound == 0 { panic!("🚨 There is no number below 0 to draw") }
+
+        let bound: u64 = bound as u64;
+        let mut product: u128 = u128::from(self.next_u64()) * u128::from(bound);
+        if (product as u64) < bound {
+
+            let threshold: u64 = bound.wrapping_neg() % bound;
+            while (product as u64) < threshold {
+                product = u128::from(self.next_u64()) * u128::from(bound);
+            }
+        }
+
+        return (product >> 64) as usize;
+    }
+}
+
+impl FixedGuide {
+
+    pub fn new(moves: Vec<MoveID>) -> FixedGuide {
+        FixedGuide {
+            moves,
+            next_round: 0
+        }
+    }
+
+    /// Plays the moves chosen in a traced strategy guide, under either reading of its second column.
+    pub fn from_traces(traces: &[RoundTrace]) -> FixedGuide {
+
+        return FixedGuide::new(traces.iter()
+            .map(|trace| trace.chosen_move_id)
+            .collect());
+    }
+}
+
+impl Strategy for FixedGuide {
+
+    fn get_name(&self) -> String { "Fixed guide".to_owned() }
+
+    fn choose_move(&mut self, game: &CyclicGame, random: &mut SeededRandom) -> MoveID {
+
+        if self.moves.is_empty() { return random.below(game.get_number_moves()) }
+
+        let chosen_move: MoveID = self.moves[self.next_round % self.moves.len()];
+        self.next_round += 1;
+        return chosen_move;
+    }
+
+    fn observe(&mut self, _game: &CyclicGame, _own_move: MoveID, _opponent_move: MoveID, _outcome: GameResult) {}
+
+    fn reset(&mut self) { self.next_round = 0 }
+}
+
+impl Strategy for RandomPlayer {
+
+    fn get_name(&self) -> String { "Random".to_owned() }
+
+    fn choose_move(&mut self, game: &CyclicGame, random: &mut SeededRandom) -> MoveID {
+        return random.below(game.get_number_moves());
+    }
+
+    fn observe(&mut self, _game: &CyclicGame, _own_move: MoveID, _opponent_move: MoveID, _outcome: GameResult) {}
+
+    fn reset(&mut self) {}
+}
+
+impl FrequencyCounter {
+
+    pub fn new() -> FrequencyCounter {
+        FrequencyCounter {
+            opponent_counts: Vec::new()
+        }
+    }
+}
+
+impl Default for FrequencyCounter {
+
+    fn default() -> FrequencyCounter {
+        return FrequencyCounter::new();
+    }
+}
+
+impl Strategy for FrequencyCounter {
+
+    fn get_name(&self) -> String { "Frequency counter".to_owned() }
+
+    fn choose_move(&mut self, game: &CyclicGame, random: &mut SeededRandom) -> MoveID {
+
+        return match most_frequent(&self.opponent_counts) {
+            Some(predicted_move) => counter_move(game, predicted_move, random),
+            None => random.below(game.get_number_moves())
+        };
+    }
+
+    fn observe(&mut self, game: &CyclicGame, _own_move: MoveID, opponent_move: MoveID, _outcome: GameResult) {
+
+        self.opponent_counts.resize(game.get_number_moves(), 0);
+        self.opponent_counts[opponent_move] += 1;
+    }
+
+    fn reset(&mut self) { self.opponent_counts.clear() }
+}
+
+impl WinStayLoseShift {
+
+    pub fn new() -> WinStayLoseShift {
+        WinStayLoseShift {
+            last_round: None
+        }
+    }
+}
+
+impl Default for WinStayLoseShift {
+
+    fn default() -> WinStayLoseShift {
+        return WinStayLoseShift::new();
+    }
+}
+
+impl Strategy for WinStayLoseShift {
+
+    fn get_name(&self) -> String { "Win-stay lose-shift".to_owned() }
+
+    fn choose_move(&mut self, game: &CyclicGame, random: &mut SeededRandom) -> MoveID {
+
+        return match self.last_round {
+            Some((own_move, _, GameResult::Win)) => own_move,
+            Some((_, opponent_move, _)) => counter_move(game, opponent_move, random),
+            None => random.below(game.get_number_moves())
+        };
+    }
+
+    fn observe(&mut self, _game: &CyclicGame, own_move: MoveID, opponent_move: MoveID, outcome: GameResult) {
+        self.last_round = Some((own_move, opponent_move, outcome));
+    }
+
+    fn reset(&mut self) { self.last_round = None }
+}
+
+impl MarkovPredictor {
+
+    pub fn new() -> MarkovPredictor {
+        MarkovPredictor {
+            transitions: Vec::new(),
+            last_opponent_move: None
+        }
+    }
+}
+
+impl Default for MarkovPredictor {
+
+    fn default() -> MarkovPredictor {
+        return MarkovPredictor::new();
+    }
+}
+
+impl Strategy for MarkovPredictor {
+
+    fn get_name(&self) -> String { "Markov predictor".to_owned() }
+
+    fn choose_move(&mut self, game: &CyclicGame, random: &mut SeededRandom) -> MoveID {
+
+        let predicted_move: Option<MoveID> = self.last_opponent_move
+            .and_then(|last_move| self.transitions.get(last_move))
+            .and_then(|next_moves| most_frequent(next_moves));
+
+        return match predicted_move {
+            Some(predicted_move) => counter_move(game, predicted_move, random),
+            None => random.below(game.get_number_moves())
+        };
+    }
+
+    fn observe(&mut self, game: &CyclicGame, _own_move: MoveID, opponent_move: MoveID, _outcome: GameResult) {
+
+        let number_moves: usize = game.get_number_moves();
+        if self.transitions.is_empty() { self.transitions = vec![vec![0; number_moves]; number_moves] }
+
+        if let Some(last_move) = self.last_opponent_move {
+            self.transitions[last_move][opponent_move] += 1;
+        }
+        self.last_opponent_move = Some(opponent_move);
+    }
+
+    fn reset(&mut self) {
+
+        self.transitions.clear();
+        self.last_opponent_move = None;
+    }
+}
+
+impl Tournament {
+
+    pub fn new(game: CyclicGame, rounds_per_match: usize, seed: u64) -> Tournament {
+        Tournament {
+            game,
+            rounds_per_match,
+            seed
+        }
+    }
+
+    /// Plays every pair of players against each other, and returns their standings from first to
+    /// last: by matches won, then matches drawn, then points scored.
+    pub fn run(&self, players: &mut [Box<dyn Strategy>]) -> Vec<Standing> {
+
+        let mut standings: Vec<Standing> = players.iter()
+            .map(|player| develop_standing(player.get_name()))
+            .collect();
+
+        for first in 0 .. players.len() {
+            for second in first + 1 .. players.len() {
+
+                let (first_players, second_players) = players.split_at_mut(second);
+                let first_player: &mut Box<dyn Strategy> = &mut first_players[first];
+                let second_player: &mut Box<dyn Strategy> = &mut second_players[0];
+                first_player.reset();
+                second_player.reset();
+
+                let mut first_random: SeededRandom = SeededRandom::new(develop_match_seed(self.seed, first, second));
+                let mut second_random: SeededRandom = SeededRandom::new(develop_match_seed(self.seed, second, first));
+
+                let mut first_rounds_won: usize = 0;
+                let mut second_rounds_won: usize = 0;
+                for _ in 0 .. self.rounds_per_match {
+
+                    let first_move: MoveID = first_player.choose_move(&self.game, &mut first_random);
+                    let second_move: MoveID = second_player.choose_move(&self.game, &mut second_random);
+                    let first_outcome: GameResult = self.game.play_match(first_move, second_move);
+                    let second_outcome: GameResult = self.game.play_match(second_move, first_move);
+
+                    first_player.observe(&self.game, first_move, second_move, first_outcome);
+                    second_player.observe(&self.game, second_move, first_move, second_outcome);
+
+                    record_round(&mut standings[first], first_outcome, self.game.compute_round_score(first_move, first_outcome));
+                    record_round(&mut standings[second], second_outcome, self.game.compute_round_score(second_move, second_outcome));
+                    if first_outcome == GameResult::Win { first_rounds_won += 1 }
+                    if second_outcome == GameResult::Win { second_rounds_won += 1 }
+                }
+
+                match first_rounds_won.cmp(&second_rounds_won) {
+                    std::cmp::Ordering::Greater => { standings[first].matches_won += 1; standings[second].matches_lost += 1; },
+                    std::cmp::Ordering::Less => { standings[first].matches_lost += 1; standings[second].matches_won += 1; },
+                    std::cmp::Ordering::Equal => { standings[first].matches_drawn += 1; standings[second].matches_drawn += 1; }
+                }
+            }
+        }
+
+        standings.sort_by_key(|standing| std::cmp::Reverse((standing.matches_won, standing.matches_drawn, standing.score)));
+        return standings;
+    }
+}
\ No newline at end of file
@@ -0,0 +1,137 @@
+use proptest::prelude::*;
+
+use day_02::{GameResult, GuideError, GuideRound, Score, convert_input_to_rounds, play_guide_setting_outcome, play_guide_setting_play};
+use day_02::breakdown::{GuideSummary, RoundTrace, UnknownMoveError, trace_guide_setting_outcome, trace_guide_setting_play};
+use day_02::engine::{CyclicGame, MoveID};
+use day_02::symbols::GuideSymbols;
+use day_02::tournament::{FixedGuide, SeededRandom, Strategy as PlayerStrategy};
+
+fn develop_rounds(lines: &[&str]) -> Vec<GuideRound> {
+    return convert_input_to_rounds(&lines.iter().map(|line| line.to_string()).collect::<Vec<String>>()).unwrap();
+}
+
+fn final_total(traces: &[RoundTrace]) -> Score {
+    return traces.last().map_or(0, |trace| trace.running_total);
+}
+
+/// Rounds written with the letters of a game of `number_moves` moves, A to C and X to Z for
+/// Rock Paper Scissors.
+fn guide(number_moves: usize) -> impl Strategy<Value = Vec<GuideRound>> {
+
+    return prop::collection::vec((0 .. number_moves, 0 .. number_moves), 0 .. 40)
+        .prop_map(move |plays| plays.into_iter()
+            .enumerate()
+            .map(|(line_index, (opponent, response))| GuideRound {
+                line: line_index + 1,
+                opponent: char::from(b'A' + opponent as u8).to_string(),
+                response: char::from(b'Z' + 1 - number_moves as u8 + response as u8).to_string()
+            })
+            .collect());
+}
+
+#[test]
+fn example_guide() {
+
+    let game: CyclicGame = CyclicGame::rock_paper_scissors();
+    let symbols: GuideSymbols = GuideSymbols::for_game(&game).unwrap();
+    let rounds: Vec<GuideRound> = develop_rounds(&["A Y", "B X", "C Z"]);
+
+    let traces: Vec<RoundTrace> = trace_guide_setting_play(&game, &symbols, &rounds).unwrap();
+    assert_eq!(traces.iter().map(|trace| (trace.chosen_move_id, trace.outcome, trace.running_total)).collect::<Vec<(MoveID, GameResult, Score)>>(),
+        vec![(1, GameResult::Win, 8), (0, GameResult::Lose, 9), (2, GameResult::Draw, 15)]);
+    assert_eq!(play_guide_setting_play(&game, &symbols, &rounds), Ok(15));
+
+    let traces: Vec<RoundTrace> = trace_guide_setting_outcome(&game, &symbols, &rounds).unwrap();
+    assert_eq!(traces.iter().map(|trace| trace.chosen_move.as_str()).collect::<Vec<&str>>(), vec!["Rock", "Rock", "Rock"]);
+    assert_eq!(play_guide_setting_outcome(&game, &symbols, &rounds), Ok(12));
+
+    let summary: GuideSummary = GuideSummary::new(&game, &traces).unwrap();
+    assert_eq!((summary.wins, summary.draws, summary.losses, summary.total), (1, 1, 1, 12));
+    assert_eq!(summary.move_contributions.iter().map(|contribution| contribution.rounds).collect::<Vec<usize>>(), vec![3, 0, 0]);
+}
+
+#[test]
+fn moves_sharing_a_name_stay_apart() {
+
+    // Two moves called "Rock", worth 1 and 2 points, both beaten by Paper and beating nothing
+    let game: CyclicGame = CyclicGame::new(vec!["Rock".to_owned(), "Rock".to_owned(), "Paper".to_owned()], &[(2, 0), (2, 1)]).unwrap();
+    let symbols: GuideSymbols = GuideSymbols::for_game(&game).unwrap();
+    let rounds: Vec<GuideRound> = develop_rounds(&["A Y", "C Y", "B X"]);
+
+    let traces: Vec<RoundTrace> = trace_guide_setting_play(&game, &symbols, &rounds).unwrap();
+    assert_eq!(traces.iter().map(|trace| trace.chosen_move_id).collect::<Vec<MoveID>>(), vec![1, 1, 0]);
+    assert!(traces.iter().all(|trace| trace.chosen_move == "Rock"));
+
+    let summary: GuideSummary = GuideSummary::new(&game, &traces).unwrap();
+    assert_eq!(summary.move_contributions.iter().map(|contribution| (contribution.rounds, contribution.shape_points)).collect::<Vec<(usize, Score)>>(),
+        vec![(1, 1), (2, 4), (0, 0)]);
+    assert_eq!(summary.total, play_guide_setting_play(&game, &symbols, &rounds).unwrap());
+
+    let mut guide: FixedGuide = FixedGuide::from_traces(&traces);
+    let mut random: SeededRandom = SeededRandom::new(0);
+    assert_eq!((0 .. 3).map(|_| guide.choose_move(&game, &mut random)).collect::<Vec<MoveID>>(), vec![1, 1, 0]);
+}
+
+#[test]
+fn summaries_look_up_moves_by_name() {
+
+    let game: CyclicGame = CyclicGame::rock_paper_scissors();
+    let symbols: GuideSymbols = GuideSymbols::for_game(&game).unwrap();
+    let mut traces: Vec<RoundTrace> = trace_guide_setting_play(&game, &symbols, &develop_rounds(&["A Y", "B Z"])).unwrap();
+
+    // Ids out of the game, or naming another move, give way to the traced name
+    traces[0].chosen_move_id = 7;
+    traces[1].chosen_move_id = 0;
+    let summary: GuideSummary = GuideSummary::new(&game, &traces).unwrap();
+    assert_eq!(summary.move_contributions.iter().map(|contribution| contribution.rounds).collect::<Vec<usize>>(), vec![0, 1, 1]);
+
+    traces[1].chosen_move = "Lizard".to_owned();
+    assert_eq!(GuideSummary::new(&game, &traces), Err(UnknownMoveError { line: 2, chosen_move: "Lizard".to_owned() }));
+    assert_eq!(UnknownMoveError { line: 2, chosen_move: "Lizard".to_owned() }.to_string(), "line 2: 'Lizard' is not a move of the game");
+}
+
+#[test]
+fn scores_fail_like_traces() {
+
+    let game: CyclicGame = CyclicGame::rock_paper_scissors();
+    let symbols: GuideSymbols = GuideSymbols::for_game(&game).unwrap();
+
+    let rounds: Vec<GuideRound> = develop_rounds(&["A Y", "D X", "B W"]);
+    assert_eq!(play_guide_setting_play(&game, &symbols, &rounds), Err(GuideError::UnknownOpponentSymbol { line: 2, symbol: "D".to_owned() }));
+    assert_eq!(trace_guide_setting_play(&game, &symbols, &rounds), Err(GuideError::UnknownOpponentSymbol { line: 2, symbol: "D".to_owned() }));
+
+    // A single move draws against itself, and can neither win nor lose
+    let game: CyclicGame = CyclicGame::cyclic(vec!["Rock".to_owned()]).unwrap();
+    let symbols: GuideSymbols = GuideSymbols::for_game(&game).unwrap();
+    let rounds: Vec<GuideRound> = develop_rounds(&["A Y", "A Z"]);
+    let error: GuideError = GuideError::ImpossibleOutcome { line: 2, outcome: GameResult::Win, opponent_move: "Rock".to_owned() };
+    assert_eq!(play_guide_setting_outcome(&game, &symbols, &rounds), Err(error.clone()));
+    assert_eq!(trace_guide_setting_outcome(&game, &symbols, &rounds), Err(error));
+    assert_eq!(play_guide_setting_outcome(&game, &symbols, &[]), Ok(0));
+}
+
+fn game_and_guide() -> impl Strategy<Value = (CyclicGame, Vec<GuideRound>)> {
+
+    return prop_oneof![Just(CyclicGame::rock_paper_scissors()), Just(CyclicGame::rock_paper_scissors_lizard_spock())]
+        .prop_flat_map(|game| {
+            let number_moves: usize = game.get_number_moves();
+            (Just(game), guide(number_moves))
+        });
+}
+
+proptest! {
+
+    #[test]
+    fn scores_match_the_traced_totals((game, rounds) in game_and_guide()) {
+
+        let symbols: GuideSymbols = GuideSymbols::for_game(&game).unwrap();
+
+        let traces: Vec<RoundTrace> = trace_guide_setting_play(&game, &symbols, &rounds).unwrap();
+        prop_assert_eq!(play_guide_setting_play(&game, &symbols, &rounds), Ok(final_total(&traces)));
+        prop_assert_eq!(GuideSummary::new(&game, &traces).unwrap().total, final_total(&traces));
+
+        // Symbols V and W of the larger game stand for no outcome, and both fail on the same line
+        prop_assert_eq!(play_guide_setting_outcome(&game, &symbols, &rounds),
+            trace_guide_setting_outcome(&game, &symbols, &rounds).map(|traces| final_total(&traces)));
+    }
+}
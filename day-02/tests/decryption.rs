@@ -0,0 +1,92 @@
+use proptest::prelude::*;
+
+use day_02::{GuideError, GuideRound, Score, convert_input_to_rounds, play_guide_setting_outcome, play_guide_setting_play};
+use day_02::decryption::{DecryptionCandidate, GuideInterpretation, infer_decryptions, rank_decryptions};
+use day_02::engine::CyclicGame;
+use day_02::symbols::GuideSymbols;
+
+fn develop_rounds(lines: &[&str]) -> Vec<GuideRound> {
+    return convert_input_to_rounds(&lines.iter().map(|line| line.to_string()).collect::<Vec<String>>()).unwrap();
+}
+
+fn count_interpretation(candidates: &[DecryptionCandidate], interpretation: GuideInterpretation) -> usize {
+    return candidates.iter().filter(|candidate| candidate.interpretation == interpretation).count();
+}
+
+#[test]
+fn example_guide() {
+
+    let game: CyclicGame = CyclicGame::rock_paper_scissors();
+    let symbols: GuideSymbols = GuideSymbols::for_game(&game).unwrap();
+    let rounds: Vec<GuideRound> = develop_rounds(&["A Y", "B X", "C Z"]);
+
+    // Three symbols go to three moves, or to three outcomes, in six ways each
+    let candidates: Vec<DecryptionCandidate> = rank_decryptions(&game, &symbols, &rounds).unwrap();
+    assert_eq!(count_interpretation(&candidates, GuideInterpretation::SettingPlay), 6);
+    assert_eq!(count_interpretation(&candidates, GuideInterpretation::SettingOutcome), 6);
+    assert!(candidates.windows(2).all(|pair| pair[0].score >= pair[1].score));
+
+    let candidates: Vec<DecryptionCandidate> = infer_decryptions(&game, &symbols, &rounds, 15).unwrap();
+    assert!(candidates.iter().any(|candidate| candidate.interpretation == GuideInterpretation::SettingPlay
+        && candidate.describe_mapping(&game) == [("X", "Rock"), ("Y", "Paper"), ("Z", "Scissors")].map(|(symbol, meaning)| (symbol.to_owned(), meaning.to_owned()))));
+    assert!(infer_decryptions(&game, &symbols, &rounds, 12).unwrap().iter().any(|candidate| candidate.interpretation == GuideInterpretation::SettingOutcome));
+    assert_eq!(infer_decryptions(&game, &symbols, &rounds, 1000), Ok(Vec::new()));
+}
+
+#[test]
+fn too_many_symbols_are_errors() {
+
+    let game: CyclicGame = CyclicGame::rock_paper_scissors();
+    let symbols: GuideSymbols = GuideSymbols::for_game(&game).unwrap();
+    let rounds: Vec<GuideRound> = develop_rounds(&["A W", "B X", "C Y", "A Z"]);
+
+    let error: GuideError = GuideError::TooManyResponseSymbols { symbols: 4, moves: 3, outcomes: 3 };
+    assert_eq!(rank_decryptions(&game, &symbols, &rounds), Err(error.clone()));
+    assert_eq!(infer_decryptions(&game, &symbols, &rounds, 10), Err(error.clone()));
+    assert_eq!(error.to_string(), "4 distinct symbols in the second column, but only 3 moves and 3 outcomes to read them as");
+
+    // Five moves are enough to read four symbols as moves, though not as outcomes
+    let game: CyclicGame = CyclicGame::rock_paper_scissors_lizard_spock();
+    let symbols: GuideSymbols = GuideSymbols::for_game(&game).unwrap();
+    let candidates: Vec<DecryptionCandidate> = rank_decryptions(&game, &symbols, &develop_rounds(&["A V", "B W", "C X", "D Y"])).unwrap();
+    assert_eq!(candidates.len(), 5 * 4 * 3 * 2);
+    assert_eq!(count_interpretation(&candidates, GuideInterpretation::SettingOutcome), 0);
+}
+
+#[test]
+fn other_errors_still_come_through() {
+
+    let game: CyclicGame = CyclicGame::rock_paper_scissors();
+    let symbols: GuideSymbols = GuideSymbols::for_game(&game).unwrap();
+    assert_eq!(rank_decryptions(&game, &symbols, &develop_rounds(&["A X", "D Y"])), Err(GuideError::UnknownOpponentSymbol { line: 2, symbol: "D".to_owned() }));
+
+    // An empty guide has a single reading of each kind, worth nothing
+    let candidates: Vec<DecryptionCandidate> = rank_decryptions(&game, &symbols, &[]).unwrap();
+    assert_eq!(candidates.iter().map(|candidate| (candidate.interpretation, candidate.score)).collect::<Vec<(GuideInterpretation, Score)>>(),
+        vec![(GuideInterpretation::SettingPlay, 0), (GuideInterpretation::SettingOutcome, 0)]);
+}
+
+proptest! {
+
+    #[test]
+    fn readings_cover_the_usual_ones(plays in prop::collection::vec((0u8 .. 3, 0u8 .. 3), 1 .. 30)) {
+
+        let game: CyclicGame = CyclicGame::rock_paper_scissors();
+        let symbols: GuideSymbols = GuideSymbols::for_game(&game).unwrap();
+        let rounds: Vec<GuideRound> = plays.iter()
+            .enumerate()
+            .map(|(line_index, (opponent, response))| GuideRound {
+                line: line_index + 1,
+                opponent: char::from(b'A' + opponent).to_string(),
+                response: char::from(b'X' + response).to_string()
+            })
+            .collect();
+
+        let candidates: Vec<DecryptionCandidate> = rank_decryptions(&game, &symbols, &rounds).unwrap();
+        let play_score: Score = play_guide_setting_play(&game, &symbols, &rounds).unwrap();
+        let outcome_score: Score = play_guide_setting_outcome(&game, &symbols, &rounds).unwrap();
+        prop_assert!(candidates.iter().any(|candidate| candidate.interpretation == GuideInterpretation::SettingPlay && candidate.score == play_score));
+        prop_assert!(candidates.iter().any(|candidate| candidate.interpretation == GuideInterpretation::SettingOutcome && candidate.score == outcome_score));
+        prop_assert_eq!(infer_decryptions(&game, &symbols, &rounds, play_score).unwrap().len(), candidates.iter().filter(|candidate| candidate.score == play_score).count());
+    }
+}
@@ -0,0 +1,167 @@
+use proptest::prelude::*;
+
+use day_02::GameResult;
+use day_02::engine::CyclicGame;
+use day_02::equilibrium::{Equilibrium, MatrixSide, MixedStrategy, PayoffMatrix, PayoffMatrixError, Probability};
+use day_02::equilibrium::{compute_expected_score, develop_payoff_matrix, find_best_response, solve_zero_sum_game};
+
+const TOLERANCE: f64 = 1e-6;
+
+fn assert_close(found: &[f64], expected: &[f64]) {
+
+    assert_eq!(found.len(), expected.len());
+    assert!(found.iter().zip(expected).all(|(found, expected)| (found - expected).abs() < TOLERANCE), "{:?} != {:?}", found, expected);
+}
+
+fn pure_strategy(number_moves: usize, chosen_move: usize) -> MixedStrategy {
+    return (0 .. number_moves).map(|move_id| if move_id == chosen_move { 1.0 } else { 0.0 }).collect();
+}
+
+fn transpose(payoff_matrix: &PayoffMatrix) -> PayoffMatrix {
+    return (0 .. payoff_matrix[0].len()).map(|column| payoff_matrix.iter().map(|payoffs| payoffs[column]).collect()).collect();
+}
+
+/// Checks that neither player gains by leaving their strategy: the player's strategy scores at
+/// least the value against every column, and the opponent's at most the value against every row.
+fn assert_equilibrium(equilibrium: &Equilibrium) {
+
+    let payoff_matrix: &PayoffMatrix = &equilibrium.payoff_matrix;
+    for strategy in [&equilibrium.self_strategy, &equilibrium.opponent_strategy] {
+        assert!(strategy.iter().all(|probability| *probability >= -TOLERANCE));
+        assert!((strategy.iter().sum::<Probability>() - 1.0).abs() < TOLERANCE);
+    }
+
+    for column in 0 .. payoff_matrix[0].len() {
+        let score: f64 = compute_expected_score(payoff_matrix, &equilibrium.self_strategy, &pure_strategy(payoff_matrix[0].len(), column)).unwrap();
+        assert!(score >= equilibrium.value - TOLERANCE, "column {}: {} < {}", column, score, equilibrium.value);
+    }
+    for row in 0 .. payoff_matrix.len() {
+        let score: f64 = compute_expected_score(payoff_matrix, &pure_strategy(payoff_matrix.len(), row), &equilibrium.opponent_strategy).unwrap();
+        assert!(score <= equilibrium.value + TOLERANCE, "row {}: {} > {}", row, score, equilibrium.value);
+    }
+
+    let score: f64 = compute_expected_score(payoff_matrix, &equilibrium.self_strategy, &equilibrium.opponent_strategy).unwrap();
+    assert!((score - equilibrium.value).abs() < TOLERANCE);
+}
+
+fn payoff_matrix() -> impl Strategy<Value = PayoffMatrix> {
+
+    return (1usize ..= 5, 1usize ..= 5)
+        .prop_flat_map(|(rows, columns)| prop::collection::vec(prop::collection::vec(-20i32 ..= 20, columns), rows))
+        .prop_map(|payoffs| payoffs.into_iter().map(|row| row.into_iter().map(f64::from).collect()).collect());
+}
+
+#[test]
+fn rock_paper_scissors_is_uniform() {
+
+    let payoff_matrix: PayoffMatrix = vec![vec![0.0, -1.0, 1.0], vec![1.0, 0.0, -1.0], vec![-1.0, 1.0, 0.0]];
+    let equilibrium: Equilibrium = solve_zero_sum_game(&payoff_matrix).unwrap();
+    assert_close(&equilibrium.self_strategy, &[1.0 / 3.0; 3]);
+    assert_close(&equilibrium.opponent_strategy, &[1.0 / 3.0; 3]);
+    assert_close(&[equilibrium.value], &[0.0]);
+
+    // Each move beats two and loses to two in Rock Paper Scissors Lizard Spock
+    let game: CyclicGame = CyclicGame::rock_paper_scissors_lizard_spock();
+    let payoff_matrix: PayoffMatrix = (0 .. 5)
+        .map(|self_move| (0 .. 5)
+            .map(|opponent_move| match game.play_match(self_move, opponent_move) {
+                GameResult::Win => 1.0,
+                GameResult::Draw => 0.0,
+                GameResult::Lose => -1.0
+            })
+            .collect())
+        .collect();
+    let equilibrium: Equilibrium = solve_zero_sum_game(&payoff_matrix).unwrap();
+    assert_close(&equilibrium.self_strategy, &[0.2; 5]);
+    assert_close(&equilibrium.opponent_strategy, &[0.2; 5]);
+    assert_close(&[equilibrium.value], &[0.0]);
+}
+
+#[test]
+fn known_two_by_two_game() {
+
+    // The row player mixes 3/7 and 4/7, the column player 2/7 and 5/7, for a value of 1/7
+    let equilibrium: Equilibrium = solve_zero_sum_game(&vec![vec![3.0, -1.0], vec![-2.0, 1.0]]).unwrap();
+    assert_close(&equilibrium.self_strategy, &[3.0 / 7.0, 4.0 / 7.0]);
+    assert_close(&equilibrium.opponent_strategy, &[2.0 / 7.0, 5.0 / 7.0]);
+    assert_close(&[equilibrium.value], &[1.0 / 7.0]);
+
+    // A saddle point is played as pure strategies
+    let equilibrium: Equilibrium = solve_zero_sum_game(&vec![vec![4.0, 2.0], vec![3.0, 1.0]]).unwrap();
+    assert_close(&equilibrium.self_strategy, &[1.0, 0.0]);
+    assert_close(&equilibrium.opponent_strategy, &[0.0, 1.0]);
+    assert_close(&[equilibrium.value], &[2.0]);
+}
+
+#[test]
+fn puzzle_game_equilibrium() {
+
+    let equilibrium: Equilibrium = solve_zero_sum_game(&develop_payoff_matrix(&CyclicGame::rock_paper_scissors())).unwrap();
+    assert_equilibrium(&equilibrium);
+}
+
+#[test]
+fn matrices_without_moves_are_errors() {
+
+    assert_eq!(solve_zero_sum_game(&Vec::new()), Err(PayoffMatrixError::NoMoves));
+    assert_eq!(solve_zero_sum_game(&vec![Vec::new(), Vec::new()]), Err(PayoffMatrixError::NoMoves));
+    assert_eq!(solve_zero_sum_game(&vec![vec![1.0, 2.0], vec![3.0]]), Err(PayoffMatrixError::RaggedRow { row: 1, expected: 2, found: 1 }));
+    assert_eq!(solve_zero_sum_game(&vec![vec![1.0, 2.0], vec![3.0, f64::NAN]]), Err(PayoffMatrixError::NonFinitePayoff { row: 1, column: 1 }));
+    assert_eq!(PayoffMatrixError::NoMoves.to_string(), "the payoff matrix needs at least one row and one column");
+
+    // A single move on each side is its own equilibrium
+    let equilibrium: Equilibrium = solve_zero_sum_game(&vec![vec![-4.0]]).unwrap();
+    assert_eq!((equilibrium.self_strategy, equilibrium.opponent_strategy), (vec![1.0], vec![1.0]));
+    assert_close(&[equilibrium.value], &[-4.0]);
+}
+
+#[test]
+fn strategies_must_fit_the_matrix() {
+
+    let payoff_matrix: PayoffMatrix = vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]];
+    assert_eq!(compute_expected_score(&payoff_matrix, &[0.5, 0.5], &[0.5, 0.5]),
+        Err(PayoffMatrixError::StrategyLength { side: MatrixSide::Columns, expected: 3, found: 2 }));
+    assert_eq!(compute_expected_score(&payoff_matrix, &[1.0], &[0.0, 0.0, 1.0]),
+        Err(PayoffMatrixError::StrategyLength { side: MatrixSide::Rows, expected: 2, found: 1 }));
+    assert_eq!(compute_expected_score(&payoff_matrix, &[0.5, 0.6], &[0.0, 0.0, 1.0]), Err(PayoffMatrixError::NotADistribution { side: MatrixSide::Rows }));
+    assert_eq!(compute_expected_score(&payoff_matrix, &[0.5, 0.5], &[1.5, -0.5, 0.0]), Err(PayoffMatrixError::NotADistribution { side: MatrixSide::Columns }));
+    assert_eq!(compute_expected_score(&payoff_matrix, &[0.5, 0.5], &[0.0, 0.0, 0.0]), Err(PayoffMatrixError::NotADistribution { side: MatrixSide::Columns }));
+    assert_eq!(compute_expected_score(&vec![vec![1.0], vec![]], &[0.5, 0.5], &[1.0]), Err(PayoffMatrixError::RaggedRow { row: 1, expected: 1, found: 0 }));
+    assert_eq!(PayoffMatrixError::StrategyLength { side: MatrixSide::Columns, expected: 3, found: 2 }.to_string(),
+        "the strategy over the columns has 2 probabilities instead of 3");
+
+    // Probabilities off by rounding still add up to 1
+    let third: Probability = 1.0 / 3.0;
+    assert_close(&[compute_expected_score(&payoff_matrix, &[0.0, 1.0], &[third, third, third]).unwrap()], &[5.0]);
+}
+
+proptest! {
+
+    #[test]
+    fn solutions_are_equilibria(payoff_matrix in payoff_matrix()) {
+
+        let equilibrium: Equilibrium = solve_zero_sum_game(&payoff_matrix).unwrap();
+        assert_equilibrium(&equilibrium);
+
+        // Swapping the roles of the players negates the value
+        let swapped: PayoffMatrix = transpose(&payoff_matrix).iter().map(|payoffs| payoffs.iter().map(|payoff| -payoff).collect()).collect();
+        prop_assert!((solve_zero_sum_game(&swapped).unwrap().value + equilibrium.value).abs() < TOLERANCE);
+    }
+
+    #[test]
+    fn best_response_beats_or_ties_every_move(payoff_matrix in payoff_matrix(), weights in prop::collection::vec(0u32 ..= 10, 5)) {
+
+        let number_columns: usize = payoff_matrix[0].len();
+        let total_weight: u32 = weights[.. number_columns].iter().sum::<u32>();
+        prop_assume!(total_weight > 0);
+        let opponent_strategy: MixedStrategy = weights[.. number_columns].iter().map(|weight| f64::from(*weight) / f64::from(total_weight)).collect();
+
+        let (best_response, best_score) = find_best_response(&payoff_matrix, &opponent_strategy);
+        let scores: Vec<f64> = (0 .. payoff_matrix.len())
+            .map(|row| compute_expected_score(&payoff_matrix, &pure_strategy(payoff_matrix.len(), row), &opponent_strategy).unwrap())
+            .collect();
+
+        prop_assert!((scores[best_response] - best_score).abs() < TOLERANCE);
+        prop_assert!(scores.iter().all(|score| best_score >= score - TOLERANCE));
+    }
+}
@@ -0,0 +1,29 @@
+use day_02::formats::{strategy_guide_from_csv, strategy_guide_from_json, strategy_guide_to_csv, strategy_guide_to_json};
+
+const GUIDE: [(char, char); 4] = [('A', 'Y'), ('B', 'X'), ('C', 'Z'), ('ü', ',')];
+
+#[test]
+fn json_round_trip() {
+
+    let json: String = strategy_guide_to_json(&GUIDE).unwrap();
+    assert_eq!(json, r#"[{"opponent":"A","response":"Y"},{"opponent":"B","response":"X"},{"opponent":"C","response":"Z"},{"opponent":"ü","response":","}]"#);
+    assert_eq!(strategy_guide_from_json(&json).unwrap(), GUIDE);
+    assert_eq!(strategy_guide_from_json("[]").unwrap(), Vec::new());
+}
+
+#[test]
+fn csv_round_trip() {
+
+    let csv: String = strategy_guide_to_csv(&GUIDE).unwrap();
+    assert_eq!(csv, "opponent,response\nA,Y\nB,X\nC,Z\nü,\",\"\n");
+    assert_eq!(strategy_guide_from_csv(&csv).unwrap(), GUIDE);
+}
+
+#[test]
+fn malformed_records_are_errors() {
+
+    assert!(strategy_guide_from_json(r#"[{"opponent": "AB", "response": "Y"}]"#).is_err());
+    assert!(strategy_guide_from_json(r#"[{"opponent": "A"}]"#).is_err());
+    assert!(strategy_guide_from_csv("opponent,response\nA,YY\n").is_err());
+    assert!(strategy_guide_from_csv("opponent,response\nA\n").is_err());
+}
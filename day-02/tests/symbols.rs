@@ -0,0 +1,127 @@
+use day_02::{GameResult, GuideRound, Score, convert_input_to_rounds, play_guide_setting_outcome, play_guide_setting_play};
+use day_02::engine::{CyclicGame, MoveID};
+use day_02::symbols::{GuideSymbols, MAX_LETTER_MOVES, SymbolConfigError};
+
+/// The ten wins of Rock Paper Scissors Lizard Spock, as the rules state them.
+const RPSLS_WINS: [(&str, &str); 10] = [
+    ("Scissors", "Paper"), ("Paper", "Rock"), ("Rock", "Lizard"), ("Lizard", "Spock"), ("Spock", "Scissors"),
+    ("Scissors", "Lizard"), ("Lizard", "Paper"), ("Paper", "Spock"), ("Spock", "Rock"), ("Rock", "Scissors")
+];
+
+fn develop_cyclic_game(number_moves: usize) -> CyclicGame {
+    return CyclicGame::cyclic((0 .. number_moves).map(|move_id| format!("Move{}", move_id)).collect()).unwrap();
+}
+
+fn letter(first: u8, offset: usize) -> char {
+    return char::from(first + offset as u8);
+}
+
+fn rpsls_outcome(self_move: &str, opponent_move: &str) -> GameResult {
+
+    if RPSLS_WINS.contains(&(self_move, opponent_move)) { return GameResult::Win }
+    if RPSLS_WINS.contains(&(opponent_move, self_move)) { return GameResult::Lose }
+    return GameResult::Draw;
+}
+
+fn rounds(lines: &[String]) -> Vec<GuideRound> {
+    return convert_input_to_rounds(lines).unwrap();
+}
+
+#[test]
+fn rpsls_letters() {
+
+    let game: CyclicGame = CyclicGame::rock_paper_scissors_lizard_spock();
+    let symbols: GuideSymbols = GuideSymbols::for_game(&game).unwrap();
+
+    for (move_id, (opponent_symbol, self_symbol)) in ["A", "B", "C", "D", "E"].into_iter().zip(["V", "W", "X", "Y", "Z"]).enumerate() {
+        assert_eq!(symbols.opponent_moves[opponent_symbol], move_id);
+        assert_eq!(symbols.self_moves[self_symbol], move_id);
+    }
+    assert_eq!((symbols.opponent_moves.len(), symbols.self_moves.len(), symbols.outcomes.len()), (5, 5, 3));
+}
+
+#[test]
+fn rpsls_scores_every_round_by_the_rules() {
+
+    let game: CyclicGame = CyclicGame::rock_paper_scissors_lizard_spock();
+    let symbols: GuideSymbols = GuideSymbols::for_game(&game).unwrap();
+
+    for opponent in 0 .. 5 {
+        for response in 0 .. 5 {
+
+            let line: String = format!("{} {}", letter(b'A', opponent), letter(b'V', response));
+            let outcome: GameResult = rpsls_outcome(game.get_move_name(response), game.get_move_name(opponent));
+            assert_eq!(play_guide_setting_play(&game, &symbols, &rounds(std::slice::from_ref(&line))), Ok(response + 1 + game.get_outcome_scores().get_score(outcome)), "{}", line);
+        }
+    }
+}
+
+#[test]
+fn rpsls_predicts_the_best_move_for_each_outcome() {
+
+    let game: CyclicGame = CyclicGame::rock_paper_scissors_lizard_spock();
+    let symbols: GuideSymbols = GuideSymbols::for_game(&game).unwrap();
+
+    for opponent in 0 .. 5 {
+        for (outcome, outcome_symbol) in [(GameResult::Lose, "X"), (GameResult::Draw, "Y"), (GameResult::Win, "Z")] {
+
+            // Two moves win and two lose against each move, and the one worth the most points is played
+            let best_move: MoveID = (0 .. 5)
+                .filter(|self_move| rpsls_outcome(game.get_move_name(*self_move), game.get_move_name(opponent)) == outcome)
+                .max()
+                .unwrap();
+            assert_eq!(game.predict_match(outcome, opponent), Some(best_move));
+
+            let line: String = format!("{} {}", letter(b'A', opponent), outcome_symbol);
+            assert_eq!(play_guide_setting_outcome(&game, &symbols, &rounds(&[line])),
+                Ok(game.compute_round_score(best_move, outcome)));
+        }
+    }
+
+    // Paper covers Rock and Spock vaporizes it, and Paper is worth more
+    assert_eq!(game.get_move_name(game.predict_match(GameResult::Win, 0).unwrap()), "Paper");
+}
+
+#[test]
+fn larger_cyclic_games_have_letters_up_to_z() {
+
+    for number_moves in [1, 3, 7, 15, 25] {
+
+        let game: CyclicGame = develop_cyclic_game(number_moves);
+        let symbols: GuideSymbols = GuideSymbols::for_game(&game).unwrap();
+
+        // Every pair of moves once: each move wins and loses (n - 1) / 2 rounds and draws one
+        let lines: Vec<String> = (0 .. number_moves)
+            .flat_map(|opponent| (0 .. number_moves)
+                .map(move |response| format!("{} {}", letter(b'A', opponent), letter(b'Z' + 1 - number_moves as u8, response))))
+            .collect();
+        let move_points: Score = number_moves * (1 ..= number_moves).sum::<Score>();
+        let outcome_points: Score = number_moves * (3 + (number_moves - 1) / 2 * 6);
+        assert_eq!(play_guide_setting_play(&game, &symbols, &rounds(&lines)), Ok(move_points + outcome_points), "{} moves", number_moves);
+
+        // Winning against every move plays the best of the moves that win, and no move wins in a
+        // game of a single move
+        let lines: Vec<String> = (0 .. number_moves).map(|opponent| format!("{} Z", letter(b'A', opponent))).collect();
+        let expected: Option<Score> = (0 .. number_moves)
+            .map(|opponent| game.predict_match(GameResult::Win, opponent)
+                .map(|self_move| game.compute_round_score(self_move, GameResult::Win)))
+            .sum();
+        assert_eq!(expected.is_none(), number_moves == 1);
+        assert_eq!(play_guide_setting_outcome(&game, &symbols, &rounds(&lines)).ok(), expected, "{} moves", number_moves);
+    }
+}
+
+#[test]
+fn games_past_z_have_no_letters() {
+
+    assert!(GuideSymbols::for_game(&develop_cyclic_game(MAX_LETTER_MOVES - 1)).is_ok());
+
+    let moves: Vec<String> = (0 .. MAX_LETTER_MOVES).map(|move_id| format!("Move{}", move_id)).collect();
+    assert!(GuideSymbols::for_game(&CyclicGame::new(moves, &[]).unwrap()).is_ok());
+
+    for number_moves in [MAX_LETTER_MOVES + 1, 255, 256, 300] {
+        let moves: Vec<String> = (0 .. number_moves).map(|move_id| format!("Move{}", move_id)).collect();
+        assert!(matches!(GuideSymbols::for_game(&CyclicGame::new(moves, &[]).unwrap()),
+            Err(SymbolConfigError::TooManyMoves { number_moves: found }) if found == number_moves));
+    }
+}
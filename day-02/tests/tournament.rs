@@ -0,0 +1,184 @@
+use proptest::prelude::*;
+
+use day_02::GameResult;
+use day_02::engine::{CyclicGame, MoveID};
+use day_02::tournament::{FixedGuide, FrequencyCounter, MarkovPredictor, RandomPlayer, SeededRandom, Standing, Strategy as PlayerStrategy};
+use day_02::tournament::{Tournament, WinStayLoseShift};
+
+const ROCK: MoveID = 0;
+const PAPER: MoveID = 1;
+const SCISSORS: MoveID = 2;
+
+/// Always plays Rock, but draws a number every round, like a player with randomness to spend.
+struct DrawingRock;
+
+impl PlayerStrategy for DrawingRock {
+
+    fn get_name(&self) -> String { "Drawing rock".to_owned() }
+
+    fn choose_move(&mut self, _game: &CyclicGame, random: &mut SeededRandom) -> MoveID {
+
+        random.next_u64();
+        return ROCK;
+    }
+
+    fn observe(&mut self, _game: &CyclicGame, _own_move: MoveID, _opponent_move: MoveID, _outcome: GameResult) {}
+
+    fn reset(&mut self) {}
+}
+
+fn develop_players() -> Vec<Box<dyn PlayerStrategy>> {
+
+    return vec![
+        Box::new(FixedGuide::new(vec![ROCK, PAPER, PAPER])),
+        Box::new(RandomPlayer),
+        Box::new(FrequencyCounter::new()),
+        Box::new(WinStayLoseShift::new()),
+        Box::new(MarkovPredictor::new())
+    ];
+}
+
+fn develop_moves(player: &mut dyn PlayerStrategy, game: &CyclicGame, rounds: usize) -> Vec<MoveID> {
+
+    let mut random: SeededRandom = SeededRandom::new(7);
+    return (0 .. rounds).map(|_| player.choose_move(game, &mut random)).collect();
+}
+
+fn find_standing<'a>(standings: &'a [Standing], name: &str) -> &'a Standing {
+    return standings.iter().find(|standing| standing.name == name).unwrap();
+}
+
+#[test]
+fn draws_are_uniform() {
+
+    let mut random: SeededRandom = SeededRandom::new(2022);
+    let mut counts: [usize; 3] = [0; 3];
+    for _ in 0 .. 30_000 { counts[random.below(3)] += 1 }
+    assert!(counts.iter().all(|count| count.abs_diff(10_000) < 500), "{:?}", counts);
+
+    // A draw taken modulo three quarters of 2^64 would fall below 2^62 half of the time
+    let bound: usize = 3 << 62;
+    let lowest: usize = (0 .. 30_000).filter(|_| random.below(bound) < 1 << 62).count();
+    assert!(lowest.abs_diff(10_000) < 500, "{}", lowest);
+
+    assert!((0 .. 100).all(|_| random.below(1) == 0));
+}
+
+#[test]
+#[should_panic]
+fn nothing_to_draw_below_zero() {
+    SeededRandom::new(0).below(0);
+}
+
+#[test]
+fn same_seed_same_standings() {
+
+    let game: CyclicGame = CyclicGame::rock_paper_scissors();
+    let standings: Vec<Standing> = Tournament::new(game.clone(), 200, 2022).run(&mut develop_players());
+    assert_eq!(Tournament::new(game.clone(), 200, 2022).run(&mut develop_players()), standings);
+    assert_ne!(Tournament::new(game, 200, 2023).run(&mut develop_players()), standings);
+
+    // Every round is counted once for each of its two players
+    assert_eq!(standings.iter().map(|standing| standing.rounds_won).sum::<usize>(), standings.iter().map(|standing| standing.rounds_lost).sum::<usize>());
+    assert!(standings.iter().all(|standing| standing.rounds_won + standing.rounds_drawn + standing.rounds_lost == 4 * 200));
+    assert!(standings.windows(2).all(|pair| (pair[0].matches_won, pair[0].matches_drawn, pair[0].score) >= (pair[1].matches_won, pair[1].matches_drawn, pair[1].score)));
+}
+
+#[test]
+fn matches_ignore_the_draws_of_other_matches() {
+
+    // The random player meets Rock twice in both tournaments, but only one of them spends draws
+    let tournament: Tournament = Tournament::new(CyclicGame::rock_paper_scissors(), 300, 2022);
+    let with_draws: Vec<Standing> = tournament.run(&mut [Box::new(RandomPlayer), Box::new(DrawingRock), Box::new(FixedGuide::new(vec![ROCK]))]);
+    let without_draws: Vec<Standing> = tournament.run(&mut [Box::new(RandomPlayer), Box::new(FixedGuide::new(vec![ROCK])), Box::new(FixedGuide::new(vec![ROCK]))]);
+
+    assert_eq!(find_standing(&with_draws, "Random"), find_standing(&without_draws, "Random"));
+}
+
+#[test]
+fn fixed_guide_cycles() {
+
+    let game: CyclicGame = CyclicGame::rock_paper_scissors();
+    let mut guide: FixedGuide = FixedGuide::new(vec![ROCK, SCISSORS]);
+    assert_eq!(develop_moves(&mut guide, &game, 5), vec![ROCK, SCISSORS, ROCK, SCISSORS, ROCK]);
+
+    guide.reset();
+    assert_eq!(develop_moves(&mut guide, &game, 2), vec![ROCK, SCISSORS]);
+    assert!(develop_moves(&mut FixedGuide::new(Vec::new()), &game, 50).iter().all(|chosen_move| *chosen_move < 3));
+}
+
+#[test]
+fn frequency_counter_counters_the_most_played_move() {
+
+    let game: CyclicGame = CyclicGame::rock_paper_scissors();
+    let mut counter: FrequencyCounter = FrequencyCounter::new();
+    counter.observe(&game, PAPER, SCISSORS, GameResult::Lose);
+    counter.observe(&game, PAPER, ROCK, GameResult::Win);
+    counter.observe(&game, PAPER, ROCK, GameResult::Win);
+    assert_eq!(develop_moves(&mut counter, &game, 3), vec![PAPER; 3]);
+
+    // A tie goes to the first move
+    counter.observe(&game, PAPER, SCISSORS, GameResult::Lose);
+    assert_eq!(develop_moves(&mut counter, &game, 1), vec![PAPER]);
+    counter.observe(&game, PAPER, SCISSORS, GameResult::Lose);
+    assert_eq!(develop_moves(&mut counter, &game, 1), vec![ROCK]);
+
+    // Without counts, any move may come
+    counter.reset();
+    let moves: Vec<MoveID> = develop_moves(&mut counter, &game, 50);
+    assert!(moves.iter().all(|chosen_move| *chosen_move < 3) && moves.iter().any(|chosen_move| *chosen_move != ROCK));
+}
+
+#[test]
+fn win_stay_lose_shift() {
+
+    let game: CyclicGame = CyclicGame::rock_paper_scissors();
+    let mut player: WinStayLoseShift = WinStayLoseShift::new();
+
+    player.observe(&game, SCISSORS, PAPER, GameResult::Win);
+    assert_eq!(develop_moves(&mut player, &game, 1), vec![SCISSORS]);
+    player.observe(&game, SCISSORS, ROCK, GameResult::Lose);
+    assert_eq!(develop_moves(&mut player, &game, 1), vec![PAPER]);
+    player.observe(&game, SCISSORS, SCISSORS, GameResult::Draw);
+    assert_eq!(develop_moves(&mut player, &game, 1), vec![ROCK]);
+
+    // Against a fixed move, one loss is enough to win every round after
+    let standings: Vec<Standing> = Tournament::new(game, 100, 2022).run(&mut [Box::new(WinStayLoseShift::new()), Box::new(FixedGuide::new(vec![SCISSORS]))]);
+    assert!(find_standing(&standings, "Win-stay lose-shift").rounds_won >= 98);
+}
+
+#[test]
+fn markov_predictor_learns_transitions() {
+
+    let game: CyclicGame = CyclicGame::rock_paper_scissors();
+    let mut predictor: MarkovPredictor = MarkovPredictor::new();
+    for opponent_move in [ROCK, PAPER, ROCK, PAPER, ROCK] {
+        predictor.observe(&game, ROCK, opponent_move, game.play_match(ROCK, opponent_move));
+    }
+
+    // Rock was always followed by Paper, which Scissors beats
+    assert_eq!(develop_moves(&mut predictor, &game, 2), vec![SCISSORS; 2]);
+
+    // A guide cycling through the three moves loses almost every round once learnt
+    let standings: Vec<Standing> = Tournament::new(game, 100, 2022).run(&mut [Box::new(MarkovPredictor::new()), Box::new(FixedGuide::new(vec![ROCK, PAPER, SCISSORS]))]);
+    assert!(find_standing(&standings, "Markov predictor").rounds_won >= 95);
+
+    // Each match starts from scratch, so the second cycle is learnt again
+    let mut players: Vec<Box<dyn PlayerStrategy>> = vec![
+        Box::new(MarkovPredictor::new()),
+        Box::new(FixedGuide::new(vec![ROCK, PAPER, SCISSORS])),
+        Box::new(FixedGuide::new(vec![SCISSORS, PAPER, ROCK]))
+    ];
+    let standings: Vec<Standing> = Tournament::new(CyclicGame::rock_paper_scissors(), 100, 2022).run(&mut players);
+    assert_eq!(find_standing(&standings, "Markov predictor").matches_won, 2);
+}
+
+proptest! {
+
+    #[test]
+    fn draws_stay_below_the_bound(seed in any::<u64>(), bound in 1usize ..) {
+
+        let mut random: SeededRandom = SeededRandom::new(seed);
+        prop_assert!((0 .. 20).all(|_| random.below(bound) < bound));
+    }
+}
@@ -0,0 +1,95 @@
+use criterion::{BatchSize, Criterion, black_box, criterion_group, criterion_main};
+
+use day_03::{Compartment, CompartmentItem, PriorityScore, Rucksack};
+use day_03::{priority_group_badge, priority_group_badge_reference, priority_rugsack, priority_rugsack_reference};
+
+/// Groups of three rucksacks generated for the benchmarks, far more than the puzzle input holds.
+const NUMBER_GROUPS: usize = 10_000;
+/// Items picked, on top of the shared ones, for each compartment.
+const COMPARTMENT_ITEMS: usize = 24;
+
+/// Small xorshift generator, so that every run times the same rucksacks.
+struct Generator {
+    state: u64
+}
+
+impl Generator {
+
+    fn pick<'a>(&mut self, items: &'a [CompartmentItem]) -> &'a CompartmentItem {
+
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 7;
+        self.state ^= self.state << 17;
+        return &items[(self.state % items.len() as u64) as usize];
+    }
+}
+
+/// Groups shaped like those of the puzzle: the compartments of each rucksack share one item type
+/// and the rucksacks of each group carry one badge in common.
+fn develop_groups() -> Vec<Vec<Rucksack>> {
+
+    let item_types: Vec<CompartmentItem> = ('a' ..= 'z').chain('A' ..= 'Z').collect();
+    let mut generator: Generator = Generator { state: 0x2022_0003 };
+
+    return (0 .. NUMBER_GROUPS)
+        .map(|group| {
+
+            let badge: CompartmentItem = item_types[group % item_types.len()];
+            return (0 .. 3)
+                .map(|position| {
+
+                    // Every other item type is left out of one rucksack of the group
+                    let pool: Vec<CompartmentItem> = item_types.iter()
+                        .filter(|item| **item != badge)
+                        .enumerate()
+                        .filter(|(index, _)| index % 3 != position)
+                        .map(|(_, item)| *item)
+                        .collect();
+                    let (first_pool, second_pool): (&[CompartmentItem], &[CompartmentItem]) = pool.split_at(pool.len() / 2);
+                    let shared: CompartmentItem = *generator.pick(first_pool);
+
+                    let mut first_compartment: Compartment = (0 .. COMPARTMENT_ITEMS).map(|_| *generator.pick(first_pool)).collect();
+                    let mut second_compartment: Compartment = (0 .. COMPARTMENT_ITEMS).map(|_| *generator.pick(second_pool)).collect();
+                    first_compartment.push(badge);
+                    second_compartment.push(shared);
+                    first_compartment.push(shared);
+                    return (first_compartment, second_compartment);
+                })
+                .collect();
+        })
+        .collect();
+}
+
+fn shared_items(criterion: &mut Criterion) {
+
+    let rucksacks: Vec<Rucksack> = develop_groups().into_iter().flatten().collect();
+    let sum = |priority: fn(&Compartment, &Compartment) -> PriorityScore| -> PriorityScore {
+        return rucksacks.iter().map(|(first, second)| priority(black_box(first), black_box(second))).sum();
+    };
+    assert_eq!(sum(priority_rugsack), sum(priority_rugsack_reference));
+
+    let mut group = criterion.benchmark_group("shared items");
+    group.bench_function("bit sets", |bencher| bencher.iter(|| sum(priority_rugsack)));
+    group.bench_function("reference", |bencher| bencher.iter(|| sum(priority_rugsack_reference)));
+    group.finish();
+}
+
+fn group_badges(criterion: &mut Criterion) {
+
+    let groups: Vec<Vec<Rucksack>> = develop_groups();
+    let sum = |priority: fn(Vec<Rucksack>) -> PriorityScore| -> PriorityScore {
+        return groups.iter().map(|group| priority(group.clone())).sum();
+    };
+    assert_eq!(sum(priority_group_badge), sum(priority_group_badge_reference));
+
+    // Both versions take the group by value, so the copies are made outside of the timings
+    let mut group = criterion.benchmark_group("group badges");
+    for (name, priority) in [("bit sets", priority_group_badge as fn(Vec<Rucksack>) -> PriorityScore), ("reference", priority_group_badge_reference)] {
+        group.bench_function(name, |bencher| bencher.iter_batched(|| groups.clone(),
+            |groups| groups.into_iter().map(priority).sum::<PriorityScore>(), BatchSize::LargeInput));
+    }
+    group.finish();
+}
+
+criterion_group!(benches, shared_items, group_badges);
+criterion_main!(benches);
@@ -0,0 +1,228 @@
+//! Search for the best way of splitting every rucksack into groups that each carry a single badge.
+//!
+//! Groups are no longer taken in input order: any partition of the rucksacks into groups of the
+//! same size will do, as long as the rucksacks of every group have exactly one item type in
+//! common, as asked by [`crate::priority_group_badge`]. Among those partitions, the one with the
+//! lowest or the highest total badge priority is looked for with a depth-first branch and bound,
+//! which can be cut short by a timeout and then returns the best partition found so far.
+//! Rucksacks holding anything other than the puzzle's items are rejected before the search starts.
+
+use std::time::{Duration, Instant};
+
+use crate::{ItemSet, PriorityScore, Rucksack};
+use crate::{develop_rucksack_item_set, item_set_priorities, item_set_single_priority};
+use crate::grouping::GroupingError;
+use crate::priority::{AdventScheme, PriorityError, validate_rucksacks};
+
+// =========================================== TYPE AND STRUCT DEFINITIONS ===========================================
+
+/// Whether the total badge priority of the partition is to be as low or as high as possible.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum BadgeObjective {
+    MinimumPriority,
+    MaximumPriority
+}
+
+/// Settings of a search.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct BadgeSearch {
+    group_size: usize,
+    objective: BadgeObjective,
+    timeout: Option<Duration>
+}
+
+/// Groups of rucksacks, by their position in the input (counting from 0), and their badges.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct BadgePartition {
+    pub groups: Vec<Vec<usize>>,
+    pub badges: Vec<PriorityScore>,
+    pub total_priority: PriorityScore
+}
+
+/// Result of a search.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct SearchOutcome {
+    /// Best partition found, or `None` if none was found.
+    pub best: Option<BadgePartition>,
+    /// Whether every partition was considered, so that `best` is optimal, or that there is none
+    /// when it is `None`. It is `false` when the timeout stopped the search.
+    pub complete: bool,
+    /// Number of groups tried during the search.
+    pub explored_groups: usize
+}
+
+/// State of a running search.
+struct SearchState<'a> {
+    settings: &'a BadgeSearch,
+    item_sets: Vec<ItemSet>,
+    /// Lowest and highest priority of each rucksack, bounding the badge of the group it joins.
+    priority_bounds: Vec<(PriorityScore, PriorityScore)>,
+    deadline: Option<Instant>,
+    assigned: Vec<bool>,
+    groups: Vec<Vec<usize>>,
+    badges: Vec<PriorityScore>,
+    best: Option<BadgePartition>,
+    explored_groups: usize,
+    timed_out: bool
+}
+
+// =============================================== AUXILIARY FUNCTIONS ===============================================
+
+/// Whether a total is better than the best one so far under the objective.
+fn improves(objective: BadgeObjective, total: PriorityScore, best: Option<&BadgePartition>) -> bool {
+
+    return match (objective, best) {
+        (_, None) => true,
+        (BadgeObjective::MinimumPriority, Some(best)) => total < best.total_priority,
+        (BadgeObjective::MaximumPriority, Some(best)) => total > best.total_priority
+    };
+}
+
+// ================================================= IMPLEMENTATIONS =================================================
+
+impl BadgeSearch {
+
+    pub fn new(group_size: usize, objective: BadgeObjective) -> BadgeSearch {
+        BadgeSearch {
+            group_size,
+            objective,
+            timeout: None
+        }
+    }
+
+    /// Stops the search after `timeout`, keeping the best partition found by then.
+    pub fn with_timeout(self, timeout: Duration) -> BadgeSearch {
+        BadgeSearch {
+            timeout: Some(timeout),
+            ..self
+        }
+    }
+
+    /// Looks for the best partition of the rucksacks, which must split evenly into groups and
+    /// hold only the puzzle's items.
+    pub fn run(&self, rucksacks: &[Rucksack]) -> Result<SearchOutcome, PriorityError> {
+
+        if self.group_size == 0 { return Err(PriorityError::Grouping(GroupingError::EmptyGroupSize)) }
+        if ! rucksacks.len().is_multiple_of(self.group_size) {
+            let first_rucksack: usize = rucksacks.len() - rucksacks.len() % self.group_size;
+            return Err(PriorityError::Grouping(GroupingError::IncompleteGroup { first_rucksack, expected: self.group_size, found: rucksacks.len() - first_rucksack }));
+        }
+        validate_rucksacks(&AdventScheme, rucksacks)?;
+
+        let item_sets: Vec<ItemSet> = rucksacks.iter().map(develop_rucksack_item_set).collect();
+        let priority_bounds: Vec<(PriorityScore, PriorityScore)> = item_sets.iter()
+            .map(|item_set| {
+                let priorities: Vec<PriorityScore> = item_set_priorities(*item_set);
+                (*priorities.first().unwrap_or(&0), *priorities.last().unwrap_or(&0))
+            })
+            .collect();
+
+        let mut state: SearchState = SearchState {
+            settings: self,
+            priority_bounds,
+            deadline: self.timeout.map(|timeout| Instant::now() + timeout),
+            assigned: vec![false; item_sets.len()],
+            item_sets,
+            groups: Vec::new(),
+            badges: Vec::new(),
+            best: None,
+            explored_groups: 0,
+            timed_out: false
+        };
+        state.search();
+
+        return Ok(SearchOutcome {
+            best: state.best,
+            complete: ! state.timed_out,
+            explored_groups: state.explored_groups
+        });
+    }
+}
+
+impl SearchState<'_> {
+
+    /// Whether the partitions completing the current groups could still beat the best one.
+    ///
+    /// The badge of a group lies between the lowest and the highest priority of each of its
+    /// rucksacks, so the badges still to come add up to at least the lowest priorities of the
+    /// rucksacks left, and at most their highest priorities, divided by the group size.
+    fn is_promising(&self) -> bool {
+
+        let Some(best) = self.best.as_ref() else { return true };
+        let group_size: PriorityScore = self.settings.group_size;
+        let total: PriorityScore = self.badges.iter().sum();
+
+        let unassigned_bounds = self.priority_bounds.iter()
+            .zip(self.assigned.iter())
+            .filter(|(_, assigned)| ! **assigned)
+            .map(|(bounds, _)| *bounds);
+
+        return match self.settings.objective {
+            BadgeObjective::MinimumPriority =>
+                group_size * total + unassigned_bounds.map(|(lowest, _)| lowest).sum::<PriorityScore>() < group_size * best.total_priority,
+            BadgeObjective::MaximumPriority =>
+                group_size * total + unassigned_bounds.map(|(_, highest)| highest).sum::<PriorityScore>() > group_size * best.total_priority
+        };
+    }
+
+    /// Whether the timeout has passed, which stops the search for good.
+    fn is_past_deadline(&mut self) -> bool {
+
+        if ! self.timed_out && self.deadline.is_some_and(|deadline| Instant::now() >= deadline) {
+            self.timed_out = true;
+        }
+        return self.timed_out;
+    }
+
+    /// Starts a group with the first rucksack left, or records the partition if there is none.
+    fn search(&mut self) {
+
+        if self.timed_out || ! self.is_promising() { return }
+
+        let Some(first_rucksack) = self.assigned.iter().position(|assigned| ! assigned) else {
+
+            let total_priority: PriorityScore = self.badges.iter().sum();
+            if improves(self.settings.objective, total_priority, self.best.as_ref()) {
+                self.best = Some(BadgePartition { groups: self.groups.clone(), badges: self.badges.clone(), total_priority });
+            }
+            return;
+        };
+
+        self.assigned[first_rucksack] = true;
+        self.extend_group(vec![first_rucksack], self.item_sets[first_rucksack]);
+        self.assigned[first_rucksack] = false;
+    }
+
+    /// Completes the group with rucksacks after the last one in it, keeping a common item type.
+    fn extend_group(&mut self, group: Vec<usize>, common_items: ItemSet) {
+
+        if group.len() == self.settings.group_size {
+
+            self.explored_groups += 1;
+            if self.is_past_deadline() { return }
+
+            let Some(badge) = item_set_single_priority(common_items) else { return };
+            self.groups.push(group);
+            self.badges.push(badge);
+            self.search();
+            self.badges.pop();
+            self.groups.pop();
+            return;
+        }
+
+        let last_rucksack: usize = *group.last().unwrap();
+        for rucksack in last_rucksack + 1 .. self.item_sets.len() {
+
+            // Rucksacks sharing no item type with the group are skipped without ever completing
+            // a group, so the deadline is checked on every one of them
+            if self.is_past_deadline() { return }
+            if self.assigned[rucksack] || common_items & self.item_sets[rucksack] == 0 { continue }
+
+            let mut extended_group: Vec<usize> = group.clone();
+            extended_group.push(rucksack);
+            self.assigned[rucksack] = true;
+            self.extend_group(extended_group, common_items & self.item_sets[rucksack]);
+            self.assigned[rucksack] = false;
+        }
+    }
+}
@@ -0,0 +1,240 @@
+//! Every item type shared by the compartments of each rucksack and by the rucksacks of each group.
+//!
+//! The puzzle expects exactly one shared item type each time, and [`crate::priority_rugsack`] and
+//! [`crate::priority_group_badge`] panic otherwise. The diagnostics never panic: they list all
+//! the shared item types, and classify the rucksacks and groups sharing none or several of them,
+//! or holding items that are not letters, as anomalies, reported by their line in the input.
+
+use std::collections::BTreeSet;
+use std::fmt;
+
+use crate::{CompartmentItem, ItemSet, PriorityScore, Rucksack};
+use crate::{item_set_priorities, priority_to_item};
+use crate::grouping::{GroupingError, GroupingRule, group_rucksack_positions};
+use crate::priority::{AdventScheme, PriorityScheme};
+
+// =========================================== TYPE AND STRUCT DEFINITIONS ===========================================
+
+/// An item type and its priority.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct SharedItem {
+    pub item: CompartmentItem,
+    pub priority: PriorityScore
+}
+
+/// How many item types are shared, against the single one the puzzle expects.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SharingKind {
+    Single,
+    /// Anomaly: no item type is shared.
+    Missing,
+    /// Anomaly: more than one item type is shared, so the puzzle's answer is ambiguous.
+    Ambiguous,
+    /// Anomaly: some items are not item types of the puzzle, and only the others were compared.
+    UnknownItems
+}
+
+/// Item types found in both compartments of the rucksack on `line` (counting from 1).
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct RucksackDiagnostic {
+    pub line: usize,
+    pub shared_items: Vec<SharedItem>,
+    /// Items of the rucksack that are not item types of the puzzle, sorted.
+    pub unknown_items: Vec<CompartmentItem>,
+    pub kind: SharingKind
+}
+
+/// Item types carried by every rucksack of a group, given by the lines of its rucksacks.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct GroupDiagnostic {
+    pub lines: Vec<usize>,
+    pub shared_items: Vec<SharedItem>,
+    /// Items of the rucksacks of the group that are not item types of the puzzle, sorted.
+    pub unknown_items: Vec<CompartmentItem>,
+    pub kind: SharingKind
+}
+
+/// Diagnostics of every rucksack and every group of the input.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct DiagnosticReport {
+    pub rucksacks: Vec<RucksackDiagnostic>,
+    pub groups: Vec<GroupDiagnostic>
+}
+
+// =============================================== AUXILIARY FUNCTIONS ===============================================
+
+fn develop_shared_items(item_set: ItemSet) -> Vec<SharedItem> {
+
+    return item_set_priorities(item_set).into_iter()
+        .filter_map(|priority| priority_to_item(priority).map(|item| SharedItem { item, priority }))
+        .collect();
+}
+
+/// Set of the item types of the puzzle among `items`, adding the other items to `unknown_items`.
+fn develop_known_item_set(items: &[CompartmentItem], unknown_items: &mut BTreeSet<CompartmentItem>) -> ItemSet {
+
+    return items.iter()
+        .fold(0, |item_set, item| match AdventScheme.get_priority(*item) {
+            Some(priority) => item_set | (1 << priority),
+            None => {
+                unknown_items.insert(*item);
+                item_set
+            }
+        });
+}
+
+fn classify_sharing(shared_items: &[SharedItem], unknown_items: &BTreeSet<CompartmentItem>) -> SharingKind {
+
+    if ! unknown_items.is_empty() { return SharingKind::UnknownItems }
+    return match shared_items.len() {
+        0 => SharingKind::Missing,
+        1 => SharingKind::Single,
+        _ => SharingKind::Ambiguous
+    };
+}
+
+/// Item types shared by the two compartments of each rucksack.
+pub fn diagnose_rucksacks(rucksacks: &[Rucksack]) -> Vec<RucksackDiagnostic> {
+
+    return rucksacks.iter()
+        .enumerate()
+        .map(|(position, (first_compartment, second_compartment))| {
+
+            let mut unknown_items: BTreeSet<CompartmentItem> = BTreeSet::new();
+            let shared_items: Vec<SharedItem> = develop_shared_items(
+                develop_known_item_set(first_compartment, &mut unknown_items) & develop_known_item_set(second_compartment, &mut unknown_items));
+
+            return RucksackDiagnostic {
+                line: position + 1,
+                kind: classify_sharing(&shared_items, &unknown_items),
+                shared_items,
+                unknown_items: unknown_items.into_iter().collect()
+            };
+        })
+        .collect();
+}
+
+/// Item types shared by the rucksacks of each group, with the groups formed following `rule`.
+pub fn diagnose_groups(rucksacks: &[Rucksack], rule: &GroupingRule) -> Result<Vec<GroupDiagnostic>, GroupingError> {
+
+    return Ok(group_rucksack_positions(rucksacks.len(), rule)?.into_iter()
+        .map(|positions| {
+
+            let mut unknown_items: BTreeSet<CompartmentItem> = BTreeSet::new();
+            let group_items: ItemSet = positions.iter()
+                .map(|position| develop_known_item_set(&rucksacks[*position].0, &mut unknown_items)
+                    | develop_known_item_set(&rucksacks[*position].1, &mut unknown_items))
+                .fold(ItemSet::MAX, |intersection, rucksack_items| intersection & rucksack_items);
+
+            let shared_items: Vec<SharedItem> = develop_shared_items(group_items);
+            return GroupDiagnostic {
+                lines: positions.into_iter().map(|position| position + 1).collect(),
+                kind: classify_sharing(&shared_items, &unknown_items),
+                shared_items,
+                unknown_items: unknown_items.into_iter().collect()
+            };
+        })
+        .collect());
+}
+
+fn write_unknown_items(formatter: &mut fmt::Formatter, unknown_items: &[CompartmentItem]) -> fmt::Result {
+
+    let items: Vec<String> = unknown_items.iter().map(|item| format!("{:?}", item)).collect();
+    return write!(formatter, "items that are not item types {}", items.join(", "));
+}
+
+fn write_shared_items(formatter: &mut fmt::Formatter, shared_items: &[SharedItem]) -> fmt::Result {
+
+    let items: Vec<String> = shared_items.iter()
+        .map(|shared_item| format!("{} ({})", shared_item.item, shared_item.priority))
+        .collect();
+    return write!(formatter, "{}", items.join(", "));
+}
+
+// ================================================= IMPLEMENTATIONS =================================================
+
+impl RucksackDiagnostic {
+
+    pub fn is_anomaly(&self) -> bool {
+        return self.kind != SharingKind::Single;
+    }
+}
+
+impl GroupDiagnostic {
+
+    pub fn is_anomaly(&self) -> bool {
+        return self.kind != SharingKind::Single;
+    }
+}
+
+impl DiagnosticReport {
+
+    /// Diagnoses the rucksacks one by one, and their groups formed following `rule`.
+    pub fn new(rucksacks: &[Rucksack], rule: &GroupingRule) -> Result<DiagnosticReport, GroupingError> {
+
+        return Ok(DiagnosticReport {
+            rucksacks: diagnose_rucksacks(rucksacks),
+            groups: diagnose_groups(rucksacks, rule)?
+        });
+    }
+
+    pub fn rucksack_anomalies(&self) -> impl Iterator<Item = &RucksackDiagnostic> {
+        return self.rucksacks.iter().filter(|diagnostic| diagnostic.is_anomaly());
+    }
+
+    pub fn group_anomalies(&self) -> impl Iterator<Item = &GroupDiagnostic> {
+        return self.groups.iter().filter(|diagnostic| diagnostic.is_anomaly());
+    }
+}
+
+impl fmt::Display for RucksackDiagnostic {
+
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+
+        write!(formatter, "line {}: ", self.line)?;
+        return match self.kind {
+            SharingKind::UnknownItems => write_unknown_items(formatter, &self.unknown_items),
+            SharingKind::Missing => write!(formatter, "no item type in both compartments"),
+            SharingKind::Single | SharingKind::Ambiguous => {
+                write!(formatter, "in both compartments ")?;
+                write_shared_items(formatter, &self.shared_items)
+            }
+        };
+    }
+}
+
+impl fmt::Display for GroupDiagnostic {
+
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+
+        let lines: Vec<String> = self.lines.iter().map(|line| line.to_string()).collect();
+        write!(formatter, "lines {}: ", lines.join(", "))?;
+        return match self.kind {
+            SharingKind::UnknownItems => write_unknown_items(formatter, &self.unknown_items),
+            SharingKind::Missing => write!(formatter, "no item type in every rucksack"),
+            SharingKind::Single | SharingKind::Ambiguous => {
+                write!(formatter, "in every rucksack ")?;
+                write_shared_items(formatter, &self.shared_items)
+            }
+        };
+    }
+}
+
+impl fmt::Display for DiagnosticReport {
+
+    /// Lists the anomalies only, rucksacks first and groups second.
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+
+        writeln!(formatter, "Rucksacks: {} of {} anomalous", self.rucksack_anomalies().count(), self.rucksacks.len())?;
+        for diagnostic in self.rucksack_anomalies() {
+            writeln!(formatter, "  {:?} {}", diagnostic.kind, diagnostic)?;
+        }
+
+        writeln!(formatter, "Groups: {} of {} anomalous", self.group_anomalies().count(), self.groups.len())?;
+        for diagnostic in self.group_anomalies() {
+            writeln!(formatter, "  {:?} {}", diagnostic.kind, diagnostic)?;
+        }
+
+        return Ok(());
+    }
+}
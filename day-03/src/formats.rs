@@ -0,0 +1,77 @@
+//! Structured representations of the rucksacks.
+//!
+//! Each rucksack is a record with the items of its `first_compartment` and `second_compartment`
+//! written as strings, e.g. `[{"first_compartment": "vJrwpWtwJgWr", "second_compartment": "hcsFMMfFFhFp"}]`
+//! in JSON or `vJrwpWtwJgWr,hcsFMMfFFhFp` rows in CSV.
+
+use serde::{Deserialize, Serialize};
+
+use crate::Rucksack;
+
+// =========================================== TYPE AND STRUCT DEFINITIONS ===========================================
+
+#[derive(Serialize, Deserialize)]
+struct RucksackRecord {
+    first_compartment: String,
+    second_compartment: String
+}
+
+// ================================================= IMPLEMENTATIONS =================================================
+
+impl From<RucksackRecord> for Rucksack {
+
+    fn from(record: RucksackRecord) -> Rucksack {
+        (record.first_compartment.chars().collect(), record.second_compartment.chars().collect())
+    }
+}
+
+impl From<&Rucksack> for RucksackRecord {
+
+    fn from(rucksack: &Rucksack) -> RucksackRecord {
+        RucksackRecord {
+            first_compartment: rucksack.0.iter().collect(),
+            second_compartment: rucksack.1.iter().collect()
+        }
+    }
+}
+
+// =============================================== AUXILIARY FUNCTIONS ===============================================
+
+/// Reads the rucksacks from a JSON list of records.
+pub fn rucksacks_from_json(input: &str) -> serde_json::Result<Vec<Rucksack>> {
+
+    let records: Vec<RucksackRecord> = serde_json::from_str(input)?;
+    return Ok(records.into_iter()
+        .map(Rucksack::from)
+        .collect());
+}
+
+/// Writes the rucksacks as a JSON list of records.
+pub fn rucksacks_to_json(rucksacks: &[Rucksack]) -> serde_json::Result<String> {
+
+    let records: Vec<RucksackRecord> = rucksacks.iter()
+        .map(RucksackRecord::from)
+        .collect();
+    return serde_json::to_string(&records);
+}
+
+/// Reads the rucksacks from CSV rows of `first_compartment,second_compartment`.
+pub fn rucksacks_from_csv(input: &str) -> csv::Result<Vec<Rucksack>> {
+
+    let mut reader = csv::Reader::from_reader(input.as_bytes());
+    return reader.deserialize()
+        .map(|record| record.map(|record: RucksackRecord| Rucksack::from(record)))
+        .collect();
+}
+
+/// Writes the rucksacks as CSV rows of `first_compartment,second_compartment`.
+pub fn rucksacks_to_csv(rucksacks: &[Rucksack]) -> csv::Result<String> {
+
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    for rucksack in rucksacks.iter() {
+        writer.serialize(RucksackRecord::from(rucksack))?;
+    }
+
+    let output: Vec<u8> = writer.into_inner().map_err(|error| error.into_error())?;
+    return Ok(String::from_utf8(output).unwrap());
+}
@@ -0,0 +1,144 @@
+//! Ways of splitting the rucksacks into the groups whose badges are looked for.
+//!
+//! The puzzle groups every three consecutive rucksacks, but groups can be of any size, overlap as
+//! sliding windows, or be given by an extra column of the input naming the group of each rucksack:
+//!
+//! ```text
+//! vJrwpWtwJgWrhcsFMMfFFhFp north
+//! jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL south
+//! PmmdzqPrVvPwwTWBwg north
+//! ```
+
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::Rucksack;
+
+// =========================================== TYPE AND STRUCT DEFINITIONS ===========================================
+
+/// Number of rucksacks in a group of the puzzle.
+pub const PUZZLE_GROUP_SIZE: usize = 3;
+
+/// How the rucksacks are split into groups.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum GroupingRule {
+    /// Consecutive rucksacks, `size` at a time, every rucksack in exactly one group.
+    Consecutive(usize),
+    /// Every run of `size` consecutive rucksacks, so that groups overlap.
+    SlidingWindow(usize),
+    /// Rucksacks with the same label, the label of each rucksack given in order, with groups
+    /// listed in the order their label first shows up.
+    Labels(Vec<String>)
+}
+
+/// Reason why the rucksacks cannot be split into groups.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum GroupingError {
+    /// Groups must hold at least one rucksack.
+    EmptyGroupSize,
+    /// The group starting at rucksack `first_rucksack` (counting from 0) only has `found` of
+    /// the `expected` rucksacks.
+    IncompleteGroup { first_rucksack: usize, expected: usize, found: usize },
+    /// There is not one label per rucksack.
+    LabelCountMismatch { rucksacks: usize, labels: usize },
+    /// A line of labelled input is not made of the items followed by a label.
+    MalformedLine { line: usize, content: String }
+}
+
+// =============================================== AUXILIARY FUNCTIONS ===============================================
+
+/// Splits each line of labelled input into the items of the rucksack and its group label, so
+/// that the items can go through [`crate::convert_input_to_correct_format`].
+pub fn split_group_labels(input_lines: Vec<String>) -> Result<(Vec<String>, Vec<String>), GroupingError> {
+
+    let mut item_lines: Vec<String> = Vec::new();
+    let mut labels: Vec<String> = Vec::new();
+
+    for (line, content) in input_lines.into_iter().enumerate() {
+
+        let mut fields = content.split_whitespace();
+        match (fields.next(), fields.next(), fields.next()) {
+            (Some(items), Some(label), None) => {
+                item_lines.push(items.to_owned());
+                labels.push(label.to_owned());
+            },
+            _ => return Err(GroupingError::MalformedLine { line: line + 1, content })
+        }
+    }
+
+    return Ok((item_lines, labels));
+}
+
+/// Positions (counting from 0) of the rucksacks in each group, when `number_rucksacks` rucksacks
+/// are split following `rule`.
+pub fn group_rucksack_positions(number_rucksacks: usize, rule: &GroupingRule) -> Result<Vec<Vec<usize>>, GroupingError> {
+
+    return match rule {
+
+        GroupingRule::Consecutive(0) | GroupingRule::SlidingWindow(0) => Err(GroupingError::EmptyGroupSize),
+
+        GroupingRule::Consecutive(size) => {
+
+            if ! number_rucksacks.is_multiple_of(*size) {
+                let first_rucksack: usize = number_rucksacks - number_rucksacks % size;
+                return Err(GroupingError::IncompleteGroup { first_rucksack, expected: *size, found: number_rucksacks - first_rucksack });
+            }
+            Ok((0 .. number_rucksacks).step_by(*size).map(|first| (first .. first + size).collect()).collect())
+        },
+
+        GroupingRule::SlidingWindow(size) => {
+
+            if number_rucksacks < *size {
+                return Err(GroupingError::IncompleteGroup { first_rucksack: 0, expected: *size, found: number_rucksacks });
+            }
+            Ok((0 ..= number_rucksacks - size).map(|first| (first .. first + size).collect()).collect())
+        },
+
+        GroupingRule::Labels(labels) => {
+
+            if labels.len() != number_rucksacks {
+                return Err(GroupingError::LabelCountMismatch { rucksacks: number_rucksacks, labels: labels.len() });
+            }
+
+            let mut group_positions: HashMap<&String, usize> = HashMap::new();
+            let mut groups: Vec<Vec<usize>> = Vec::new();
+            for (position, label) in labels.iter().enumerate() {
+
+                let group: usize = *group_positions.entry(label).or_insert_with(|| {
+                    groups.push(Vec::new());
+                    groups.len() - 1
+                });
+                groups[group].push(position);
+            }
+            Ok(groups)
+        }
+    };
+}
+
+/// Splits the rucksacks into groups following `rule`.
+pub fn group_rucksacks(rucksacks: &[Rucksack], rule: &GroupingRule) -> Result<Vec<Vec<Rucksack>>, GroupingError> {
+
+    return Ok(group_rucksack_positions(rucksacks.len(), rule)?.into_iter()
+        .map(|positions| positions.into_iter().map(|position| rucksacks[position].clone()).collect())
+        .collect());
+}
+
+// ================================================= IMPLEMENTATIONS =================================================
+
+impl fmt::Display for GroupingError {
+
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+
+        return match self {
+            GroupingError::EmptyGroupSize => write!(formatter, "groups must hold at least one rucksack"),
+            GroupingError::IncompleteGroup { first_rucksack, expected, found } =>
+                write!(formatter, "the group starting at rucksack {} has {} rucksacks instead of {}", first_rucksack, found, expected),
+            GroupingError::LabelCountMismatch { rucksacks, labels } =>
+                write!(formatter, "{} group labels were given for {} rucksacks", labels, rucksacks),
+            GroupingError::MalformedLine { line, content } =>
+                write!(formatter, "line {} is not made of items followed by a group label: '{}'", line, content)
+        };
+    }
+}
+
+impl std::error::Error for GroupingError {}
@@ -1,77 +1,181 @@
+//! Rucksack reorganization (Advent of Code 2022, day 03).
+//!
+//! Each rucksack holds two compartments of items identified by a letter. Every item type has a
+//! priority: `a` to `z` are worth 1 to 26 and `A` to `Z` are worth 27 to 52. Other alphabets and
+//! priorities, for any Unicode items, are handled by the schemes of [`priority`].
+//! Items are handled as bit sets, see [`ItemSet`], so that compartments and rucksacks are compared
+//! with bitwise operations; `cargo bench` times them against the `_reference` versions on large
+//! generated inputs.
+//! Groups of rucksacks are formed by any of the rules of [`grouping`], by three as in the puzzle.
+//! [`badge_search`] looks for the partition into groups with single badges and the lowest, or
+//! highest, total badge priority.
+//! Rucksacks and groups that do not share exactly one item type are reported by [`diagnostics`].
+//! [`repair`] plans the fewest moves and swaps that put the misplaced items back in place.
+//! The rucksacks can also be read from, and written to, JSON or CSV through [`formats`].
+
 use std::collections::HashSet;
 
+use crate::priority::{AdventScheme, PriorityScheme};
+
+pub mod formats;
+pub mod badge_search;
+pub mod diagnostics;
+pub mod grouping;
+pub mod priority;
+pub mod repair;
+
 // =========================================== TYPE AND STRUCT DEFINITIONS ===========================================
 
+/// Priority of an item type, or a sum of priorities.
 pub type PriorityScore = usize;
+/// A single item, identified by its letter.
 pub type CompartmentItem = char;
+/// The items stored in one of the two compartments of a rucksack.
 pub type Compartment = Vec<CompartmentItem>;
+/// A rucksack, as its first and second compartments.
+pub type Rucksack = (Compartment, Compartment);
+/// Set of item types, with the bit at the priority of each item type present set.
+pub type ItemSet = u64;
 
 #[derive(PartialEq, Eq, Clone, Copy)]
-enum CompartmentItemType {
+pub(crate) enum CompartmentItemType {
     Lowercase,
     Uppercase
 }
 
 // =============================================== AUXILIARY FUNCTIONS ===============================================
 
-fn item_to_item_type(item: &CompartmentItem) -> CompartmentItemType {
-    
-    return match item.is_lowercase() {
-        true => CompartmentItemType::Lowercase,
-        false => CompartmentItemType::Uppercase
-    }
+/// Splits each line of the input in half, into the two compartments of a rucksack.
+pub fn convert_input_to_correct_format(input_lines: Vec<String>) -> Vec<Rucksack> {
+
+    return input_lines.into_iter()
+        .map(|line| line.chars().collect::<Vec<char>>())
+        .map(|items| {
+
+            let split_point: usize = items.len() / 2;
+            let (first_compartment, second_compartment): (&[char], &[char]) = items.split_at(split_point);
+            return (first_compartment.to_owned(), second_compartment.to_owned());
+
+        }).collect();
+}
+
+pub(crate) fn item_to_item_type(item: &CompartmentItem) -> Option<CompartmentItemType> {
+
+    if item.is_ascii_lowercase() { return Some(CompartmentItemType::Lowercase) }
+    if item.is_ascii_uppercase() { return Some(CompartmentItemType::Uppercase) }
+    return None;
 }
 
 fn item_priority_value(item: &CompartmentItem) -> PriorityScore {
 
-    let item_reduced: CompartmentItem = *item.to_lowercase()
-        .collect::<Vec<CompartmentItem>>()
-        .get(0)
-        .unwrap();
+    return AdventScheme.get_priority(*item)
+        .unwrap_or_else(|| panic!("🚨 '{}' is not an item of the puzzle, only the letters a to z and A to Z are", item));
+}
 
-    let base_value: PriorityScore = 'a' as PriorityScore;
-    let reduced_value: PriorityScore = item_reduced as PriorityScore;
+/// Set of the item types among `items`.
+pub fn develop_item_set(items: &[CompartmentItem]) -> ItemSet {
 
-    let priority_reduced = reduced_value - base_value + 1;
+    return items.iter()
+        .fold(0, |item_set, item| item_set | (1 << item_priority_value(item)));
+}
 
-    return match item_to_item_type(item) {
-        CompartmentItemType::Lowercase => priority_reduced,
-        CompartmentItemType::Uppercase => priority_reduced + 26
-    }
+/// Set of the item types in either compartment of a rucksack.
+pub fn develop_rucksack_item_set(rucksack: &Rucksack) -> ItemSet {
+    return develop_item_set(&rucksack.0) | develop_item_set(&rucksack.1);
+}
+
+/// Priority of the only item type of `item_set`, or `None` if it holds no item types or several.
+pub fn item_set_single_priority(item_set: ItemSet) -> Option<PriorityScore> {
+
+    if item_set.count_ones() != 1 { return None }
+    return Some(item_set.trailing_zeros() as PriorityScore);
+}
+
+/// Item type of the given priority, if it is one of the 52 item types.
+pub fn priority_to_item(priority: PriorityScore) -> Option<CompartmentItem> {
 
+    return match priority {
+        1 ..= 26 => Some((b'a' + (priority - 1) as u8) as CompartmentItem),
+        27 ..= 52 => Some((b'A' + (priority - 27) as u8) as CompartmentItem),
+        _ => None
+    };
 }
 
+/// Priorities of the item types of `item_set`, from the lowest to the highest.
+pub fn item_set_priorities(item_set: ItemSet) -> Vec<PriorityScore> {
+
+    return (0 .. ItemSet::BITS as PriorityScore)
+        .filter(|priority| item_set & (1 << priority) != 0)
+        .collect();
+}
+
+/// Priority of the item type that shows up in both compartments of a rucksack.
+///
+/// When several item types do, the first of them in the second compartment is the one scored,
+/// as in [`priority_rugsack_reference`].
 pub fn priority_rugsack(first_compartment: &Compartment, second_compartment: &Compartment) -> PriorityScore {
 
+    let first_items: ItemSet = develop_item_set(first_compartment);
+
+    return second_compartment.iter()
+        .map(item_priority_value)
+        .find(|priority| first_items & (1 << priority) != 0)
+        .unwrap_or_else(|| panic!("🚨 I assume it should not be possible, not sure ..."));
+}
+
+/// Priority of the badge: the only item type carried by every rucksack of the group.
+pub fn priority_group_badge(group_rugsacks: Vec<(Compartment, Compartment)>) -> PriorityScore {
+
+    return find_group_badge(&group_rugsacks)
+        .unwrap_or_else(|| panic!("🚨 I assume it should not be possible, not sure ..."));
+}
+
+/// Priority of the only item type carried by every rucksack of the group, or `None` if they
+/// carry no item type in common or several.
+pub fn find_group_badge(group_rugsacks: &[Rucksack]) -> Option<PriorityScore> {
+
+    let rugsack_intersection: ItemSet = group_rugsacks.iter()
+        .map(develop_rucksack_item_set)
+        .fold(ItemSet::MAX, |intersection, rucksack_items| intersection & rucksack_items);
+
+    return item_set_single_priority(rugsack_intersection);
+}
+
+/// Straightforward version of [`priority_rugsack`], going through sets of items.
+/// Kept as a reference to check the faster version against.
+pub fn priority_rugsack_reference(first_compartment: &Compartment, second_compartment: &Compartment) -> PriorityScore {
+
     let match_compartment_set: HashSet<CompartmentItem> = first_compartment.iter()
-        .map(|item| *item)
+        .copied()
         .collect();
 
     for second_compartment_item in second_compartment {
 
-        if match_compartment_set.contains(&second_compartment_item) {
-            return item_priority_value(&second_compartment_item);
+        if match_compartment_set.contains(second_compartment_item) {
+            return item_priority_value(second_compartment_item);
         }
     }
 
     panic!("🚨 I assume it should not be possible, not sure ...")
 }
 
-pub fn priority_group_badge(group_rugsacks: Vec<(Compartment, Compartment)>) -> PriorityScore {
+/// Straightforward version of [`priority_group_badge`], going through sets of items.
+/// Kept as a reference to check the faster version against.
+pub fn priority_group_badge_reference(group_rugsacks: Vec<(Compartment, Compartment)>) -> PriorityScore {
 
     let first_rugsasck: &(Compartment, Compartment) = group_rugsacks.first().unwrap();
     let first_rugsack_first_compartment: HashSet<&CompartmentItem> = first_rugsasck.0.iter().collect();
     let first_rugsack_second_compartment: HashSet<&CompartmentItem> = first_rugsasck.1.iter().collect();
 
-    let mut rugsack_intersection: HashSet<&CompartmentItem> = first_rugsack_first_compartment.union(&first_rugsack_second_compartment).into_iter().map(|item| *item).collect();
+    let mut rugsack_intersection: HashSet<&CompartmentItem> = first_rugsack_first_compartment.union(&first_rugsack_second_compartment).copied().collect();
 
     for group_rugsack in group_rugsacks.iter().skip(1) {
      
         let current_rugsack_first_compartment: HashSet<&CompartmentItem> = group_rugsack.0.iter().collect();
         let current_rugsack_second_compartment: HashSet<&CompartmentItem> = group_rugsack.1.iter().collect();
 
-        let current_rugsack: HashSet<&CompartmentItem> = current_rugsack_first_compartment.union(&current_rugsack_second_compartment).into_iter().map(|item| *item).collect();
-        rugsack_intersection = rugsack_intersection.intersection(&current_rugsack).into_iter().map(|item| *item).collect();
+        let current_rugsack: HashSet<&CompartmentItem> = current_rugsack_first_compartment.union(&current_rugsack_second_compartment).copied().collect();
+        rugsack_intersection = rugsack_intersection.intersection(&current_rugsack).copied().collect();
 
     }
 
@@ -79,7 +183,7 @@ pub fn priority_group_badge(group_rugsacks: Vec<(Compartment, Compartment)>) ->
         panic!("🚨 I assume it should not be possible, not sure ...")
     }
 
-    let item_intersected: &CompartmentItem = rugsack_intersection.into_iter().collect::<Vec<&CompartmentItem>>().get(0).unwrap();
+    let item_intersected: &CompartmentItem = rugsack_intersection.into_iter().next().unwrap();
     return item_priority_value(item_intersected);
 
 }
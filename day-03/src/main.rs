@@ -1,31 +1,113 @@
 mod read;
-mod lib;
+
+use day_03::{PriorityScore, Rucksack};
+use day_03::{convert_input_to_correct_format, find_group_badge, priority_rugsack, priority_group_badge};
+use day_03::badge_search::{BadgeObjective, BadgeSearch, SearchOutcome};
+use day_03::diagnostics::DiagnosticReport;
+use day_03::priority::{Alphabet, PriorityScheme, PriorityTable, sum_badge_priorities, sum_shared_item_priorities};
+use day_03::repair::RepairReport;
+use day_03::grouping::{GroupingRule, PUZZLE_GROUP_SIZE, group_rucksacks, split_group_labels};
 
 fn main() {
 
     let input = read::read_lines("input.txt".to_owned());
-    let input_formatted : Vec<(lib::Compartment, lib::Compartment)> = input.into_iter()
-        .map(|line| line.chars().collect::<Vec<char>>())
-        .map(|items| {
+    let input_formatted : Vec<Rucksack> = convert_input_to_correct_format(input);
 
-            let split_point: usize = items.len() / 2;
-            let (first_compartment, second_compartment): (&[char], &[char]) = items.split_at(split_point);
-            return (first_compartment.to_owned(), second_compartment.to_owned());
+    // The diagnostics are meant for rucksacks the puzzle parts would panic on, so they run instead of them
+    if let Some("--diagnose") = std::env::args().nth(1).as_deref() {
 
-        }).collect();
+        let report: DiagnosticReport = DiagnosticReport::new(&input_formatted, &GroupingRule::Consecutive(PUZZLE_GROUP_SIZE))
+            .unwrap_or_else(|error| panic!("🚨 {}", error));
+        print!("\r🔍 {}", report);
+        return;
+    }
     
+    // Both parts under other priorities instead of the puzzle's, which only knows letters, e.g.
+    // `--priorities priorities.toml` or `--alphabet zyxwvutsrqponmlkjihgfedcba`
+    let scheme: Option<Box<dyn PriorityScheme>> = match (std::env::args().nth(1).as_deref(), std::env::args().nth(2)) {
+        (Some("--priorities"), Some(config_path)) => Some(Box::new(PriorityTable::from_toml(&std::fs::read_to_string(config_path).unwrap())
+            .unwrap_or_else(|error| panic!("🚨 {}", error)))),
+        (Some("--alphabet"), Some(items)) => Some(Box::new(Alphabet::new(&items)
+            .unwrap_or_else(|error| panic!("🚨 {}", error)))),
+        _ => None
+    };
+    if let Some(scheme) = scheme {
+
+        let sum_shared: PriorityScore = sum_shared_item_priorities(&scheme, &input_formatted)
+            .unwrap_or_else(|error| panic!("🚨 {}", error));
+        let sum_badges: PriorityScore = sum_badge_priorities(&scheme, &input_formatted, &GroupingRule::Consecutive(PUZZLE_GROUP_SIZE))
+            .unwrap_or_else(|error| panic!("🚨 {}", error));
+        println!("\r🔤 Under the given priorities: '{}' (Part 1), '{}' (Part 2)", sum_shared, sum_badges);
+        return;
+    }
+
+    // Other grouping rules, run instead of the puzzle parts which would panic on incomplete groups,
+    // e.g. `--grouping sliding 3` or `--grouping labels labelled.txt`
+    if let Some("--grouping") = std::env::args().nth(1).as_deref() {
+
+        let argument: String = std::env::args().nth(3).unwrap_or_else(|| PUZZLE_GROUP_SIZE.to_string());
+        let (rucksacks, rule): (Vec<Rucksack>, GroupingRule) = match std::env::args().nth(2).as_deref() {
+            Some("labels") => {
+                let (item_lines, labels) = split_group_labels(read::read_lines(argument))
+                    .unwrap_or_else(|error| panic!("🚨 {}", error));
+                (convert_input_to_correct_format(item_lines), GroupingRule::Labels(labels))
+            },
+            Some("sliding") => (input_formatted.clone(), GroupingRule::SlidingWindow(argument.parse().unwrap())),
+            _ => (input_formatted.clone(), GroupingRule::Consecutive(argument.parse().unwrap()))
+        };
+
+        let badges: Vec<Option<PriorityScore>> = group_rucksacks(&rucksacks, &rule)
+            .unwrap_or_else(|error| panic!("🚨 {}", error))
+            .iter()
+            .map(|group| find_group_badge(group))
+            .collect();
+        println!("\r🎒 Groups with a single badge: {} of {}, their priorities combined: '{}'",
+            badges.iter().flatten().count(), badges.len(), badges.iter().flatten().sum::<PriorityScore>());
+        return;
+    }
+
+    // Repairs are meant for rucksacks sharing several item types, which the puzzle parts would panic on
+    if let Some("--repair") = std::env::args().nth(1).as_deref() {
+
+        print!("\r🧰 {}", RepairReport::new(&input_formatted).unwrap_or_else(|error| panic!("🚨 {}", error)));
+        return;
+    }
+
+    // Groups in any order, run instead of the puzzle parts which panic on groups without a single badge,
+    // e.g. `--badge-search max 5000` to maximize the total badge priority, searching for 5 seconds at most
+    if let Some("--badge-search") = std::env::args().nth(1).as_deref() {
+
+        let objective: BadgeObjective = match std::env::args().nth(2).as_deref() {
+            Some("max") => BadgeObjective::MaximumPriority,
+            _ => BadgeObjective::MinimumPriority
+        };
+        let timeout: u64 = std::env::args().nth(3).map_or(1000, |timeout| timeout.parse().unwrap());
+
+        let outcome: SearchOutcome = BadgeSearch::new(PUZZLE_GROUP_SIZE, objective)
+            .with_timeout(std::time::Duration::from_millis(timeout))
+            .run(&input_formatted)
+            .unwrap_or_else(|error| panic!("🚨 {}", error));
+
+        match (outcome.best, outcome.complete) {
+            (Some(best), true) => println!("\r🔎 Best badge grouping: '{}' ({} groups tried)", best.total_priority, outcome.explored_groups),
+            (Some(best), false) => println!("\r🔎 Best badge grouping found before the timeout: '{}' ({} groups tried)", best.total_priority, outcome.explored_groups),
+            (None, true) => println!("\r🔎 No grouping gives every group a single badge"),
+            (None, false) => println!("\r🔎 No grouping with single badges found before the timeout")
+        }
+        return;
+    }
+
     // Part 1
-    let sum_priorities: lib::PriorityScore = input_formatted.iter()
-        .map(|(first_compartment, second_compartment)| lib::priority_rugsack(first_compartment, second_compartment))
+    let sum_priorities: PriorityScore = input_formatted.iter()
+        .map(|(first_compartment, second_compartment)| priority_rugsack(first_compartment, second_compartment))
         .sum();
     println!("\r🎁 Priority rugsacks combined: '{}' (Part 1)", sum_priorities);
     
     // Part 2
-    let sum_priorities_group: lib::PriorityScore = input_formatted.chunks(3)
-        .map(|chunk| chunk.to_vec())
-        .collect::<Vec<_>>()
+    let sum_priorities_group: PriorityScore = group_rucksacks(&input_formatted, &GroupingRule::Consecutive(PUZZLE_GROUP_SIZE))
+        .unwrap_or_else(|error| panic!("🚨 {}", error))
         .into_iter()
-        .map(|group_rugsacks| lib::priority_group_badge(group_rugsacks))
+        .map(priority_group_badge)
         .sum();
     println!("\r🎁 Priority group rugsacks combined: '{}' (Part 2)", sum_priorities_group);
-}
\ No newline at end of file
+}
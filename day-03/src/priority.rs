@@ -0,0 +1,240 @@
+//! Priorities of the item types, for the puzzle's letters or for any other set of Unicode items.
+//!
+//! A [`PriorityScheme`] tells which items exist and what each one is worth. Besides the puzzle's
+//! [`AdventScheme`], an [`Alphabet`] ranks its items in order, and a [`PriorityTable`] gives each
+//! item an arbitrary priority, loaded from TOML:
+//!
+//! ```toml
+//! [priorities]
+//! "α" = 1
+//! "β" = 2
+//! "🍎" = 100
+//! ```
+//!
+//! The bit sets of [`crate::ItemSet`] only hold the puzzle's items, so the totals under any scheme
+//! are computed with sets of items instead.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt;
+use serde::Deserialize;
+
+use crate::{CompartmentItem, CompartmentItemType, PriorityScore, Rucksack, item_to_item_type};
+use crate::grouping::{GroupingError, GroupingRule, group_rucksack_positions};
+
+// =========================================== TYPE AND STRUCT DEFINITIONS ===========================================
+
+/// Which items exist, and the priority of each of them.
+pub trait PriorityScheme {
+
+    /// Priority of `item`, or `None` if the scheme has no such item.
+    fn get_priority(&self, item: CompartmentItem) -> Option<PriorityScore>;
+}
+
+/// The puzzle's scheme: `a` to `z` are worth 1 to 26 and `A` to `Z` are worth 27 to 52.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct AdventScheme;
+
+/// Items ranked in order, the first one being worth 1.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Alphabet {
+    priorities: HashMap<CompartmentItem, PriorityScore>
+}
+
+/// Priority of every item, set one by one.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct PriorityTable {
+    priorities: HashMap<CompartmentItem, PriorityScore>
+}
+
+/// Reason why a scheme cannot be built.
+#[derive(Debug)]
+pub enum SchemeConfigError {
+    /// The configuration is not valid TOML, or lacks the `priorities` table.
+    Parse(toml::de::Error),
+    /// A key of the table is not a single item.
+    NotASingleItem { key: String },
+    /// The alphabet lists `item` more than once.
+    DuplicateItem { item: CompartmentItem }
+}
+
+/// Reason why rucksacks cannot be scored under a scheme.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum PriorityError {
+    /// The rucksack on `line` (counting from 1) holds an item the scheme does not have.
+    UnknownItem { line: usize, item: CompartmentItem },
+    /// The rucksacks cannot be split into groups.
+    Grouping(GroupingError)
+}
+
+#[derive(Deserialize)]
+struct PriorityTableRecord {
+    priorities: BTreeMap<String, PriorityScore>
+}
+
+// =============================================== AUXILIARY FUNCTIONS ===============================================
+
+/// Checks that every item of every rucksack belongs to the scheme.
+pub fn validate_rucksacks(scheme: &impl PriorityScheme, rucksacks: &[Rucksack]) -> Result<(), PriorityError> {
+
+    for (position, (first_compartment, second_compartment)) in rucksacks.iter().enumerate() {
+
+        let unknown_item: Option<&CompartmentItem> = first_compartment.iter()
+            .chain(second_compartment.iter())
+            .find(|item| scheme.get_priority(**item).is_none());
+
+        if let Some(item) = unknown_item {
+            return Err(PriorityError::UnknownItem { line: position + 1, item: *item });
+        }
+    }
+
+    return Ok(());
+}
+
+fn sum_priorities(scheme: &impl PriorityScheme, items: &HashSet<CompartmentItem>) -> PriorityScore {
+    return items.iter().filter_map(|item| scheme.get_priority(*item)).sum();
+}
+
+fn rucksack_items(rucksack: &Rucksack) -> HashSet<CompartmentItem> {
+    return rucksack.0.iter().chain(rucksack.1.iter()).copied().collect();
+}
+
+/// Adds up, over every rucksack, the priorities of the item types found in both of its
+/// compartments, which is the answer to the first part for the puzzle's rucksacks.
+pub fn sum_shared_item_priorities(scheme: &impl PriorityScheme, rucksacks: &[Rucksack]) -> Result<PriorityScore, PriorityError> {
+
+    validate_rucksacks(scheme, rucksacks)?;
+
+    return Ok(rucksacks.iter()
+        .map(|(first_compartment, second_compartment)| {
+
+            let first_items: HashSet<CompartmentItem> = first_compartment.iter().copied().collect();
+            let shared_items: HashSet<CompartmentItem> = second_compartment.iter()
+                .filter(|item| first_items.contains(item))
+                .copied()
+                .collect();
+            return sum_priorities(scheme, &shared_items);
+        })
+        .sum());
+}
+
+/// Adds up, over every group formed following `rule`, the priorities of the item types carried
+/// by all of its rucksacks, which is the answer to the second part for the puzzle's rucksacks.
+pub fn sum_badge_priorities(scheme: &impl PriorityScheme, rucksacks: &[Rucksack], rule: &GroupingRule) -> Result<PriorityScore, PriorityError> {
+
+    validate_rucksacks(scheme, rucksacks)?;
+
+    return Ok(group_rucksack_positions(rucksacks.len(), rule).map_err(PriorityError::Grouping)?.into_iter()
+        .map(|positions| {
+
+            let badges: HashSet<CompartmentItem> = positions.iter()
+                .map(|position| rucksack_items(&rucksacks[*position]))
+                .reduce(|common_items, items| common_items.intersection(&items).copied().collect())
+                .unwrap_or_default();
+            return sum_priorities(scheme, &badges);
+        })
+        .sum());
+}
+
+// ================================================= IMPLEMENTATIONS =================================================
+
+impl<S: PriorityScheme + ?Sized> PriorityScheme for Box<S> {
+
+    fn get_priority(&self, item: CompartmentItem) -> Option<PriorityScore> {
+        return (**self).get_priority(item);
+    }
+}
+
+impl PriorityScheme for AdventScheme {
+
+    fn get_priority(&self, item: CompartmentItem) -> Option<PriorityScore> {
+
+        let (base_item, base_priority): (CompartmentItem, PriorityScore) = match item_to_item_type(&item)? {
+            CompartmentItemType::Lowercase => ('a', 1),
+            CompartmentItemType::Uppercase => ('A', 27)
+        };
+
+        return Some((item as PriorityScore) - (base_item as PriorityScore) + base_priority);
+    }
+}
+
+impl Alphabet {
+
+    /// Ranks the items of `items` in the order they are written.
+    pub fn new(items: &str) -> Result<Alphabet, SchemeConfigError> {
+
+        let mut priorities: HashMap<CompartmentItem, PriorityScore> = HashMap::new();
+        for (position, item) in items.chars().enumerate() {
+
+            if priorities.insert(item, position + 1).is_some() {
+                return Err(SchemeConfigError::DuplicateItem { item });
+            }
+        }
+
+        return Ok(Alphabet { priorities });
+    }
+}
+
+impl PriorityScheme for Alphabet {
+
+    fn get_priority(&self, item: CompartmentItem) -> Option<PriorityScore> {
+        return self.priorities.get(&item).copied();
+    }
+}
+
+impl PriorityTable {
+
+    pub fn new(priorities: HashMap<CompartmentItem, PriorityScore>) -> PriorityTable {
+        PriorityTable {
+            priorities
+        }
+    }
+
+    /// Reads the priorities from the `priorities` table of a TOML configuration.
+    pub fn from_toml(config: &str) -> Result<PriorityTable, SchemeConfigError> {
+
+        let record: PriorityTableRecord = toml::from_str(config).map_err(SchemeConfigError::Parse)?;
+        return Ok(PriorityTable::new(record.priorities.into_iter()
+            .map(|(key, priority)| {
+                let mut items = key.chars();
+                match (items.next(), items.next()) {
+                    (Some(item), None) => Ok((item, priority)),
+                    _ => Err(SchemeConfigError::NotASingleItem { key })
+                }
+            })
+            .collect::<Result<_, _>>()?));
+    }
+}
+
+impl PriorityScheme for PriorityTable {
+
+    fn get_priority(&self, item: CompartmentItem) -> Option<PriorityScore> {
+        return self.priorities.get(&item).copied();
+    }
+}
+
+impl fmt::Display for SchemeConfigError {
+
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+
+        return match self {
+            SchemeConfigError::Parse(error) => write!(formatter, "invalid priority configuration: {}", error),
+            SchemeConfigError::NotASingleItem { key } => write!(formatter, "'{}' is not a single item", key),
+            SchemeConfigError::DuplicateItem { item } => write!(formatter, "'{}' shows up more than once in the alphabet", item)
+        };
+    }
+}
+
+impl std::error::Error for SchemeConfigError {}
+
+impl fmt::Display for PriorityError {
+
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+
+        return match self {
+            PriorityError::UnknownItem { line, item } => write!(formatter, "line {}: '{}' has no priority in the scheme", line, item),
+            PriorityError::Grouping(error) => write!(formatter, "{}", error)
+        };
+    }
+}
+
+impl std::error::Error for PriorityError {}
@@ -0,0 +1,258 @@
+//! Plans to put back the misplaced items, so that no item type is left in both compartments.
+//!
+//! Every copy of a shared item type has to end up in the same compartment, either by moving it
+//! to the other compartment or by swapping it with an item going the opposite way. A swap puts
+//! two items in place at once, so a plan needs as many steps as there are items to carry in the
+//! busiest direction. Among the plans with the fewest steps, the one carrying the lowest total of
+//! priorities is kept.
+//!
+//! Plans are computed on the bit sets of [`crate::ItemSet`], so rucksacks holding anything other
+//! than the puzzle's items are rejected with a [`PriorityError`].
+
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::{CompartmentItem, PriorityScore, Rucksack};
+use crate::{develop_item_set, item_set_priorities, priority_to_item};
+use crate::priority::{AdventScheme, PriorityError, validate_rucksacks};
+
+// =========================================== TYPE AND STRUCT DEFINITIONS ===========================================
+
+/// One of the two compartments of a rucksack.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum CompartmentSide {
+    First,
+    Second
+}
+
+/// A single step of a repair, carrying one copy of an item type per item named.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum RepairStep {
+    /// Takes `item` out of the compartment `from`, into the other one.
+    Move { item: CompartmentItem, from: CompartmentSide },
+    /// Exchanges `first_item` of the first compartment with `second_item` of the second one.
+    Swap { first_item: CompartmentItem, second_item: CompartmentItem }
+}
+
+/// Steps leaving the compartments of a rucksack disjoint, and the priorities of the items carried.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct RepairPlan {
+    pub steps: Vec<RepairStep>,
+    pub cost: PriorityScore
+}
+
+/// Repair plans of every rucksack of the input, in order, and their totals.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct RepairReport {
+    pub plans: Vec<RepairPlan>,
+    pub total_steps: usize,
+    pub total_cost: PriorityScore
+}
+
+/// A step of a plan that takes out an item its compartment does not hold, when the plan is
+/// applied to a rucksack it was not computed for.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct RepairApplyError {
+    /// Position of the step in the plan, counting from 0.
+    pub step: usize,
+    pub item: CompartmentItem,
+    pub from: CompartmentSide
+}
+
+/// A shared item type and how many copies of it each compartment holds.
+struct MisplacedItem {
+    item: CompartmentItem,
+    priority: PriorityScore,
+    first_copies: usize,
+    second_copies: usize
+}
+
+/// Cheapest choice of directions for a given number of items carried each way, with bit `i` of
+/// `to_second` set when the copies of the `i`-th shared item type go to the second compartment.
+#[derive(Clone, Copy)]
+struct PartialPlan {
+    cost: PriorityScore,
+    to_second: u64
+}
+
+// =============================================== AUXILIARY FUNCTIONS ===============================================
+
+fn count_copies(items: &[CompartmentItem], item: CompartmentItem) -> usize {
+    return items.iter().filter(|other| **other == item).count();
+}
+
+/// Plan with the fewest steps leaving the compartments of the rucksack disjoint, and among those
+/// the one carrying the lowest total of priorities.
+///
+/// An item that is not one of the puzzle's is reported as being on line 1.
+pub fn plan_rucksack_repair(rucksack: &Rucksack) -> Result<RepairPlan, PriorityError> {
+
+    validate_rucksacks(&AdventScheme, std::slice::from_ref(rucksack))?;
+    return Ok(develop_repair_plan(rucksack));
+}
+
+/// Plan of [`plan_rucksack_repair`], for a rucksack holding only the puzzle's items.
+fn develop_repair_plan(rucksack: &Rucksack) -> RepairPlan {
+
+    let (first_compartment, second_compartment) = rucksack;
+    let misplaced_items: Vec<MisplacedItem> = item_set_priorities(develop_item_set(first_compartment) & develop_item_set(second_compartment))
+        .into_iter()
+        .filter_map(|priority| priority_to_item(priority).map(|item| (item, priority)))
+        .map(|(item, priority)| MisplacedItem {
+            item,
+            priority,
+            first_copies: count_copies(first_compartment, item),
+            second_copies: count_copies(second_compartment, item)
+        })
+        .collect();
+
+    // Cheapest way of carrying each number of items to the second and to the first compartment
+    let mut partial_plans: HashMap<(usize, usize), PartialPlan> = HashMap::from([((0, 0), PartialPlan { cost: 0, to_second: 0 })]);
+    for (position, misplaced) in misplaced_items.iter().enumerate() {
+
+        let mut next_partial_plans: HashMap<(usize, usize), PartialPlan> = HashMap::new();
+        for ((to_second, to_first), partial_plan) in partial_plans.into_iter() {
+
+            let options: [((usize, usize), PartialPlan); 2] = [
+                ((to_second + misplaced.first_copies, to_first), PartialPlan {
+                    cost: partial_plan.cost + misplaced.first_copies * misplaced.priority,
+                    to_second: partial_plan.to_second | (1 << position)
+                }),
+                ((to_second, to_first + misplaced.second_copies), PartialPlan {
+                    cost: partial_plan.cost + misplaced.second_copies * misplaced.priority,
+                    to_second: partial_plan.to_second
+                })
+            ];
+
+            for (carried, option) in options {
+                let best: &mut PartialPlan = next_partial_plans.entry(carried).or_insert(option);
+                if option.cost < best.cost { *best = option }
+            }
+        }
+        partial_plans = next_partial_plans;
+    }
+
+    let ((_, _), chosen_plan): ((usize, usize), PartialPlan) = partial_plans.into_iter()
+        .min_by_key(|((to_second, to_first), partial_plan)| (*to_second.max(to_first), partial_plan.cost, partial_plan.to_second))
+        .unwrap();
+
+    let mut items_to_second: Vec<CompartmentItem> = Vec::new();
+    let mut items_to_first: Vec<CompartmentItem> = Vec::new();
+    for (position, misplaced) in misplaced_items.iter().enumerate() {
+
+        match chosen_plan.to_second & (1 << position) != 0 {
+            true => items_to_second.extend(std::iter::repeat_n(misplaced.item, misplaced.first_copies)),
+            false => items_to_first.extend(std::iter::repeat_n(misplaced.item, misplaced.second_copies))
+        }
+    }
+
+    let number_swaps: usize = items_to_second.len().min(items_to_first.len());
+    let steps: Vec<RepairStep> = items_to_second.iter().zip(items_to_first.iter())
+        .map(|(first_item, second_item)| RepairStep::Swap { first_item: *first_item, second_item: *second_item })
+        .chain(items_to_second.iter().skip(number_swaps).map(|item| RepairStep::Move { item: *item, from: CompartmentSide::First }))
+        .chain(items_to_first.iter().skip(number_swaps).map(|item| RepairStep::Move { item: *item, from: CompartmentSide::Second }))
+        .collect();
+
+    return RepairPlan { steps, cost: chosen_plan.cost };
+}
+
+fn take_item(compartment: &mut Vec<CompartmentItem>, item: CompartmentItem, from: CompartmentSide, step: usize) -> Result<(), RepairApplyError> {
+
+    let position: usize = compartment.iter().position(|other| *other == item)
+        .ok_or(RepairApplyError { step, item, from })?;
+    compartment.remove(position);
+    return Ok(());
+}
+
+// ================================================= IMPLEMENTATIONS =================================================
+
+impl RepairPlan {
+
+    /// The rucksack once every step of the plan is carried out, or the first step taking out an
+    /// item that is not there.
+    pub fn apply(&self, rucksack: &Rucksack) -> Result<Rucksack, RepairApplyError> {
+
+        let (mut first_compartment, mut second_compartment) = rucksack.clone();
+        for (position, step) in self.steps.iter().enumerate() {
+
+            match *step {
+                RepairStep::Move { item, from: CompartmentSide::First } => {
+                    take_item(&mut first_compartment, item, CompartmentSide::First, position)?;
+                    second_compartment.push(item);
+                },
+                RepairStep::Move { item, from: CompartmentSide::Second } => {
+                    take_item(&mut second_compartment, item, CompartmentSide::Second, position)?;
+                    first_compartment.push(item);
+                },
+                RepairStep::Swap { first_item, second_item } => {
+                    take_item(&mut first_compartment, first_item, CompartmentSide::First, position)?;
+                    take_item(&mut second_compartment, second_item, CompartmentSide::Second, position)?;
+                    first_compartment.push(second_item);
+                    second_compartment.push(first_item);
+                }
+            }
+        }
+
+        return Ok((first_compartment, second_compartment));
+    }
+}
+
+impl RepairReport {
+
+    /// Plans the repair of every rucksack, once all of them are checked to hold only the
+    /// puzzle's items.
+    pub fn new(rucksacks: &[Rucksack]) -> Result<RepairReport, PriorityError> {
+
+        validate_rucksacks(&AdventScheme, rucksacks)?;
+        let plans: Vec<RepairPlan> = rucksacks.iter()
+            .map(develop_repair_plan)
+            .collect();
+
+        return Ok(RepairReport {
+            total_steps: plans.iter().map(|plan| plan.steps.len()).sum(),
+            total_cost: plans.iter().map(|plan| plan.cost).sum(),
+            plans
+        });
+    }
+}
+
+impl fmt::Display for RepairStep {
+
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+
+        return match self {
+            RepairStep::Move { item, from: CompartmentSide::First } => write!(formatter, "move {} to the second compartment", item),
+            RepairStep::Move { item, from: CompartmentSide::Second } => write!(formatter, "move {} to the first compartment", item),
+            RepairStep::Swap { first_item, second_item } => write!(formatter, "swap {} with {}", first_item, second_item)
+        };
+    }
+}
+
+impl fmt::Display for RepairApplyError {
+
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+
+        let side: &str = match self.from {
+            CompartmentSide::First => "first",
+            CompartmentSide::Second => "second"
+        };
+        return write!(formatter, "step {} takes out a '{}' the {} compartment does not hold", self.step + 1, self.item, side);
+    }
+}
+
+impl std::error::Error for RepairApplyError {}
+
+impl fmt::Display for RepairReport {
+
+    /// Lists the plans of the rucksacks needing repair, by their line in the input, then the totals.
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+
+        for (position, plan) in self.plans.iter().enumerate().filter(|(_, plan)| ! plan.steps.is_empty()) {
+
+            let steps: Vec<String> = plan.steps.iter().map(|step| step.to_string()).collect();
+            writeln!(formatter, "line {}: {} (cost {})", position + 1, steps.join(", "), plan.cost)?;
+        }
+
+        return writeln!(formatter, "{} steps in total, for a cost of {}", self.total_steps, self.total_cost);
+    }
+}
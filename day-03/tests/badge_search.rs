@@ -0,0 +1,169 @@
+use std::time::Duration;
+
+use proptest::prelude::*;
+
+use day_03::{CompartmentItem, ItemSet, PriorityScore, Rucksack};
+use day_03::{develop_rucksack_item_set, item_set_single_priority};
+use day_03::badge_search::{BadgeObjective, BadgePartition, BadgeSearch, SearchOutcome};
+use day_03::grouping::GroupingError;
+use day_03::priority::PriorityError;
+
+fn develop_rucksack(items: &str) -> Rucksack {
+
+    let items: Vec<CompartmentItem> = items.chars().collect();
+    let (first_compartment, second_compartment) = items.split_at(items.len() / 2);
+    return (first_compartment.to_owned(), second_compartment.to_owned());
+}
+
+fn group_badge(rucksacks: &[Rucksack], group: &[usize]) -> Option<PriorityScore> {
+
+    let common_items: ItemSet = group.iter()
+        .map(|position| develop_rucksack_item_set(&rucksacks[*position]))
+        .fold(ItemSet::MAX, |common_items, item_set| common_items & item_set);
+    return item_set_single_priority(common_items);
+}
+
+/// Total badge priorities of every partition into groups with single badges, going through all
+/// the partitions of the rucksacks left.
+fn brute_force_totals(rucksacks: &[Rucksack], group_size: usize, left: Vec<usize>) -> Vec<PriorityScore> {
+
+    let Some((first_rucksack, others)) = left.split_first() else { return vec![0] };
+
+    let mut totals: Vec<PriorityScore> = Vec::new();
+    for mask in 0 .. 1usize << others.len() {
+
+        if mask.count_ones() as usize != group_size - 1 { continue }
+        let in_group = |index: &usize| mask & (1 << index) != 0;
+        let group: Vec<usize> = std::iter::once(*first_rucksack)
+            .chain((0 .. others.len()).filter(in_group).map(|index| others[index]))
+            .collect();
+
+        let Some(badge) = group_badge(rucksacks, &group) else { continue };
+        let rest: Vec<usize> = (0 .. others.len()).filter(|index| ! in_group(index)).map(|index| others[index]).collect();
+        totals.extend(brute_force_totals(rucksacks, group_size, rest).into_iter().map(|total| total + badge));
+    }
+
+    return totals;
+}
+
+/// Checks that the partition uses every rucksack once, in groups with the badges it claims.
+fn assert_valid_partition(rucksacks: &[Rucksack], group_size: usize, partition: &BadgePartition) {
+
+    let mut positions: Vec<usize> = partition.groups.iter().flatten().copied().collect();
+    positions.sort();
+    assert_eq!(positions, (0 .. rucksacks.len()).collect::<Vec<usize>>());
+    assert!(partition.groups.iter().all(|group| group.len() == group_size));
+
+    let badges: Vec<Option<PriorityScore>> = partition.groups.iter().map(|group| group_badge(rucksacks, group)).collect();
+    assert_eq!(badges, partition.badges.iter().map(|badge| Some(*badge)).collect::<Vec<Option<PriorityScore>>>());
+    assert_eq!(partition.total_priority, partition.badges.iter().sum::<PriorityScore>());
+}
+
+fn small_rucksacks() -> impl Strategy<Value = (usize, Vec<Rucksack>)> {
+
+    return prop_oneof![Just(2usize), Just(3usize)]
+        .prop_flat_map(|group_size| (Just(group_size), prop::collection::vec(
+            prop::collection::vec(prop::sample::select(vec!['a', 'b', 'c', 'd', 'A', 'B']), 1 ..= 6), group_size .. group_size * 3 + 1)))
+        .prop_map(|(group_size, mut items)| {
+            items.truncate(items.len() - items.len() % group_size);
+            (group_size, items.into_iter().map(|items| develop_rucksack(&items.into_iter().collect::<String>())).collect())
+        });
+}
+
+#[test]
+fn puzzle_example_groups() {
+
+    let rucksacks: Vec<Rucksack> = ["vJrwpWtwJgWrhcsFMMfFFhFp", "jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL", "PmmdzqPrVvPwwTWBwg",
+        "wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn", "ttgJtRGJQctTZtZT", "CrZsJsPPZsGzwwsLwLmpwMDw"]
+        .map(develop_rucksack)
+        .to_vec();
+
+    // In input order the badges are `r` (18) and `Z` (52)
+    let lowest: SearchOutcome = BadgeSearch::new(3, BadgeObjective::MinimumPriority).run(&rucksacks).unwrap();
+    let highest: SearchOutcome = BadgeSearch::new(3, BadgeObjective::MaximumPriority).run(&rucksacks).unwrap();
+    assert!(lowest.complete && highest.complete);
+
+    let totals: Vec<PriorityScore> = brute_force_totals(&rucksacks, 3, (0 .. 6).collect());
+    assert!(totals.contains(&(18 + 52)));
+    assert_eq!(lowest.best.unwrap().total_priority, *totals.iter().min().unwrap());
+    assert_eq!(highest.best.unwrap().total_priority, *totals.iter().max().unwrap());
+}
+
+#[test]
+fn no_partition_with_single_badges() {
+
+    // Every pair shares two item types, or none
+    let rucksacks: Vec<Rucksack> = ["abab", "abba", "cdcd", "dcdc"].map(develop_rucksack).to_vec();
+    let outcome: SearchOutcome = BadgeSearch::new(2, BadgeObjective::MinimumPriority).run(&rucksacks).unwrap();
+    assert_eq!(outcome.best, None);
+    assert!(outcome.complete);
+
+    // No rucksack shares anything with the first one
+    let rucksacks: Vec<Rucksack> = ["aa", "bb", "bb", "bb"].map(develop_rucksack).to_vec();
+    let outcome: SearchOutcome = BadgeSearch::new(2, BadgeObjective::MaximumPriority).run(&rucksacks).unwrap();
+    assert_eq!((outcome.best, outcome.complete, outcome.explored_groups), (None, true, 0));
+
+    // Nothing to split is a single, empty, partition
+    let outcome: SearchOutcome = BadgeSearch::new(3, BadgeObjective::MinimumPriority).run(&[]).unwrap();
+    assert_eq!(outcome.best, Some(BadgePartition { groups: Vec::new(), badges: Vec::new(), total_priority: 0 }));
+}
+
+#[test]
+fn rucksacks_must_split_evenly() {
+
+    let rucksacks: Vec<Rucksack> = ["aa", "ab", "ac", "ad", "ae"].map(develop_rucksack).to_vec();
+    assert_eq!(BadgeSearch::new(0, BadgeObjective::MinimumPriority).run(&rucksacks), Err(PriorityError::Grouping(GroupingError::EmptyGroupSize)));
+    assert_eq!(BadgeSearch::new(3, BadgeObjective::MinimumPriority).run(&rucksacks),
+        Err(PriorityError::Grouping(GroupingError::IncompleteGroup { first_rucksack: 3, expected: 3, found: 2 })));
+}
+
+#[test]
+fn items_outside_the_puzzle_are_errors() {
+
+    // Checked before searching, even with no time to search at all
+    let rucksacks: Vec<Rucksack> = ["aa", "ab", "a-"].map(develop_rucksack).to_vec();
+    assert_eq!(BadgeSearch::new(3, BadgeObjective::MinimumPriority).run(&rucksacks), Err(PriorityError::UnknownItem { line: 3, item: '-' }));
+    assert_eq!(BadgeSearch::new(3, BadgeObjective::MaximumPriority).with_timeout(Duration::ZERO).run(&rucksacks),
+        Err(PriorityError::UnknownItem { line: 3, item: '-' }));
+}
+
+#[test]
+fn zero_timeout_stops_the_search() {
+
+    // Sixty rucksacks all sharing `a` have a huge number of partitions
+    let rucksacks: Vec<Rucksack> = (0 .. 60).map(|position| develop_rucksack(&format!("a{}", (b'b' + (position % 20) as u8) as char))).collect();
+
+    for objective in [BadgeObjective::MinimumPriority, BadgeObjective::MaximumPriority] {
+
+        let outcome: SearchOutcome = BadgeSearch::new(3, objective).with_timeout(Duration::ZERO).run(&rucksacks).unwrap();
+        assert!(! outcome.complete);
+        if let Some(best) = outcome.best.as_ref() { assert_valid_partition(&rucksacks, 3, best) }
+    }
+
+    // The deadline is also checked while looking for rucksacks to add to a group, so that the
+    // search stops even when no group is ever completed
+    let rucksacks: Vec<Rucksack> = std::iter::once(develop_rucksack("zz")).chain((0 .. 2).map(|_| develop_rucksack("aa"))).collect();
+    let outcome: SearchOutcome = BadgeSearch::new(3, BadgeObjective::MinimumPriority).with_timeout(Duration::ZERO).run(&rucksacks).unwrap();
+    assert_eq!((outcome.best, outcome.complete, outcome.explored_groups), (None, false, 0));
+
+    // A timeout long enough to finish keeps the search complete
+    let outcome: SearchOutcome = BadgeSearch::new(3, BadgeObjective::MinimumPriority).with_timeout(Duration::from_secs(60)).run(&rucksacks).unwrap();
+    assert_eq!((outcome.best, outcome.complete), (None, true));
+}
+
+proptest! {
+
+    #[test]
+    fn best_partitions_match_brute_force((group_size, rucksacks) in small_rucksacks()) {
+
+        let totals: Vec<PriorityScore> = brute_force_totals(&rucksacks, group_size, (0 .. rucksacks.len()).collect());
+
+        for (objective, expected) in [(BadgeObjective::MinimumPriority, totals.iter().min()), (BadgeObjective::MaximumPriority, totals.iter().max())] {
+
+            let outcome: SearchOutcome = BadgeSearch::new(group_size, objective).run(&rucksacks).unwrap();
+            prop_assert!(outcome.complete);
+            prop_assert_eq!(outcome.best.as_ref().map(|best| best.total_priority), expected.copied());
+            if let Some(best) = outcome.best.as_ref() { assert_valid_partition(&rucksacks, group_size, best) }
+        }
+    }
+}
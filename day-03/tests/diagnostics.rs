@@ -0,0 +1,90 @@
+use day_03::{Rucksack, convert_input_to_correct_format};
+use day_03::diagnostics::{DiagnosticReport, SharedItem, SharingKind, diagnose_groups, diagnose_rucksacks};
+use day_03::grouping::{GroupingError, GroupingRule};
+
+fn rucksacks(lines: &[&str]) -> Vec<Rucksack> {
+    return convert_input_to_correct_format(lines.iter().map(|line| line.to_string()).collect());
+}
+
+#[test]
+fn rucksacks_are_classified_by_their_shared_item_types() {
+
+    let diagnostics = diagnose_rucksacks(&rucksacks(&["vJrwpWtwJgWrhcsFMMfFFhFp", "abcdef", "abZabZ"]));
+
+    assert_eq!(diagnostics[0].line, 1);
+    assert_eq!(diagnostics[0].kind, SharingKind::Single);
+    assert_eq!(diagnostics[0].shared_items, vec![SharedItem { item: 'p', priority: 16 }]);
+
+    assert_eq!(diagnostics[1].line, 2);
+    assert_eq!(diagnostics[1].kind, SharingKind::Missing);
+    assert!(diagnostics[1].shared_items.is_empty());
+
+    assert_eq!(diagnostics[2].line, 3);
+    assert_eq!(diagnostics[2].kind, SharingKind::Ambiguous);
+    assert_eq!(diagnostics[2].shared_items, vec![
+        SharedItem { item: 'a', priority: 1 },
+        SharedItem { item: 'b', priority: 2 },
+        SharedItem { item: 'Z', priority: 52 }
+    ]);
+
+    assert!(! diagnostics[0].is_anomaly() && diagnostics[1].is_anomaly() && diagnostics[2].is_anomaly());
+}
+
+#[test]
+fn items_that_are_not_letters_are_reported_instead_of_panicking() {
+
+    let diagnostics = diagnose_rucksacks(&rucksacks(&["ab 1ab\r", "aé-éa!"]));
+
+    assert_eq!(diagnostics[0].kind, SharingKind::UnknownItems);
+    assert_eq!(diagnostics[0].unknown_items, vec!['\r', ' ', '1']);
+    assert_eq!(diagnostics[0].shared_items.len(), 2);
+
+    assert_eq!(diagnostics[1].kind, SharingKind::UnknownItems);
+    assert_eq!(diagnostics[1].unknown_items, vec!['!', '-', 'é']);
+    assert_eq!(diagnostics[1].shared_items, vec![SharedItem { item: 'a', priority: 1 }]);
+}
+
+#[test]
+fn groups_are_classified_by_the_item_types_all_their_rucksacks_carry() {
+
+    let input: Vec<Rucksack> = rucksacks(&[
+        "vJrwpWtwJgWrhcsFMMfFFhFp", "jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL", "PmmdzqPrVvPwwTWBwg",
+        "abcdab", "abefab", "abghab",
+        "abcd", "efgh", "ijkl",
+        "abab", "a!a!", "abab"
+    ]);
+    let diagnostics = diagnose_groups(&input, &GroupingRule::Consecutive(3)).unwrap();
+
+    assert_eq!(diagnostics.iter().map(|diagnostic| diagnostic.lines.clone()).collect::<Vec<_>>(),
+        vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9], vec![10, 11, 12]]);
+    assert_eq!(diagnostics.iter().map(|diagnostic| diagnostic.kind).collect::<Vec<_>>(),
+        vec![SharingKind::Single, SharingKind::Ambiguous, SharingKind::Missing, SharingKind::UnknownItems]);
+
+    assert_eq!(diagnostics[0].shared_items, vec![SharedItem { item: 'r', priority: 18 }]);
+    assert_eq!(diagnostics[1].shared_items.len(), 2);
+    assert_eq!(diagnostics[3].unknown_items, vec!['!']);
+    assert_eq!(diagnostics[3].shared_items, vec![SharedItem { item: 'a', priority: 1 }]);
+}
+
+#[test]
+fn groups_follow_the_grouping_rule() {
+
+    let input: Vec<Rucksack> = rucksacks(&["abab", "acac", "adad", "aeae"]);
+
+    let windows = diagnose_groups(&input, &GroupingRule::SlidingWindow(2)).unwrap();
+    assert_eq!(windows.iter().map(|diagnostic| diagnostic.lines.clone()).collect::<Vec<_>>(), vec![vec![1, 2], vec![2, 3], vec![3, 4]]);
+    assert!(windows.iter().all(|diagnostic| diagnostic.kind == SharingKind::Single));
+
+    assert_eq!(diagnose_groups(&input, &GroupingRule::Consecutive(3)),
+        Err(GroupingError::IncompleteGroup { first_rucksack: 3, expected: 3, found: 1 }));
+}
+
+#[test]
+fn report_counts_the_anomalies() {
+
+    let report = DiagnosticReport::new(&rucksacks(&["abab", "abcd", "abZa"]), &GroupingRule::Consecutive(3)).unwrap();
+
+    assert_eq!(report.rucksack_anomalies().map(|diagnostic| diagnostic.line).collect::<Vec<_>>(), vec![1, 2]);
+    assert_eq!(report.group_anomalies().count(), 1);
+    assert!(report.to_string().starts_with("Rucksacks: 2 of 3 anomalous\n"));
+}
@@ -0,0 +1,43 @@
+use proptest::prelude::*;
+
+use day_03::{Rucksack, convert_input_to_correct_format};
+use day_03::formats::{rucksacks_from_csv, rucksacks_from_json, rucksacks_to_csv, rucksacks_to_json};
+
+fn develop_rucksacks() -> Vec<Rucksack> {
+    return convert_input_to_correct_format(vec!["vJrwpWtwJgWrhcsFMMfFFhFp".to_owned(), "αβ,\"".to_owned(), String::new()]);
+}
+
+#[test]
+fn json_round_trip() {
+
+    let json: String = rucksacks_to_json(&develop_rucksacks()).unwrap();
+    assert_eq!(json, r#"[{"first_compartment":"vJrwpWtwJgWr","second_compartment":"hcsFMMfFFhFp"},{"first_compartment":"αβ","second_compartment":",\""},{"first_compartment":"","second_compartment":""}]"#);
+    assert_eq!(rucksacks_from_json(&json).unwrap(), develop_rucksacks());
+}
+
+#[test]
+fn csv_round_trip() {
+
+    let csv: String = rucksacks_to_csv(&develop_rucksacks()).unwrap();
+    assert_eq!(csv, "first_compartment,second_compartment\nvJrwpWtwJgWr,hcsFMMfFFhFp\nαβ,\",\"\"\"\n,\n");
+    assert_eq!(rucksacks_from_csv(&csv).unwrap(), develop_rucksacks());
+}
+
+#[test]
+fn malformed_records_are_errors() {
+
+    assert!(rucksacks_from_json(r#"[{"first_compartment": "ab"}]"#).is_err());
+    assert!(rucksacks_from_json(r#"[{"first_compartment": 1, "second_compartment": "ab"}]"#).is_err());
+    assert!(rucksacks_from_csv("first_compartment,second_compartment\nab\n").is_err());
+}
+
+proptest! {
+
+    #[test]
+    fn any_rucksacks_round_trip(rucksacks in prop::collection::vec(
+        (prop::collection::vec(any::<char>(), 0 .. 8), prop::collection::vec(any::<char>(), 0 .. 8)), 0 .. 8)) {
+
+        prop_assert_eq!(rucksacks_from_json(&rucksacks_to_json(&rucksacks).unwrap()).unwrap(), rucksacks.clone());
+        prop_assert_eq!(rucksacks_from_csv(&rucksacks_to_csv(&rucksacks).unwrap()).unwrap(), rucksacks);
+    }
+}
@@ -0,0 +1,92 @@
+use proptest::prelude::*;
+
+use day_03::{Rucksack, convert_input_to_correct_format};
+use day_03::grouping::{GroupingError, GroupingRule, PUZZLE_GROUP_SIZE, group_rucksack_positions, group_rucksacks, split_group_labels};
+
+fn develop_lines(lines: &[&str]) -> Vec<String> {
+    return lines.iter().map(|line| line.to_string()).collect();
+}
+
+fn develop_labels(labels: &[&str]) -> GroupingRule {
+    return GroupingRule::Labels(labels.iter().map(|label| label.to_string()).collect());
+}
+
+#[test]
+fn incomplete_groups_start_after_the_last_full_one() {
+
+    assert_eq!(group_rucksack_positions(7, &GroupingRule::Consecutive(PUZZLE_GROUP_SIZE)),
+        Err(GroupingError::IncompleteGroup { first_rucksack: 6, expected: 3, found: 1 }));
+    assert_eq!(group_rucksack_positions(8, &GroupingRule::Consecutive(3)),
+        Err(GroupingError::IncompleteGroup { first_rucksack: 6, expected: 3, found: 2 }));
+    assert_eq!(group_rucksack_positions(2, &GroupingRule::Consecutive(5)),
+        Err(GroupingError::IncompleteGroup { first_rucksack: 0, expected: 5, found: 2 }));
+    assert_eq!(GroupingError::IncompleteGroup { first_rucksack: 6, expected: 3, found: 1 }.to_string(),
+        "the group starting at rucksack 6 has 1 rucksacks instead of 3");
+
+    assert_eq!(group_rucksack_positions(6, &GroupingRule::Consecutive(3)), Ok(vec![vec![0, 1, 2], vec![3, 4, 5]]));
+    assert_eq!(group_rucksack_positions(0, &GroupingRule::Consecutive(3)), Ok(Vec::new()));
+    assert_eq!(group_rucksack_positions(4, &GroupingRule::Consecutive(0)), Err(GroupingError::EmptyGroupSize));
+}
+
+#[test]
+fn sliding_windows_need_enough_rucksacks() {
+
+    assert_eq!(group_rucksack_positions(2, &GroupingRule::SlidingWindow(3)),
+        Err(GroupingError::IncompleteGroup { first_rucksack: 0, expected: 3, found: 2 }));
+    assert_eq!(group_rucksack_positions(0, &GroupingRule::SlidingWindow(1)),
+        Err(GroupingError::IncompleteGroup { first_rucksack: 0, expected: 1, found: 0 }));
+    assert_eq!(group_rucksack_positions(3, &GroupingRule::SlidingWindow(0)), Err(GroupingError::EmptyGroupSize));
+
+    assert_eq!(group_rucksack_positions(3, &GroupingRule::SlidingWindow(3)), Ok(vec![vec![0, 1, 2]]));
+    assert_eq!(group_rucksack_positions(4, &GroupingRule::SlidingWindow(2)), Ok(vec![vec![0, 1], vec![1, 2], vec![2, 3]]));
+}
+
+#[test]
+fn labels_keep_the_order_they_show_up_in() {
+
+    assert_eq!(group_rucksack_positions(6, &develop_labels(&["south", "north", "south", "east", "north", "south"])),
+        Ok(vec![vec![0, 2, 5], vec![1, 4], vec![3]]));
+    assert_eq!(group_rucksack_positions(2, &develop_labels(&["b", "a"])), Ok(vec![vec![0], vec![1]]));
+    assert_eq!(group_rucksack_positions(3, &develop_labels(&["a", "b"])), Err(GroupingError::LabelCountMismatch { rucksacks: 3, labels: 2 }));
+
+    // Grouping the rucksacks themselves follows the same positions
+    let rucksacks: Vec<Rucksack> = convert_input_to_correct_format(develop_lines(&["ab", "cd", "ef"]));
+    let groups: Vec<Vec<Rucksack>> = group_rucksacks(&rucksacks, &develop_labels(&["y", "x", "y"])).unwrap();
+    assert_eq!(groups, vec![vec![rucksacks[0].clone(), rucksacks[2].clone()], vec![rucksacks[1].clone()]]);
+}
+
+#[test]
+fn labelled_lines_need_items_and_a_label() {
+
+    let (item_lines, labels) = split_group_labels(develop_lines(&["vJrwpWtwJgWrhcsFMMfFFhFp north", "  PmmdzqPrVvPwwTWBwg\tsouth "])).unwrap();
+    assert_eq!(item_lines, vec!["vJrwpWtwJgWrhcsFMMfFFhFp", "PmmdzqPrVvPwwTWBwg"]);
+    assert_eq!(labels, vec!["north", "south"]);
+
+    assert_eq!(split_group_labels(develop_lines(&["ab north", "cd"])), Err(GroupingError::MalformedLine { line: 2, content: "cd".to_owned() }));
+    assert_eq!(split_group_labels(develop_lines(&["ab north east"])), Err(GroupingError::MalformedLine { line: 1, content: "ab north east".to_owned() }));
+    assert_eq!(split_group_labels(develop_lines(&["ab north", ""])), Err(GroupingError::MalformedLine { line: 2, content: String::new() }));
+    assert_eq!(GroupingError::MalformedLine { line: 2, content: "cd".to_owned() }.to_string(),
+        "line 2 is not made of items followed by a group label: 'cd'");
+    assert_eq!(split_group_labels(Vec::new()), Ok((Vec::new(), Vec::new())));
+}
+
+proptest! {
+
+    #[test]
+    fn groups_cover_the_rucksacks(number_rucksacks in 0usize .. 40, size in 1usize .. 6) {
+
+        match group_rucksack_positions(number_rucksacks, &GroupingRule::Consecutive(size)) {
+            Ok(groups) => prop_assert_eq!(groups.concat(), (0 .. number_rucksacks).collect::<Vec<usize>>()),
+            Err(error) => prop_assert_eq!(error, GroupingError::IncompleteGroup {
+                first_rucksack: number_rucksacks / size * size,
+                expected: size,
+                found: number_rucksacks % size
+            })
+        }
+
+        if let Ok(windows) = group_rucksack_positions(number_rucksacks, &GroupingRule::SlidingWindow(size)) {
+            prop_assert_eq!(windows.len(), number_rucksacks + 1 - size);
+            prop_assert!(windows.iter().enumerate().all(|(first, window)| *window == (first .. first + size).collect::<Vec<usize>>()));
+        }
+    }
+}
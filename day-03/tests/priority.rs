@@ -0,0 +1,110 @@
+use std::collections::HashMap;
+
+use day_03::{PriorityScore, Rucksack, convert_input_to_correct_format, priority_group_badge, priority_rugsack};
+use day_03::grouping::{GroupingError, GroupingRule, group_rucksacks};
+use day_03::priority::{AdventScheme, Alphabet, PriorityError, PriorityScheme, PriorityTable, SchemeConfigError};
+use day_03::priority::{sum_badge_priorities, sum_shared_item_priorities, validate_rucksacks};
+
+const EXAMPLE: [&str; 6] = [
+    "vJrwpWtwJgWrhcsFMMfFFhFp",
+    "jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL",
+    "PmmdzqPrVvPwwTWBwg",
+    "wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn",
+    "ttgJtRGJQctTZtZT",
+    "CrZsJsPPZsGzwwsLwLmpwMDw"
+];
+
+fn rucksacks(lines: &[&str]) -> Vec<Rucksack> {
+    return convert_input_to_correct_format(lines.iter().map(|line| line.to_string()).collect());
+}
+
+#[test]
+fn advent_scheme_gives_the_puzzle_priorities() {
+
+    assert_eq!(AdventScheme.get_priority('a'), Some(1));
+    assert_eq!(AdventScheme.get_priority('z'), Some(26));
+    assert_eq!(AdventScheme.get_priority('A'), Some(27));
+    assert_eq!(AdventScheme.get_priority('Z'), Some(52));
+    for item in ['0', ' ', '-', 'é', 'Ω', '\r'] {
+        assert_eq!(AdventScheme.get_priority(item), None, "{:?}", item);
+    }
+}
+
+#[test]
+fn advent_scheme_totals_match_both_parts() {
+
+    let input: Vec<Rucksack> = rucksacks(&EXAMPLE);
+    let rule: GroupingRule = GroupingRule::Consecutive(3);
+
+    let part_1: PriorityScore = input.iter().map(|(first, second)| priority_rugsack(first, second)).sum();
+    let part_2: PriorityScore = group_rucksacks(&input, &rule).unwrap().into_iter().map(priority_group_badge).sum();
+
+    assert_eq!((part_1, part_2), (157, 70));
+    assert_eq!(sum_shared_item_priorities(&AdventScheme, &input), Ok(part_1));
+    assert_eq!(sum_badge_priorities(&AdventScheme, &input, &rule), Ok(part_2));
+}
+
+#[test]
+fn alphabet_ranks_unicode_items_in_order() {
+
+    let alphabet: Alphabet = Alphabet::new("αβγ🍎").unwrap();
+    assert_eq!(alphabet.get_priority('α'), Some(1));
+    assert_eq!(alphabet.get_priority('🍎'), Some(4));
+    assert_eq!(alphabet.get_priority('a'), None);
+
+    let input: Vec<Rucksack> = rucksacks(&["αβγα", "β🍎🍎β", "αββα"]);
+    assert_eq!(sum_shared_item_priorities(&alphabet, &input), Ok(1 + (2 + 4) + (1 + 2)));
+    assert_eq!(sum_badge_priorities(&alphabet, &input, &GroupingRule::Consecutive(3)), Ok(2));
+}
+
+#[test]
+fn alphabet_rejects_duplicate_items() {
+    assert!(matches!(Alphabet::new("abcb"), Err(SchemeConfigError::DuplicateItem { item: 'b' })));
+}
+
+#[test]
+fn priority_table_loads_from_toml() {
+
+    let table: PriorityTable = PriorityTable::from_toml("[priorities]\n\"α\" = 10\n\"🍎\" = 100\nx = 3\n").unwrap();
+    assert_eq!(table, PriorityTable::new(HashMap::from([('α', 10), ('🍎', 100), ('x', 3)])));
+    assert_eq!(table.get_priority('🍎'), Some(100));
+    assert_eq!(table.get_priority('y'), None);
+}
+
+#[test]
+fn priority_table_rejects_keys_that_are_not_single_items() {
+
+    let error: SchemeConfigError = PriorityTable::from_toml("[priorities]\na = 1\nab = 2\n").unwrap_err();
+    assert!(matches!(&error, SchemeConfigError::NotASingleItem { key } if key == "ab"), "{:?}", error);
+
+    let error: SchemeConfigError = PriorityTable::from_toml("[priorities]\n\"\" = 1\n").unwrap_err();
+    assert!(matches!(&error, SchemeConfigError::NotASingleItem { key } if key.is_empty()), "{:?}", error);
+}
+
+#[test]
+fn priority_table_reports_parse_errors() {
+
+    for config in ["[priorities]\na = ", "[priorities]\na = \"one\"\n", "[other]\na = 1\n", "[priorities]\na = -1\n"] {
+        assert!(matches!(PriorityTable::from_toml(config), Err(SchemeConfigError::Parse(_))), "{:?}", config);
+    }
+}
+
+#[test]
+fn unknown_items_are_reported_with_their_line() {
+
+    let input: Vec<Rucksack> = rucksacks(&["abab", "cdcd", "ef1ef1", "g!g!"]);
+
+    assert_eq!(validate_rucksacks(&AdventScheme, &input), Err(PriorityError::UnknownItem { line: 3, item: '1' }));
+    assert_eq!(validate_rucksacks(&AdventScheme, &input[.. 2]), Ok(()));
+    assert_eq!(sum_shared_item_priorities(&AdventScheme, &input), Err(PriorityError::UnknownItem { line: 3, item: '1' }));
+    assert_eq!(sum_badge_priorities(&Alphabet::new("abcdefg!").unwrap(), &input, &GroupingRule::Consecutive(2)),
+        Err(PriorityError::UnknownItem { line: 3, item: '1' }));
+}
+
+#[test]
+fn grouping_errors_are_forwarded() {
+
+    let input: Vec<Rucksack> = rucksacks(&["abab", "abab"]);
+    assert_eq!(sum_badge_priorities(&AdventScheme, &input, &GroupingRule::Consecutive(3)),
+        Err(PriorityError::Grouping(GroupingError::IncompleteGroup { first_rucksack: 0, expected: 3, found: 2 })));
+}
@@ -0,0 +1,163 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashSet};
+
+use proptest::prelude::*;
+
+use day_03::{Compartment, CompartmentItem, PriorityScore, Rucksack};
+use day_03::priority::{AdventScheme, PriorityScheme};
+use day_03::priority::PriorityError;
+use day_03::repair::{CompartmentSide, RepairApplyError, RepairPlan, RepairReport, RepairStep, plan_rucksack_repair};
+
+/// Item types of the small rucksacks searched by brute force, with far apart priorities.
+const SMALL_ITEM_TYPES: [CompartmentItem; 4] = ['a', 'b', 'c', 'Z'];
+
+/// Copies of each of the small item types held by each compartment.
+type CopyCounts = ([usize; 4], [usize; 4]);
+
+fn develop_rucksack(first_compartment: &str, second_compartment: &str) -> Rucksack {
+    return (first_compartment.chars().collect(), second_compartment.chars().collect());
+}
+
+fn priority(item: CompartmentItem) -> PriorityScore {
+    return AdventScheme.get_priority(item).unwrap();
+}
+
+fn count_copies(compartment: &Compartment) -> [usize; 4] {
+    return SMALL_ITEM_TYPES.map(|item| compartment.iter().filter(|other| **other == item).count());
+}
+
+/// Fewest steps, then lowest cost, over every sequence of moves and swaps of any items, searched
+/// with Dijkstra's algorithm over the copies held by each compartment.
+fn brute_force_repair(rucksack: &Rucksack) -> (usize, PriorityScore) {
+
+    let start: CopyCounts = (count_copies(&rucksack.0), count_copies(&rucksack.1));
+    let mut queue: BinaryHeap<Reverse<(usize, PriorityScore, CopyCounts)>> = BinaryHeap::from([Reverse((0, 0, start))]);
+    let mut visited: HashSet<CopyCounts> = HashSet::new();
+
+    while let Some(Reverse((steps, cost, (first, second)))) = queue.pop() {
+
+        if (0 .. 4).all(|item| first[item] == 0 || second[item] == 0) { return (steps, cost) }
+        if ! visited.insert((first, second)) { continue }
+
+        for item in (0 .. 4).filter(|item| first[*item] > 0) {
+
+            let (mut next_first, mut next_second) = (first, second);
+            next_first[item] -= 1;
+            next_second[item] += 1;
+            queue.push(Reverse((steps + 1, cost + priority(SMALL_ITEM_TYPES[item]), (next_first, next_second))));
+
+            for other in (0 .. 4).filter(|other| second[*other] > 0) {
+
+                let (mut next_first, mut next_second) = (first, second);
+                next_first[item] -= 1;
+                next_second[other] -= 1;
+                next_first[other] += 1;
+                next_second[item] += 1;
+                let carried: PriorityScore = priority(SMALL_ITEM_TYPES[item]) + priority(SMALL_ITEM_TYPES[other]);
+                queue.push(Reverse((steps + 1, cost + carried, (next_first, next_second))));
+            }
+        }
+
+        for item in (0 .. 4).filter(|item| second[*item] > 0) {
+
+            let (mut next_first, mut next_second) = (first, second);
+            next_second[item] -= 1;
+            next_first[item] += 1;
+            queue.push(Reverse((steps + 1, cost + priority(SMALL_ITEM_TYPES[item]), (next_first, next_second))));
+        }
+    }
+
+    unreachable!("moving every item to one compartment leaves them disjoint");
+}
+
+fn compartment(item_types: &'static str, max_items: usize) -> impl Strategy<Value = Compartment> {
+
+    let items: Vec<CompartmentItem> = item_types.chars().collect();
+    return prop::collection::vec(prop::sample::select(items), 0 ..= max_items);
+}
+
+#[test]
+fn lowest_cost_among_the_fewest_steps() {
+
+    // Both plans take two steps: moving the `a` to the first compartment and both `Z` to the
+    // second carries 1 + 52 + 52, the other way around carries 1 + 1 + 52 + 52
+    let rucksack: Rucksack = develop_rucksack("aaZZ", "abZZ");
+    let plan: RepairPlan = plan_rucksack_repair(&rucksack).unwrap();
+    assert_eq!(plan, RepairPlan {
+        steps: vec![RepairStep::Swap { first_item: 'Z', second_item: 'a' }, RepairStep::Move { item: 'Z', from: CompartmentSide::First }],
+        cost: 105
+    });
+    assert_eq!(plan.apply(&rucksack), Ok(develop_rucksack("aaa", "bZZZZ")));
+}
+
+#[test]
+fn disjoint_rucksacks_need_no_steps() {
+
+    let report: RepairReport = RepairReport::new(&[develop_rucksack("abc", "ABC"), develop_rucksack("", ""), develop_rucksack("aa", "Zb")]).unwrap();
+    assert!(report.plans.iter().all(|plan| plan.steps.is_empty() && plan.cost == 0));
+    assert_eq!((report.total_steps, report.total_cost), (0, 0));
+    assert_eq!(report.to_string(), "0 steps in total, for a cost of 0\n");
+}
+
+#[test]
+fn report_lists_the_plans_by_line() {
+
+    let report: RepairReport = RepairReport::new(&[develop_rucksack("ab", "cd"), develop_rucksack("aab", "ab"), develop_rucksack("aA", "A")]).unwrap();
+    assert_eq!((report.total_steps, report.total_cost), (2, 30));
+    assert_eq!(report.to_string(), "line 2: swap b with a (cost 3)\nline 3: move A to the first compartment (cost 27)\n2 steps in total, for a cost of 30\n");
+}
+
+#[test]
+fn items_outside_the_puzzle_are_errors() {
+
+    assert_eq!(RepairReport::new(&[develop_rucksack("ab", "cd"), develop_rucksack("a1", "1b")]), Err(PriorityError::UnknownItem { line: 2, item: '1' }));
+    assert_eq!(plan_rucksack_repair(&develop_rucksack("aé", "a")), Err(PriorityError::UnknownItem { line: 1, item: 'é' }));
+}
+
+#[test]
+fn plans_applied_to_other_rucksacks_are_errors() {
+
+    let plan: RepairPlan = plan_rucksack_repair(&develop_rucksack("aaZZ", "abZZ")).unwrap();
+
+    // The swap needs a `Z` in the first compartment, and the move a second one
+    assert_eq!(plan.apply(&develop_rucksack("ab", "ab")), Err(RepairApplyError { step: 0, item: 'Z', from: CompartmentSide::First }));
+    assert_eq!(plan.apply(&develop_rucksack("Z", "a")), Err(RepairApplyError { step: 1, item: 'Z', from: CompartmentSide::First }));
+    assert_eq!(plan.apply(&develop_rucksack("Z", "b")), Err(RepairApplyError { step: 0, item: 'a', from: CompartmentSide::Second }));
+    assert_eq!(RepairApplyError { step: 1, item: 'Z', from: CompartmentSide::First }.to_string(),
+        "step 2 takes out a 'Z' the first compartment does not hold");
+}
+
+proptest! {
+
+    #[test]
+    fn applied_plans_leave_disjoint_compartments(first in compartment("abcdeXYZ", 30), second in compartment("abcdeXYZ", 30)) {
+
+        let rucksack: Rucksack = (first, second);
+        let plan: RepairPlan = plan_rucksack_repair(&rucksack).unwrap();
+        let (first_compartment, second_compartment) = plan.apply(&rucksack).unwrap();
+
+        prop_assert!(first_compartment.iter().all(|item| ! second_compartment.contains(item)));
+        let mut before: Compartment = rucksack.0.iter().chain(rucksack.1.iter()).copied().collect();
+        let mut after: Compartment = first_compartment.iter().chain(second_compartment.iter()).copied().collect();
+        before.sort();
+        after.sort();
+        prop_assert_eq!(before, after);
+
+        // The cost is the priorities of the items carried, two per swap
+        let carried: PriorityScore = plan.steps.iter()
+            .map(|step| match *step {
+                RepairStep::Move { item, .. } => priority(item),
+                RepairStep::Swap { first_item, second_item } => priority(first_item) + priority(second_item)
+            })
+            .sum();
+        prop_assert_eq!(plan.cost, carried);
+    }
+
+    #[test]
+    fn plans_match_brute_force_on_small_rucksacks(first in compartment("abcZ", 4), second in compartment("abcZ", 4)) {
+
+        let rucksack: Rucksack = (first, second);
+        let plan: RepairPlan = plan_rucksack_repair(&rucksack).unwrap();
+        prop_assert_eq!((plan.steps.len(), plan.cost), brute_force_repair(&rucksack));
+    }
+}
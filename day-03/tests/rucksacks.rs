@@ -0,0 +1,91 @@
+use proptest::prelude::*;
+use proptest::sample::Index;
+
+use day_03::{Compartment, CompartmentItem, Rucksack};
+use day_03::{priority_group_badge, priority_group_badge_reference, priority_rugsack, priority_rugsack_reference};
+
+fn shuffled_item_types() -> impl Strategy<Value = Vec<CompartmentItem>> {
+    return Just(('a' ..= 'z').chain('A' ..= 'Z').collect::<Vec<CompartmentItem>>()).prop_shuffle();
+}
+
+fn pick_items(pool: &[CompartmentItem], picks: &[Index]) -> Compartment {
+    return picks.iter().map(|pick| pool[pick.index(pool.len())]).collect();
+}
+
+/// Rucksacks whose compartments share exactly one item type, the first of the shuffled types.
+fn rucksack_with_one_shared_item() -> impl Strategy<Value = Rucksack> {
+
+    return (shuffled_item_types(), 2usize .. 51, prop::collection::vec(any::<Index>(), 0 .. 24), prop::collection::vec(any::<Index>(), 0 .. 24))
+        .prop_map(|(item_types, split_point, first_picks, second_picks)| {
+
+            let mut first_compartment: Compartment = pick_items(&item_types[1 .. split_point], &first_picks);
+            let mut second_compartment: Compartment = pick_items(&item_types[split_point ..], &second_picks);
+            first_compartment.push(item_types[0]);
+            second_compartment.insert(0, item_types[0]);
+            return (first_compartment, second_compartment);
+        });
+}
+
+/// Groups of three rucksacks carrying exactly one common item type, the first of the shuffled types.
+fn group_with_one_badge() -> impl Strategy<Value = Vec<Rucksack>> {
+
+    return (shuffled_item_types(), prop::collection::vec((prop::collection::vec(any::<Index>(), 0 .. 48), any::<Index>()), 3))
+        .prop_map(|(item_types, rucksack_picks)| rucksack_picks.into_iter()
+            .enumerate()
+            .map(|(position, (picks, split_point))| {
+
+                // Every other item type is left out of one rucksack of the group
+                let pool: Vec<CompartmentItem> = item_types[1 ..].iter()
+                    .enumerate()
+                    .filter(|(index, _)| index % 3 != position)
+                    .map(|(_, item)| *item)
+                    .collect();
+
+                let mut items: Compartment = pick_items(&pool, &picks);
+                items.push(item_types[0]);
+                let second_compartment: Compartment = items.split_off(split_point.index(items.len()));
+                return (items, second_compartment);
+            })
+            .collect());
+}
+
+/// Rucksacks drawn from few item types, so that their compartments often share several of them.
+fn rucksack_with_shared_items() -> impl Strategy<Value = Rucksack> {
+
+    let compartment = || prop::collection::vec(prop::sample::select(vec!['a', 'b', 'Y', 'Z']), 1 .. 8);
+    return (compartment(), compartment(), prop::sample::select(vec!['a', 'b', 'Y', 'Z']))
+        .prop_map(|(mut first_compartment, mut second_compartment, shared_item)| {
+
+            first_compartment.push(shared_item);
+            second_compartment.push(shared_item);
+            return (first_compartment, second_compartment);
+        });
+}
+
+#[test]
+fn several_shared_items_score_the_first_of_the_second_compartment() {
+
+    // 'Z', 'a' and 'b' are all shared, and 'Z' comes first in the second compartment
+    let first_compartment: Compartment = vec!['a', 'b', 'Z', 'c'];
+    let second_compartment: Compartment = vec!['d', 'Z', 'b', 'a'];
+    assert_eq!(priority_rugsack(&first_compartment, &second_compartment), 52);
+    assert_eq!(priority_rugsack_reference(&first_compartment, &second_compartment), 52);
+}
+
+proptest! {
+
+    #[test]
+    fn priority_rugsack_matches_reference_on_several_shared_items(rucksack in rucksack_with_shared_items()) {
+        prop_assert_eq!(priority_rugsack(&rucksack.0, &rucksack.1), priority_rugsack_reference(&rucksack.0, &rucksack.1));
+    }
+
+    #[test]
+    fn priority_rugsack_matches_reference(rucksack in rucksack_with_one_shared_item()) {
+        prop_assert_eq!(priority_rugsack(&rucksack.0, &rucksack.1), priority_rugsack_reference(&rucksack.0, &rucksack.1));
+    }
+
+    #[test]
+    fn priority_group_badge_matches_reference(group in group_with_one_badge()) {
+        prop_assert_eq!(priority_group_badge(group.clone()), priority_group_badge_reference(group));
+    }
+}
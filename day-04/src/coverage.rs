@@ -0,0 +1,93 @@
+//! Sections of the camp left uncovered or crowded, and elves whose assignment others already cover.
+//!
+//! The crew is numbered as in [`crate::crew`]. An elf is redundant when every section of their
+//! assignment is covered by at least one other elf; each redundant elf can be reassigned on their
+//! own, but reassigning several of them at once may leave sections uncovered.
+
+use std::fmt;
+
+use crate::{ResponsabilityBoundary, ResponsabilityInterval};
+use crate::crew::{ElfID, develop_coverage_events};
+use crate::interval_set::{IntervalSemantics, IntervalSet};
+
+// =========================================== TYPE AND STRUCT DEFINITIONS ===========================================
+
+/// Coverage of the camp by the crew.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct CoverageReport {
+    pub camp: ResponsabilityInterval,
+    /// Sections of the camp no elf is assigned to.
+    pub gaps: IntervalSet,
+    /// Largest number of elves a section can have without being crowded.
+    pub max_elves: usize,
+    /// Sections assigned to more than `max_elves` elves.
+    pub crowded_sections: IntervalSet,
+    /// Elves whose whole assignment is covered by the other elves, in order.
+    pub redundant_elves: Vec<ElfID>
+}
+
+// =============================================== AUXILIARY FUNCTIONS ===============================================
+
+/// Sections assigned to more than `number_elves` elves of the crew.
+pub fn sections_covered_by_more_than(crew: &[ResponsabilityInterval], number_elves: usize) -> IntervalSet {
+
+    let mut crowded_intervals: Vec<ResponsabilityInterval> = Vec::new();
+    let mut crowded_since: Option<ResponsabilityBoundary> = None;
+    let mut current_elves: usize = 0;
+
+    for (section, added) in develop_coverage_events(crew) {
+
+        match added {
+            true => current_elves += 1,
+            false => current_elves -= 1
+        }
+
+        match (crowded_since, current_elves > number_elves) {
+            (None, true) => crowded_since = Some(section),
+            (Some(start), false) => {
+                crowded_intervals.push(ResponsabilityInterval::new(start, section));
+                crowded_since = None;
+            },
+            _ => ()
+        }
+    }
+
+    return IntervalSet::from_intervals(crowded_intervals, IntervalSemantics::Closed);
+}
+
+// ================================================= IMPLEMENTATIONS =================================================
+
+impl CoverageReport {
+
+    /// Checks the crew's assignments against the sections of `camp`, closed as in the puzzle.
+    pub fn new(crew: &[ResponsabilityInterval], camp: ResponsabilityInterval, max_elves: usize) -> CoverageReport {
+
+        let covered: IntervalSet = IntervalSet::from_intervals(crew.iter().copied(), IntervalSemantics::Closed);
+        let covered_twice: IntervalSet = sections_covered_by_more_than(crew, 1);
+
+        return CoverageReport {
+            camp,
+            gaps: IntervalSet::from(camp).difference(&covered),
+            max_elves,
+            crowded_sections: sections_covered_by_more_than(crew, max_elves),
+            redundant_elves: (0 .. crew.len())
+                .filter(|elf| covered_twice.contains_interval(crew[*elf], IntervalSemantics::Closed))
+                .collect()
+        };
+    }
+}
+
+impl fmt::Display for CoverageReport {
+
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+
+        writeln!(formatter, "Sections of the camp {} nobody covers: {} ({} sections)", self.camp, self.gaps, self.gaps.len())?;
+        writeln!(formatter, "Sections covered by more than {} elves: {} ({} sections)", self.max_elves, self.crowded_sections, self.crowded_sections.len())?;
+
+        let redundant_elves: Vec<String> = self.redundant_elves.iter()
+            .map(|elf| format!("{} (line {})", elf, elf / 2 + 1))
+            .collect();
+        return writeln!(formatter, "Elves covered by the others: {} of them{}{}", redundant_elves.len(),
+            if redundant_elves.is_empty() { "" } else { ", " }, redundant_elves.join(", "));
+    }
+}
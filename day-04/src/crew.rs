@@ -0,0 +1,141 @@
+//! Overlaps between every pair of elves of the crew, not only the two elves of a line.
+//!
+//! Every assignment of the input is an elf, numbered in the order of the input, so that the
+//! elves of the first line are 0 and 1, those of the second line 2 and 3, and so on. Overlapping
+//! pairs are found with a sweep line over the starts of the assignments, which only ever compares
+//! an elf with the elves whose assignments are still running, instead of with the whole crew.
+//! An assignment whose start is past its end holds no sections, so it overlaps no other.
+
+use std::collections::BTreeSet;
+
+use crate::{PairResponsabilities, ResponsabilityBoundary, ResponsabilityInterval, detect_partial_overlap};
+
+// =========================================== TYPE AND STRUCT DEFINITIONS ===========================================
+
+/// Position of an elf in the crew.
+pub type ElfID = usize;
+
+/// How the assignments of the crew overlap.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct CrewOverlaps {
+    /// Pairs of elves sharing at least one section, the lowest ID first, sorted.
+    pub overlapping_pairs: Vec<(ElfID, ElfID)>,
+    /// Number of other elves each elf shares sections with.
+    pub overlap_counts: Vec<usize>,
+    /// Largest number of elves assigned to a single section.
+    pub max_elves_on_section: usize,
+    /// First section with that many elves, if any elf has a section.
+    pub busiest_section: Option<ResponsabilityBoundary>
+}
+
+// =============================================== AUXILIARY FUNCTIONS ===============================================
+
+/// Assignments of every elf of the crew, in order.
+pub fn develop_crew(pairs: &[PairResponsabilities]) -> Vec<ResponsabilityInterval> {
+
+    return pairs.iter()
+        .flat_map(|(first_interval, second_interval)| [*first_interval, *second_interval])
+        .collect();
+}
+
+/// Pairs of elves whose assignments share at least one section, the lowest ID first, sorted.
+pub fn find_overlapping_pairs(crew: &[ResponsabilityInterval]) -> Vec<(ElfID, ElfID)> {
+
+    let mut elves_by_start: Vec<ElfID> = (0 .. crew.len())
+        .filter(|elf| ! crew[*elf].is_empty())
+        .collect();
+    elves_by_start.sort_by_key(|elf| crew[*elf].start);
+
+    // Elves whose assignment has started, by the last section of their assignment
+    let mut running: BTreeSet<(ResponsabilityBoundary, ElfID)> = BTreeSet::new();
+    let mut overlapping_pairs: Vec<(ElfID, ElfID)> = Vec::new();
+
+    for elf in elves_by_start {
+
+        let start: ResponsabilityBoundary = crew[elf].start;
+        running = running.split_off(&(start, 0));
+
+        overlapping_pairs.extend(running.iter().map(|(_, other)| (elf.min(*other), elf.max(*other))));
+        running.insert((crew[elf].end, elf));
+    }
+
+    overlapping_pairs.sort();
+    return overlapping_pairs;
+}
+
+/// Straightforward version of [`find_overlapping_pairs`], comparing every pair of elves.
+/// Kept as a reference to check the faster version against.
+pub fn find_overlapping_pairs_reference(crew: &[ResponsabilityInterval]) -> Vec<(ElfID, ElfID)> {
+
+    let mut overlapping_pairs: Vec<(ElfID, ElfID)> = Vec::new();
+    for first in 0 .. crew.len() {
+        for second in first + 1 .. crew.len() {
+
+            // The comparisons of the puzzle take the boundaries as given, even the wrong way round
+            if crew[first].is_empty() || crew[second].is_empty() { continue }
+            if detect_partial_overlap(&crew[first], &crew[second]) {
+                overlapping_pairs.push((first, second));
+            }
+        }
+    }
+
+    return overlapping_pairs;
+}
+
+/// Sections where an elf starts (`true`) or stops (`false`) working, sorted, with every elf
+/// starting at a section coming before any elf working there for the last time.
+pub(crate) fn develop_coverage_events(crew: &[ResponsabilityInterval]) -> Vec<(ResponsabilityBoundary, bool)> {
+
+    // Each assignment adds an elf at its start and removes it at its end, once the elves starting
+    // there are counted, which keeps the last section of the camp from overflowing
+    let mut events: Vec<(ResponsabilityBoundary, bool)> = crew.iter()
+        .filter(|interval| ! interval.is_empty())
+        .flat_map(|interval| [(interval.start, true), (interval.end, false)])
+        .collect();
+    events.sort_by_key(|(section, added)| (*section, ! *added));
+
+    return events;
+}
+
+/// Largest number of elves assigned to a single section, and the first section with that many.
+pub fn find_busiest_section(crew: &[ResponsabilityInterval]) -> Option<(ResponsabilityBoundary, usize)> {
+
+    let mut busiest: Option<(ResponsabilityBoundary, usize)> = None;
+    let mut number_elves: usize = 0;
+    for (section, added) in develop_coverage_events(crew) {
+
+        match added {
+            true => number_elves += 1,
+            false => number_elves -= 1
+        }
+        if busiest.is_none_or(|(_, most_elves)| number_elves > most_elves) {
+            busiest = Some((section, number_elves));
+        }
+    }
+
+    return busiest;
+}
+
+// ================================================= IMPLEMENTATIONS =================================================
+
+impl CrewOverlaps {
+
+    pub fn new(crew: &[ResponsabilityInterval]) -> CrewOverlaps {
+
+        let overlapping_pairs: Vec<(ElfID, ElfID)> = find_overlapping_pairs(crew);
+
+        let mut overlap_counts: Vec<usize> = vec![0; crew.len()];
+        for (first, second) in overlapping_pairs.iter() {
+            overlap_counts[*first] += 1;
+            overlap_counts[*second] += 1;
+        }
+
+        let busiest: Option<(ResponsabilityBoundary, usize)> = find_busiest_section(crew);
+        return CrewOverlaps {
+            overlapping_pairs,
+            overlap_counts,
+            max_elves_on_section: busiest.map_or(0, |(_, number_elves)| number_elves),
+            busiest_section: busiest.map(|(section, _)| section)
+        };
+    }
+}
@@ -0,0 +1,58 @@
+//! Structured representations of the section assignments.
+//!
+//! In JSON each line is a pair of intervals, e.g. `[[{"start": 2, "end": 4}, {"start": 6, "end": 8}]]`.
+//! In CSV each line is a row of `first_start,first_end,second_start,second_end`.
+
+use serde::{Deserialize, Serialize};
+
+use crate::{PairResponsabilities, ResponsabilityBoundary, ResponsabilityInterval};
+
+// =========================================== TYPE AND STRUCT DEFINITIONS ===========================================
+
+#[derive(Serialize, Deserialize)]
+struct PairRecord {
+    first_start: ResponsabilityBoundary,
+    first_end: ResponsabilityBoundary,
+    second_start: ResponsabilityBoundary,
+    second_end: ResponsabilityBoundary
+}
+
+// =============================================== AUXILIARY FUNCTIONS ===============================================
+
+/// Reads the pairs of assignments from a JSON list of interval pairs.
+pub fn pairs_from_json(input: &str) -> serde_json::Result<Vec<PairResponsabilities>> {
+    return serde_json::from_str(input);
+}
+
+/// Writes the pairs of assignments as a JSON list of interval pairs.
+pub fn pairs_to_json(pairs: &[PairResponsabilities]) -> serde_json::Result<String> {
+    return serde_json::to_string(pairs);
+}
+
+/// Reads the pairs of assignments from CSV rows of `first_start,first_end,second_start,second_end`.
+pub fn pairs_from_csv(input: &str) -> csv::Result<Vec<PairResponsabilities>> {
+
+    let mut reader = csv::Reader::from_reader(input.as_bytes());
+    return reader.deserialize()
+        .map(|record| record.map(|record: PairRecord| (
+            ResponsabilityInterval::new(record.first_start, record.first_end),
+            ResponsabilityInterval::new(record.second_start, record.second_end))))
+        .collect();
+}
+
+/// Writes the pairs of assignments as CSV rows of `first_start,first_end,second_start,second_end`.
+pub fn pairs_to_csv(pairs: &[PairResponsabilities]) -> csv::Result<String> {
+
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    for (first_interval, second_interval) in pairs.iter() {
+        writer.serialize(PairRecord {
+            first_start: first_interval.start,
+            first_end: first_interval.end,
+            second_start: second_interval.start,
+            second_end: second_interval.end
+        })?;
+    }
+
+    let output: Vec<u8> = writer.into_inner().map_err(|error| error.into_error())?;
+    return Ok(String::from_utf8(output).unwrap());
+}
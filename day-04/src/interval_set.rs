@@ -0,0 +1,227 @@
+//! Sets of sections, kept as the fewest disjoint intervals, and the usual set operations on them.
+//!
+//! A set is always normalized: its intervals are sorted, never overlap and never touch, so that
+//! `2-4` and `5-7` are kept as `2-7`. Intervals come in and out either closed, with both ends
+//! included as in the puzzle, or half-open, with the end left out; inside the set they are closed.
+
+use std::fmt;
+
+use crate::{ResponsabilityBoundary, ResponsabilityInterval};
+
+// =========================================== TYPE AND STRUCT DEFINITIONS ===========================================
+
+/// Whether the end of an interval is one of its sections.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum IntervalSemantics {
+    /// From `start` to `end`, both included; empty when `start > end`.
+    Closed,
+    /// From `start` included to `end` excluded; empty when `start >= end`.
+    HalfOpen
+}
+
+/// Set of sections, as sorted closed intervals that neither overlap nor touch.
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct IntervalSet {
+    intervals: Vec<ResponsabilityInterval>
+}
+
+// =============================================== AUXILIARY FUNCTIONS ===============================================
+
+/// The interval in closed form, or `None` if it holds no section.
+fn to_closed(interval: ResponsabilityInterval, semantics: IntervalSemantics) -> Option<ResponsabilityInterval> {
+
+    return match semantics {
+        IntervalSemantics::Closed if interval.start <= interval.end => Some(interval),
+        IntervalSemantics::HalfOpen if interval.start < interval.end => Some(ResponsabilityInterval::new(interval.start, interval.end - 1)),
+        _ => None
+    };
+}
+
+fn from_closed(interval: ResponsabilityInterval, semantics: IntervalSemantics) -> ResponsabilityInterval {
+
+    return match semantics {
+        IntervalSemantics::Closed => interval,
+        IntervalSemantics::HalfOpen => ResponsabilityInterval::new(interval.start, interval.end.checked_add(1)
+            .unwrap_or_else(|| panic!("🚨 Section {} is the last one, no half-open interval can include it", interval.end)))
+    };
+}
+
+/// Sorts closed intervals and merges the ones that overlap or touch.
+fn normalize(mut intervals: Vec<ResponsabilityInterval>) -> Vec<ResponsabilityInterval> {
+
+    intervals.sort_by_key(|interval| (interval.start, interval.end));
+
+    let mut merged: Vec<ResponsabilityInterval> = Vec::with_capacity(intervals.len());
+    for interval in intervals {
+
+        match merged.last_mut() {
+            Some(last) if interval.start <= last.end.saturating_add(1) => last.end = last.end.max(interval.end),
+            _ => merged.push(interval)
+        }
+    }
+
+    return merged;
+}
+
+// ================================================= IMPLEMENTATIONS =================================================
+
+impl IntervalSet {
+
+    /// The empty set.
+    pub fn new() -> IntervalSet {
+        IntervalSet {
+            intervals: Vec::new()
+        }
+    }
+
+    /// Set of the sections of all the intervals, read under `semantics`.
+    pub fn from_intervals<I: IntoIterator<Item = ResponsabilityInterval>>(intervals: I, semantics: IntervalSemantics) -> IntervalSet {
+
+        return IntervalSet {
+            intervals: normalize(intervals.into_iter()
+                .filter_map(|interval| to_closed(interval, semantics))
+                .collect())
+        };
+    }
+
+    /// Adds the sections of the interval, read under `semantics`.
+    pub fn insert(&mut self, interval: ResponsabilityInterval, semantics: IntervalSemantics) {
+
+        if let Some(interval) = to_closed(interval, semantics) {
+            self.intervals.push(interval);
+            self.intervals = normalize(std::mem::take(&mut self.intervals));
+        }
+    }
+
+    /// The intervals of the set, closed, sorted, and neither overlapping nor touching.
+    pub fn get_intervals(&self) -> &[ResponsabilityInterval] {
+        return &self.intervals;
+    }
+
+    /// The intervals of the set, written under `semantics`.
+    pub fn to_intervals(&self, semantics: IntervalSemantics) -> Vec<ResponsabilityInterval> {
+
+        return self.intervals.iter()
+            .map(|interval| from_closed(*interval, semantics))
+            .collect();
+    }
+
+    /// Number of sections in the set.
+    pub fn len(&self) -> usize {
+        return self.intervals.iter().map(|interval| interval.end - interval.start + 1).sum();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.intervals.is_empty();
+    }
+
+    pub fn contains_section(&self, section: ResponsabilityBoundary) -> bool {
+
+        let position: usize = self.intervals.partition_point(|interval| interval.end < section);
+        return self.intervals.get(position).is_some_and(|interval| interval.start <= section);
+    }
+
+    /// Whether every section of the interval, read under `semantics`, is in the set.
+    pub fn contains_interval(&self, interval: ResponsabilityInterval, semantics: IntervalSemantics) -> bool {
+
+        let Some(interval) = to_closed(interval, semantics) else { return true };
+        let position: usize = self.intervals.partition_point(|other| other.end < interval.start);
+        return self.intervals.get(position).is_some_and(|other| other.start <= interval.start && interval.end <= other.end);
+    }
+
+    /// Whether every section of the set is in `other`.
+    pub fn is_subset(&self, other: &IntervalSet) -> bool {
+        return self.intervals.iter().all(|interval| other.contains_interval(*interval, IntervalSemantics::Closed));
+    }
+
+    /// Whether every section of `other` is in the set.
+    pub fn is_superset(&self, other: &IntervalSet) -> bool {
+        return other.is_subset(self);
+    }
+
+    /// Sections in either set.
+    pub fn union(&self, other: &IntervalSet) -> IntervalSet {
+
+        return IntervalSet {
+            intervals: normalize(self.intervals.iter().chain(other.intervals.iter()).copied().collect())
+        };
+    }
+
+    /// Sections in both sets.
+    pub fn intersection(&self, other: &IntervalSet) -> IntervalSet {
+
+        let mut intervals: Vec<ResponsabilityInterval> = Vec::new();
+        let (mut first, mut second): (usize, usize) = (0, 0);
+
+        while let (Some(first_interval), Some(second_interval)) = (self.intervals.get(first), other.intervals.get(second)) {
+
+            let start: ResponsabilityBoundary = first_interval.start.max(second_interval.start);
+            let end: ResponsabilityBoundary = first_interval.end.min(second_interval.end);
+            if start <= end { intervals.push(ResponsabilityInterval::new(start, end)) }
+
+            // The interval ending first cannot meet any later interval of the other set
+            match first_interval.end <= second_interval.end {
+                true => first += 1,
+                false => second += 1
+            }
+        }
+
+        return IntervalSet { intervals };
+    }
+
+    /// Sections in the set but not in `other`.
+    pub fn difference(&self, other: &IntervalSet) -> IntervalSet {
+
+        let mut intervals: Vec<ResponsabilityInterval> = Vec::new();
+        let mut second: usize = 0;
+
+        for interval in self.intervals.iter() {
+
+            // Removed intervals ending before this one cannot affect the later ones either
+            while other.intervals.get(second).is_some_and(|removed| removed.end < interval.start) {
+                second += 1;
+            }
+
+            let mut start: ResponsabilityBoundary = interval.start;
+            let mut remaining: bool = true;
+            for removed in other.intervals[second ..].iter().take_while(|removed| removed.start <= interval.end) {
+
+                if removed.start > start { intervals.push(ResponsabilityInterval::new(start, removed.start - 1)) }
+                if removed.end >= interval.end {
+                    remaining = false;
+                    break;
+                }
+                start = removed.end + 1;
+            }
+
+            if remaining { intervals.push(ResponsabilityInterval::new(start, interval.end)) }
+        }
+
+        return IntervalSet { intervals };
+    }
+
+    /// Sections in exactly one of the sets.
+    pub fn symmetric_difference(&self, other: &IntervalSet) -> IntervalSet {
+        return self.union(other).difference(&self.intersection(other));
+    }
+}
+
+impl From<ResponsabilityInterval> for IntervalSet {
+
+    /// Set of the sections of a single interval, closed as in the puzzle.
+    fn from(interval: ResponsabilityInterval) -> IntervalSet {
+        return IntervalSet::from_intervals([interval], IntervalSemantics::Closed);
+    }
+}
+
+impl fmt::Display for IntervalSet {
+
+    /// Writes the closed intervals as in the input, e.g. `2-4, 6-8`, or `none` for the empty set.
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+
+        if self.is_empty() { return write!(formatter, "none") }
+
+        let intervals: Vec<String> = self.intervals.iter().map(|interval| interval.to_string()).collect();
+        return write!(formatter, "{}", intervals.join(", "));
+    }
+}
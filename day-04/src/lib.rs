@@ -1,23 +1,76 @@
+//! Camp cleanup (Advent of Code 2022, day 04).
+//!
+//! Each line of the input assigns a pair of elves an inclusive range of section IDs to clean,
+//! and the puzzle asks how often one assignment overlaps the other.
+//! Overlaps across the whole crew, rather than within each pair, are found by [`crew`].
+//! Sections nobody covers, crowded sections and redundant elves are reported by [`coverage`].
+//! Whole sets of sections are combined with the union, intersection and difference of [`interval_set`].
+//! The assignments can also be read from, and written to, JSON or CSV through [`formats`].
+
+use std::fmt;
+use serde::{Deserialize, Serialize};
+
+pub mod coverage;
+pub mod crew;
+pub mod formats;
+pub mod interval_set;
 
 // =========================================== TYPE AND STRUCT DEFINITIONS ===========================================
 
+/// ID of a section of the camp.
 pub type ResponsabilityBoundary = usize;
 
+/// Inclusive range of sections assigned to an elf.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
 pub struct ResponsabilityInterval {
     start: ResponsabilityBoundary,
     end: ResponsabilityBoundary
 }
 
+/// Assignments of the two elves of a pair.
 pub type PairResponsabilities = (ResponsabilityInterval, ResponsabilityInterval);
 
 // =============================================== AUXILIARY FUNCTIONS ===============================================
 
+/// Reads each line of the input, such as `2-4,6-8`, as the assignments of a pair of elves.
+pub fn convert_input_to_correct_format(input_lines: Vec<String>) -> Vec<PairResponsabilities> {
+
+    return input_lines.into_iter()
+        .map(|line| {
+
+            let mut split_elfs: Vec<String> = line.split(',')
+                .map(|split| split.to_owned())
+                .rev()
+                .collect();
+            
+            let first_split: String = split_elfs.pop().unwrap();
+            let second_split: String = split_elfs.pop().unwrap();
+
+            let mut first_split_boundaries: Vec<ResponsabilityBoundary> = first_split.split('-')
+                .map(|boundary| boundary.parse().unwrap())
+                .rev()
+                .collect();
+            let mut second_split_boundaries: Vec<ResponsabilityBoundary> = second_split.split('-')
+                .map(|boundary| boundary.parse().unwrap())
+                .rev()
+                .collect();
+
+            let first_interval: ResponsabilityInterval = ResponsabilityInterval::new(first_split_boundaries.pop().unwrap(), first_split_boundaries.pop().unwrap());
+            let second_interval: ResponsabilityInterval = ResponsabilityInterval::new(second_split_boundaries.pop().unwrap(), second_split_boundaries.pop().unwrap());
+
+            return (first_interval, second_interval);
+
+        }).collect();
+}
+
+/// Whether one of the intervals fully contains the other.
 pub fn detect_total_overlap(first_interval: &ResponsabilityInterval, second_interval: &ResponsabilityInterval) -> bool {
 
     return (first_interval.start >= second_interval.start && first_interval.end <= second_interval.end) ||
         (second_interval.start >= first_interval.start && second_interval.end <= first_interval.end);
 }
 
+/// Whether the intervals share at least one section.
 pub fn detect_partial_overlap(first_interval: &ResponsabilityInterval, second_interval: &ResponsabilityInterval) -> bool {
 
     return (first_interval.start >= second_interval.start && first_interval.start <= second_interval.end) ||
@@ -30,7 +83,29 @@ pub fn detect_partial_overlap(first_interval: &ResponsabilityInterval, second_in
 
 impl ResponsabilityInterval {
 
+    /// Creates the interval of sections from `start` to `end`, both included.
     pub fn new(start: ResponsabilityBoundary, end: ResponsabilityBoundary) -> ResponsabilityInterval {
-        ResponsabilityInterval { start: start, end: end }
+        ResponsabilityInterval { start, end }
+    }
+
+    pub fn get_start(&self) -> ResponsabilityBoundary {
+        return self.start;
+    }
+
+    pub fn get_end(&self) -> ResponsabilityBoundary {
+        return self.end;
+    }
+
+    /// Whether the interval holds no sections, its start being past its end.
+    pub fn is_empty(&self) -> bool {
+        return self.start > self.end;
+    }
+}
+
+impl fmt::Display for ResponsabilityInterval {
+
+    /// Writes the interval as in the input, e.g. `2-4`.
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        return write!(formatter, "{}-{}", self.start, self.end);
     }
 }
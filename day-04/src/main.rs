@@ -1,45 +1,55 @@
 mod read;
-mod lib;
+
+use day_04::{PairResponsabilities, ResponsabilityInterval};
+use day_04::{convert_input_to_correct_format, detect_total_overlap, detect_partial_overlap};
+use day_04::coverage::CoverageReport;
+use day_04::crew::{CrewOverlaps, develop_crew};
 
 fn main() {
 
     let input = read::read_lines("input.txt".to_owned());
-    let input_formatted : Vec<lib::PairResponsabilities> = input.into_iter()
-        .map(|line| {
-
-            let mut split_elfs: Vec<String> = line.split(',')
-                .map(|split| split.to_owned())
-                .rev()
-                .collect();
-            
-            let first_split: String = split_elfs.pop().unwrap();
-            let second_split: String = split_elfs.pop().unwrap();
-
-            let mut first_split_boundaries: Vec<lib::ResponsabilityBoundary> = first_split.split('-')
-                .map(|boundary| boundary.parse().unwrap())
-                .rev()
-                .collect();
-            let mut second_split_boundaries: Vec<lib::ResponsabilityBoundary> = second_split.split('-')
-                .map(|boundary| boundary.parse().unwrap())
-                .rev()
-                .collect();
-
-            let first_interval: lib::ResponsabilityInterval = lib::ResponsabilityInterval::new(first_split_boundaries.pop().unwrap(), first_split_boundaries.pop().unwrap());
-            let second_interval: lib::ResponsabilityInterval = lib::ResponsabilityInterval::new(second_split_boundaries.pop().unwrap(), second_split_boundaries.pop().unwrap());
-
-            return (first_interval, second_interval);
-
-        }).collect();
+    let input_formatted : Vec<PairResponsabilities> = convert_input_to_correct_format(input);
     
     // Part 1
     let number_total_overlaps: usize = input_formatted.iter()
-        .filter(|(first_interval, second_interval)| lib::detect_total_overlap(first_interval, second_interval))
+        .filter(|(first_interval, second_interval)| detect_total_overlap(first_interval, second_interval))
         .count();
     println!("\r🏭 Number of total overlaps in elves work: '{}' (Part 1)", number_total_overlaps);
     
     // Part 2
     let number_partial_overlaps: usize = input_formatted.iter()
-        .filter(|(first_interval, second_interval)| lib::detect_partial_overlap(first_interval, second_interval))
+        .filter(|(first_interval, second_interval)| detect_partial_overlap(first_interval, second_interval))
         .count();
     println!("\r🏭 Number of partial overlaps in elves work: '{}' (Part 2)", number_partial_overlaps);
-}
\ No newline at end of file
+
+    if let Some("--crew") = std::env::args().nth(1).as_deref() {
+
+        let crew_overlaps: CrewOverlaps = CrewOverlaps::new(&develop_crew(&input_formatted));
+        println!("\r👷 Pairs of elves overlapping across the crew: '{}'", crew_overlaps.overlapping_pairs.len());
+
+        if let Some((elf, overlaps)) = crew_overlaps.overlap_counts.iter().enumerate().max_by_key(|(elf, overlaps)| (**overlaps, std::cmp::Reverse(*elf))) {
+            println!("\r👷 Elf overlapping the most others: elf {} (line {}), with '{}'", elf, elf / 2 + 1, overlaps);
+        }
+        if let Some(section) = crew_overlaps.busiest_section {
+            println!("\r👷 Most elves on a single section: '{}' (section {})", crew_overlaps.max_elves_on_section, section);
+        }
+    }
+
+    // E.g. `--coverage 1-99 2` for the camp of sections 1 to 99, crowded above 2 elves. There is
+    // no sensible default for either, so both must be given
+    if let Some("--coverage") = std::env::args().nth(1).as_deref() {
+
+        let (camp, max_elves): (ResponsabilityInterval, usize) = match (std::env::args().nth(2), std::env::args().nth(3)) {
+            (Some(camp), Some(max_elves)) => (
+                camp.split_once('-')
+                    .and_then(|(start, end)| Some(ResponsabilityInterval::new(start.parse().ok()?, end.parse().ok()?)))
+                    .unwrap_or_else(|| panic!("🚨 '{}' is not a camp, expected sections such as '1-99'", camp)),
+                max_elves.parse()
+                    .unwrap_or_else(|_| panic!("🚨 '{}' is not a number of elves", max_elves))
+            ),
+            _ => panic!("🚨 --coverage needs the camp and the most elves a section can have, e.g. '--coverage 1-99 2'")
+        };
+
+        print!("\r🗺️ {}", CoverageReport::new(&develop_crew(&input_formatted), camp, max_elves));
+    }
+}
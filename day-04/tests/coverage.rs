@@ -0,0 +1,105 @@
+use std::collections::BTreeSet;
+
+use proptest::prelude::*;
+
+use day_04::{ResponsabilityBoundary, ResponsabilityInterval};
+use day_04::coverage::{CoverageReport, sections_covered_by_more_than};
+use day_04::crew::ElfID;
+use day_04::interval_set::IntervalSet;
+
+/// Sections up to this one hold every assignment and camp of the generated crews.
+const LAST_SECTION: ResponsabilityBoundary = 20;
+
+type SectionModel = BTreeSet<ResponsabilityBoundary>;
+
+fn interval(start: ResponsabilityBoundary, end: ResponsabilityBoundary) -> ResponsabilityInterval {
+    return ResponsabilityInterval::new(start, end);
+}
+
+fn model_set(set: &IntervalSet) -> SectionModel {
+    return set.get_intervals().iter().flat_map(|interval| interval.get_start() ..= interval.get_end()).collect();
+}
+
+fn elves_on_section(crew: &[ResponsabilityInterval], section: ResponsabilityBoundary) -> usize {
+    return crew.iter().filter(|interval| interval.get_start() <= section && section <= interval.get_end()).count();
+}
+
+/// Gaps, crowded sections and redundant elves, found by looking at one section at a time.
+fn model_report(crew: &[ResponsabilityInterval], camp: ResponsabilityInterval, max_elves: usize) -> (SectionModel, SectionModel, Vec<ElfID>) {
+
+    let gaps: SectionModel = (camp.get_start() ..= camp.get_end())
+        .filter(|section| elves_on_section(crew, *section) == 0)
+        .collect();
+    let crowded_sections: SectionModel = (0 ..= LAST_SECTION)
+        .filter(|section| elves_on_section(crew, *section) > max_elves)
+        .collect();
+
+    // Every section of a redundant elf has someone else on it, which holds for no sections at all
+    let redundant_elves: Vec<ElfID> = (0 .. crew.len())
+        .filter(|elf| (crew[*elf].get_start() ..= crew[*elf].get_end()).all(|section| elves_on_section(crew, section) > 1))
+        .collect();
+
+    return (gaps, crowded_sections, redundant_elves);
+}
+
+/// Crews on few sections, some of their assignments the wrong way round.
+fn small_crew() -> impl Strategy<Value = Vec<ResponsabilityInterval>> {
+
+    return prop::collection::vec((0 ..= LAST_SECTION, 0 ..= LAST_SECTION), 0 .. 10)
+        .prop_map(|assignments| assignments.into_iter()
+            .map(|(start, end)| interval(start, end))
+            .collect());
+}
+
+#[test]
+fn example_report() {
+
+    let crew: Vec<ResponsabilityInterval> = vec![interval(2, 4), interval(6, 8), interval(2, 3), interval(4, 5), interval(7, 9)];
+    let report: CoverageReport = CoverageReport::new(&crew, interval(1, 10), 1);
+
+    assert_eq!(report.gaps.to_string(), "1-1, 10-10");
+    assert_eq!(report.crowded_sections.to_string(), "2-4, 7-8");
+    assert_eq!(report.redundant_elves, vec![0, 2]);
+    assert_eq!(report.to_string(), "Sections of the camp 1-10 nobody covers: 1-1, 10-10 (2 sections)\n\
+        Sections covered by more than 1 elves: 2-4, 7-8 (5 sections)\n\
+        Elves covered by the others: 2 of them, 0 (line 1), 2 (line 2)\n");
+}
+
+#[test]
+fn sections_up_to_the_last_boundary() {
+
+    let last: ResponsabilityBoundary = ResponsabilityBoundary::MAX;
+    let crew: Vec<ResponsabilityInterval> = vec![interval(last - 3, last), interval(last - 1, last), interval(last, last)];
+
+    assert_eq!(sections_covered_by_more_than(&crew, 1).get_intervals(), &[interval(last - 1, last)]);
+    assert_eq!(sections_covered_by_more_than(&crew, 2).get_intervals(), &[interval(last, last)]);
+    assert!(sections_covered_by_more_than(&crew, 3).is_empty());
+
+    let report: CoverageReport = CoverageReport::new(&crew, interval(last - 5, last), 2);
+    assert_eq!(report.gaps.get_intervals(), &[interval(last - 5, last - 4)]);
+    assert_eq!(report.redundant_elves, vec![1, 2]);
+}
+
+proptest! {
+
+    #[test]
+    fn report_matches_sections_one_by_one(crew in small_crew(), camp in (0 ..= LAST_SECTION, 0 ..= LAST_SECTION), max_elves in 0usize .. 4) {
+
+        let camp: ResponsabilityInterval = interval(camp.0, camp.1);
+        let report: CoverageReport = CoverageReport::new(&crew, camp, max_elves);
+        let (gaps, crowded_sections, redundant_elves) = model_report(&crew, camp, max_elves);
+
+        prop_assert_eq!(model_set(&report.gaps), gaps);
+        prop_assert_eq!(model_set(&report.crowded_sections), crowded_sections);
+        prop_assert_eq!(report.redundant_elves, redundant_elves);
+    }
+
+    #[test]
+    fn crowded_sections_by_threshold(crew in small_crew(), number_elves in 0usize .. 5) {
+
+        let crowded_sections: SectionModel = (0 ..= LAST_SECTION)
+            .filter(|section| elves_on_section(&crew, *section) > number_elves)
+            .collect();
+        prop_assert_eq!(model_set(&sections_covered_by_more_than(&crew, number_elves)), crowded_sections);
+    }
+}
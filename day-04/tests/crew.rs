@@ -0,0 +1,111 @@
+use proptest::prelude::*;
+
+use day_04::{ResponsabilityBoundary, ResponsabilityInterval, convert_input_to_correct_format};
+use day_04::crew::{CrewOverlaps, ElfID, develop_crew, find_busiest_section, find_overlapping_pairs, find_overlapping_pairs_reference};
+
+const EXAMPLE: [&str; 6] = ["2-4,6-8", "2-3,4-5", "5-7,7-9", "2-8,3-7", "6-6,4-6", "2-6,4-8"];
+
+fn interval(start: ResponsabilityBoundary, end: ResponsabilityBoundary) -> ResponsabilityInterval {
+    return ResponsabilityInterval::new(start, end);
+}
+
+/// Elves working on each section from 0 to `last_section`, counted one section at a time.
+fn count_elves_by_section(crew: &[ResponsabilityInterval], last_section: ResponsabilityBoundary) -> Vec<usize> {
+
+    return (0 ..= last_section)
+        .map(|section| crew.iter().filter(|interval| interval.get_start() <= section && section <= interval.get_end()).count())
+        .collect();
+}
+
+fn crew() -> impl Strategy<Value = Vec<ResponsabilityInterval>> {
+
+    return prop::collection::vec((0usize .. 100, 0usize .. 30), 0 .. 60)
+        .prop_map(|assignments| assignments.into_iter()
+            .map(|(start, length)| interval(start, start + length))
+            .collect());
+}
+
+/// Crews on few sections, some of their assignments the wrong way round.
+fn small_crew() -> impl Strategy<Value = Vec<ResponsabilityInterval>> {
+
+    return prop::collection::vec((0usize .. 20, 0usize .. 20), 0 .. 12)
+        .prop_map(|assignments| assignments.into_iter()
+            .map(|(start, end)| interval(start, end))
+            .collect());
+}
+
+#[test]
+fn example_crew() {
+
+    let crew: Vec<ResponsabilityInterval> = develop_crew(&convert_input_to_correct_format(EXAMPLE.map(|line| line.to_owned()).to_vec()));
+    let overlaps: CrewOverlaps = CrewOverlaps::new(&crew);
+
+    assert_eq!(overlaps.overlapping_pairs, find_overlapping_pairs_reference(&crew));
+    assert_eq!(overlaps.overlap_counts, vec![7, 8, 4, 7, 9, 5, 11, 11, 7, 9, 10, 10]);
+    assert_eq!(overlaps.overlap_counts.iter().sum::<usize>(), 2 * overlaps.overlapping_pairs.len());
+
+    // Section 6 has eight elves, and section 7 comes next with six
+    assert_eq!((overlaps.max_elves_on_section, overlaps.busiest_section), (8, Some(6)));
+}
+
+#[test]
+fn crews_without_sections() {
+
+    let empty: CrewOverlaps = CrewOverlaps::new(&[]);
+    assert_eq!((empty.overlapping_pairs, empty.overlap_counts, empty.max_elves_on_section, empty.busiest_section), (Vec::new(), Vec::new(), 0, None));
+
+    // Assignments the wrong way round hold no sections, for both versions
+    let reversed: Vec<ResponsabilityInterval> = vec![interval(5, 3), interval(3, 5), interval(4, 4), interval(9, 0)];
+    assert_eq!(find_overlapping_pairs(&reversed), vec![(1, 2)]);
+    assert_eq!(find_overlapping_pairs_reference(&reversed), vec![(1, 2)]);
+
+    let overlaps: CrewOverlaps = CrewOverlaps::new(&reversed);
+    assert_eq!(overlaps.overlap_counts, vec![0, 1, 1, 0]);
+    assert_eq!((overlaps.max_elves_on_section, overlaps.busiest_section), (2, Some(4)));
+    assert_eq!(find_busiest_section(&[interval(2, 1)]), None);
+}
+
+#[test]
+fn sections_up_to_the_last_boundary() {
+
+    let last: ResponsabilityBoundary = ResponsabilityBoundary::MAX;
+    let crew: Vec<ResponsabilityInterval> = vec![interval(last - 2, last), interval(last, last), interval(0, last), interval(last - 1, last - 1)];
+    let overlaps: CrewOverlaps = CrewOverlaps::new(&crew);
+
+    assert_eq!(overlaps.overlapping_pairs, vec![(0, 1), (0, 2), (0, 3), (1, 2), (2, 3)]);
+    assert_eq!(overlaps.overlap_counts, vec![3, 2, 3, 2]);
+    assert_eq!((overlaps.max_elves_on_section, overlaps.busiest_section), (3, Some(last - 1)));
+    assert_eq!(find_busiest_section(&[interval(last, last)]), Some((last, 1)));
+}
+
+proptest! {
+
+    #[test]
+    fn find_overlapping_pairs_matches_reference(crew in crew()) {
+        prop_assert_eq!(find_overlapping_pairs(&crew), find_overlapping_pairs_reference(&crew));
+    }
+
+    #[test]
+    fn find_overlapping_pairs_matches_reference_on_reversed_assignments(crew in small_crew()) {
+        prop_assert_eq!(find_overlapping_pairs(&crew), find_overlapping_pairs_reference(&crew));
+    }
+
+    #[test]
+    fn crew_overlaps_match_counts_by_section(crew in small_crew()) {
+
+        let overlaps: CrewOverlaps = CrewOverlaps::new(&crew);
+
+        // Two elves overlap when some section has them both
+        let shares_section = |first: ElfID, second: ElfID| (0 .. 20)
+            .any(|section| [first, second].iter().all(|elf| crew[*elf].get_start() <= section && section <= crew[*elf].get_end()));
+        let overlap_counts: Vec<usize> = (0 .. crew.len())
+            .map(|elf| (0 .. crew.len()).filter(|other| *other != elf && shares_section(elf, *other)).count())
+            .collect();
+        prop_assert_eq!(&overlaps.overlap_counts, &overlap_counts);
+
+        let elves_by_section: Vec<usize> = count_elves_by_section(&crew, 20);
+        let max_elves: usize = *elves_by_section.iter().max().unwrap();
+        prop_assert_eq!(overlaps.max_elves_on_section, max_elves);
+        prop_assert_eq!(overlaps.busiest_section, (max_elves > 0).then(|| elves_by_section.iter().position(|elves| *elves == max_elves).unwrap()));
+    }
+}
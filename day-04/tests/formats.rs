@@ -0,0 +1,53 @@
+use proptest::prelude::*;
+
+use day_04::{PairResponsabilities, ResponsabilityInterval};
+use day_04::formats::{pairs_from_csv, pairs_from_json, pairs_to_csv, pairs_to_json};
+
+fn develop_pairs() -> Vec<PairResponsabilities> {
+
+    return vec![
+        (ResponsabilityInterval::new(2, 4), ResponsabilityInterval::new(6, 8)),
+        (ResponsabilityInterval::new(5, 7), ResponsabilityInterval::new(7, 9))
+    ];
+}
+
+#[test]
+fn json_round_trip() {
+
+    let json: String = pairs_to_json(&develop_pairs()).unwrap();
+    assert_eq!(json, r#"[[{"start":2,"end":4},{"start":6,"end":8}],[{"start":5,"end":7},{"start":7,"end":9}]]"#);
+    assert_eq!(pairs_from_json(&json).unwrap(), develop_pairs());
+}
+
+#[test]
+fn csv_round_trip() {
+
+    let csv: String = pairs_to_csv(&develop_pairs()).unwrap();
+    assert_eq!(csv, "first_start,first_end,second_start,second_end\n2,4,6,8\n5,7,7,9\n");
+    assert_eq!(pairs_from_csv(&csv).unwrap(), develop_pairs());
+}
+
+#[test]
+fn malformed_records_are_errors() {
+
+    assert!(pairs_from_json(r#"[[{"start": 2, "end": 4}]]"#).is_err());
+    assert!(pairs_from_json(r#"[[{"start": -2, "end": 4}, {"start": 6, "end": 8}]]"#).is_err());
+    assert!(pairs_from_csv("first_start,first_end,second_start,second_end\n2,4,6\n").is_err());
+    assert!(pairs_from_csv("first_start,first_end,second_start,second_end\n2,4,six,8\n").is_err());
+}
+
+proptest! {
+
+    #[test]
+    fn any_pairs_round_trip(pairs in prop::collection::vec(
+        (any::<usize>(), any::<usize>(), any::<usize>(), any::<usize>()), 0 .. 16)) {
+
+        let pairs: Vec<PairResponsabilities> = pairs.into_iter()
+            .map(|(first_start, first_end, second_start, second_end)| (
+                ResponsabilityInterval::new(first_start, first_end), ResponsabilityInterval::new(second_start, second_end)))
+            .collect();
+
+        prop_assert_eq!(pairs_from_json(&pairs_to_json(&pairs).unwrap()).unwrap(), pairs.clone());
+        prop_assert_eq!(pairs_from_csv(&pairs_to_csv(&pairs).unwrap()).unwrap(), pairs);
+    }
+}
@@ -0,0 +1,149 @@
+use std::collections::BTreeSet;
+
+use day_04::{ResponsabilityBoundary, ResponsabilityInterval};
+use day_04::interval_set::{IntervalSemantics, IntervalSet};
+
+/// Sections up to this one are enough for every way two intervals can sit next to each other.
+const LAST_SECTION: ResponsabilityBoundary = 5;
+
+type SectionModel = BTreeSet<ResponsabilityBoundary>;
+
+/// Every interval with both ends up to `LAST_SECTION`, empty and reversed ones included.
+fn all_intervals() -> Vec<ResponsabilityInterval> {
+
+    return (0 ..= LAST_SECTION)
+        .flat_map(|start| (0 ..= LAST_SECTION).map(move |end| ResponsabilityInterval::new(start, end)))
+        .collect();
+}
+
+fn model_interval(interval: &ResponsabilityInterval, semantics: IntervalSemantics) -> SectionModel {
+
+    return match semantics {
+        IntervalSemantics::Closed => (interval.get_start() ..= interval.get_end()).collect(),
+        IntervalSemantics::HalfOpen => (interval.get_start() .. interval.get_end()).collect()
+    };
+}
+
+fn model_set(set: &IntervalSet) -> SectionModel {
+    return set.get_intervals().iter().flat_map(|interval| model_interval(interval, IntervalSemantics::Closed)).collect();
+}
+
+/// Every set made of up to two closed intervals, with the sections it should hold.
+fn all_sets() -> Vec<(IntervalSet, SectionModel)> {
+
+    let closed_intervals: Vec<ResponsabilityInterval> = all_intervals().into_iter()
+        .filter(|interval| interval.get_start() <= interval.get_end())
+        .collect();
+
+    let mut sets: Vec<(IntervalSet, SectionModel)> = vec![(IntervalSet::new(), SectionModel::new())];
+    for (position, first) in closed_intervals.iter().enumerate() {
+
+        sets.push((IntervalSet::from(*first), model_interval(first, IntervalSemantics::Closed)));
+        for second in closed_intervals[position + 1 ..].iter() {
+
+            let model: SectionModel = model_interval(first, IntervalSemantics::Closed).union(&model_interval(second, IntervalSemantics::Closed)).copied().collect();
+            sets.push((IntervalSet::from_intervals([*second, *first], IntervalSemantics::Closed), model));
+        }
+    }
+
+    return sets;
+}
+
+fn assert_normalized(set: &IntervalSet) {
+
+    for interval in set.get_intervals() {
+        assert!(interval.get_start() <= interval.get_end(), "{:?} is empty in {:?}", interval, set);
+    }
+    for pair in set.get_intervals().windows(2) {
+        assert!(pair[0].get_end() + 1 < pair[1].get_start(), "{:?} and {:?} overlap or touch in {:?}", pair[0], pair[1], set);
+    }
+}
+
+#[test]
+fn sets_hold_the_sections_of_their_intervals() {
+
+    for (set, model) in all_sets() {
+
+        assert_normalized(&set);
+        assert_eq!(model_set(&set), model);
+        assert_eq!(set.len(), model.len());
+        assert_eq!(set.is_empty(), model.is_empty());
+        for section in 0 ..= LAST_SECTION + 1 {
+            assert_eq!(set.contains_section(section), model.contains(&section), "section {} of {:?}", section, set);
+        }
+    }
+}
+
+#[test]
+fn both_semantics_read_and_write_the_same_sections() {
+
+    for semantics in [IntervalSemantics::Closed, IntervalSemantics::HalfOpen] {
+        for interval in all_intervals() {
+
+            let set: IntervalSet = IntervalSet::from_intervals([interval], semantics);
+            assert_eq!(model_set(&set), model_interval(&interval, semantics), "{:?} under {:?}", interval, semantics);
+
+            let written: SectionModel = set.to_intervals(semantics).iter()
+                .flat_map(|interval| model_interval(interval, semantics))
+                .collect();
+            assert_eq!(written, model_set(&set));
+        }
+    }
+}
+
+#[test]
+fn inserting_merges_overlapping_and_adjacent_intervals() {
+
+    for semantics in [IntervalSemantics::Closed, IntervalSemantics::HalfOpen] {
+        for first in all_intervals() {
+            for second in all_intervals() {
+
+                let mut set: IntervalSet = IntervalSet::from_intervals([first], semantics);
+                set.insert(second, semantics);
+
+                let model: SectionModel = model_interval(&first, semantics).union(&model_interval(&second, semantics)).copied().collect();
+                assert_normalized(&set);
+                assert_eq!(model_set(&set), model, "{:?} then {:?} under {:?}", first, second, semantics);
+            }
+        }
+    }
+}
+
+#[test]
+fn contains_interval_matches_sections() {
+
+    for (set, model) in all_sets() {
+        for semantics in [IntervalSemantics::Closed, IntervalSemantics::HalfOpen] {
+            for interval in all_intervals() {
+                assert_eq!(set.contains_interval(interval, semantics), model_interval(&interval, semantics).is_subset(&model),
+                    "{:?} in {:?} under {:?}", interval, set, semantics);
+            }
+        }
+    }
+}
+
+#[test]
+fn set_operations_match_sections() {
+
+    let sets: Vec<(IntervalSet, SectionModel)> = all_sets();
+    for (first, first_model) in sets.iter() {
+        for (second, second_model) in sets.iter() {
+
+            let union: IntervalSet = first.union(second);
+            let intersection: IntervalSet = first.intersection(second);
+            let difference: IntervalSet = first.difference(second);
+            let symmetric_difference: IntervalSet = first.symmetric_difference(second);
+
+            for result in [&union, &intersection, &difference, &symmetric_difference] {
+                assert_normalized(result);
+            }
+            assert_eq!(model_set(&union), first_model.union(second_model).copied().collect(), "{:?} | {:?}", first, second);
+            assert_eq!(model_set(&intersection), first_model.intersection(second_model).copied().collect(), "{:?} & {:?}", first, second);
+            assert_eq!(model_set(&difference), first_model.difference(second_model).copied().collect(), "{:?} - {:?}", first, second);
+            assert_eq!(model_set(&symmetric_difference), first_model.symmetric_difference(second_model).copied().collect(), "{:?} ^ {:?}", first, second);
+
+            assert_eq!(first.is_subset(second), first_model.is_subset(second_model));
+            assert_eq!(first.is_superset(second), first_model.is_superset(second_model));
+        }
+    }
+}
@@ -0,0 +1,128 @@
+//! Structured representations of the stacks of crates and the crane moves.
+//!
+//! In JSON the whole procedure is a single object, e.g.
+//! `{"stacks": [{"stack_id": 1, "containers": ["Z", "N"]}], "instructions": [{"from_stack": 2, "to_stack": 1, "count": 1}]}`.
+//! In CSV the stacks are rows of `stack_id,containers`, with the crates from the bottom to the top
+//! written as a string, and the moves are rows of `count,from_stack,to_stack`.
+
+use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+
+use crate::{ContainerID, Instruction, Stack, StackID};
+
+// =========================================== TYPE AND STRUCT DEFINITIONS ===========================================
+
+#[derive(Deserialize)]
+struct ProcedureRecord {
+    stacks: Vec<Stack>,
+    instructions: Vec<Instruction>
+}
+
+#[derive(Serialize)]
+struct ProcedureRecordRef<'a> {
+    stacks: Vec<&'a Stack>,
+    instructions: &'a [Instruction]
+}
+
+#[derive(Serialize, Deserialize)]
+struct StackRecord {
+    stack_id: StackID,
+    containers: String
+}
+
+#[derive(Serialize, Deserialize)]
+struct InstructionRecord {
+    count: usize,
+    from_stack: StackID,
+    to_stack: StackID
+}
+
+// =============================================== AUXILIARY FUNCTIONS ===============================================
+
+fn develop_stacks_mapped(stacks: Vec<Stack>) -> HashMap<StackID, Stack> {
+
+    return stacks.into_iter()
+        .map(|stack| (stack.stack_id, stack))
+        .collect();
+}
+
+fn develop_stacks_sorted(stacks: &HashMap<StackID, Stack>) -> Vec<&Stack> {
+
+    let mut stacks_sorted: Vec<&Stack> = stacks.values().collect();
+    stacks_sorted.sort_by_key(|stack| stack.stack_id);
+    return stacks_sorted;
+}
+
+/// Reads the stacks and the moves from a single JSON object.
+pub fn procedure_from_json(input: &str) -> serde_json::Result<(HashMap<StackID, Stack>, Vec<Instruction>)> {
+
+    let record: ProcedureRecord = serde_json::from_str(input)?;
+    return Ok((develop_stacks_mapped(record.stacks), record.instructions));
+}
+
+/// Writes the stacks, sorted by their identifier, and the moves as a single JSON object.
+pub fn procedure_to_json(stacks: &HashMap<StackID, Stack>, instructions: &[Instruction]) -> serde_json::Result<String> {
+
+    return serde_json::to_string(&ProcedureRecordRef {
+        stacks: develop_stacks_sorted(stacks),
+        instructions
+    });
+}
+
+/// Reads the stacks from CSV rows of `stack_id,containers`.
+pub fn stacks_from_csv(input: &str) -> csv::Result<HashMap<StackID, Stack>> {
+
+    let mut reader = csv::Reader::from_reader(input.as_bytes());
+    let stacks: Vec<Stack> = reader.deserialize()
+        .map(|record| record.map(|record: StackRecord| Stack {
+            stack_id: record.stack_id,
+            containers: record.containers.chars().collect::<Vec<ContainerID>>()
+        }))
+        .collect::<csv::Result<Vec<Stack>>>()?;
+
+    return Ok(develop_stacks_mapped(stacks));
+}
+
+/// Writes the stacks, sorted by their identifier, as CSV rows of `stack_id,containers`.
+pub fn stacks_to_csv(stacks: &HashMap<StackID, Stack>) -> csv::Result<String> {
+
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    for stack in develop_stacks_sorted(stacks) {
+        writer.serialize(StackRecord {
+            stack_id: stack.stack_id,
+            containers: stack.containers.iter().collect()
+        })?;
+    }
+
+    let output: Vec<u8> = writer.into_inner().map_err(|error| error.into_error())?;
+    return Ok(String::from_utf8(output).unwrap());
+}
+
+/// Reads the moves from CSV rows of `count,from_stack,to_stack`.
+pub fn instructions_from_csv(input: &str) -> csv::Result<Vec<Instruction>> {
+
+    let mut reader = csv::Reader::from_reader(input.as_bytes());
+    return reader.deserialize()
+        .map(|record| record.map(|record: InstructionRecord| Instruction {
+            from_stack: record.from_stack,
+            to_stack: record.to_stack,
+            count: record.count
+        }))
+        .collect();
+}
+
+/// Writes the moves as CSV rows of `count,from_stack,to_stack`.
+pub fn instructions_to_csv(instructions: &[Instruction]) -> csv::Result<String> {
+
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    for instruction in instructions.iter() {
+        writer.serialize(InstructionRecord {
+            count: instruction.count,
+            from_stack: instruction.from_stack,
+            to_stack: instruction.to_stack
+        })?;
+    }
+
+    let output: Vec<u8> = writer.into_inner().map_err(|error| error.into_error())?;
+    return Ok(String::from_utf8(output).unwrap());
+}
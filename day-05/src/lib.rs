@@ -1,19 +1,34 @@
+//! Supply stacks (Advent of Code 2022, day 05).
+//!
+//! The input draws the initial stacks of crates and then lists the moves performed by the crane.
+//! The CrateMover 9000 moves crates one at a time, while the CrateMover 9001 moves several at once.
+//! The stacks and moves can also be read from, and written to, JSON or CSV through [`formats`].
+//! Each crane move is recorded as a `tracing` span; with the `trace` feature the binary writes
+//! them as JSON lines through `aoc_common::trace`.
 
 use regex::Regex;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+pub mod formats;
+
 // =========================================== TYPE AND STRUCT DEFINITIONS ===========================================
 
-type StackID = u32;
-type ContainerID = char;
+/// Identifier of a stack, as numbered in the input.
+pub type StackID = u32;
+/// A crate, identified by its letter.
+pub type ContainerID = char;
 
+/// A crane move: take `count` crates from one stack and put them on another.
+#[derive(Serialize, Deserialize)]
 pub struct Instruction {
     from_stack: StackID,
     to_stack: StackID,
     count: usize,
 }
 
-#[derive(Clone, Debug)]
+/// A stack of crates, from the bottom to the top.
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Stack {
     pub stack_id: StackID,
     pub containers: Vec<ContainerID>,
@@ -21,7 +36,7 @@ pub struct Stack {
 
 // =============================================== AUXILIARY FUNCTIONS ===============================================
 
-fn parse_stacks(line: &String) -> Vec<Option<ContainerID>> {
+fn parse_stacks(line: &str) -> Vec<Option<ContainerID>> {
 
     return line.chars().collect::<Vec<char>>()
         .chunks(4)
@@ -38,7 +53,7 @@ fn parse_stacks(line: &String) -> Vec<Option<ContainerID>> {
 
 }
 
-fn parse_stack_ids(line: &String) -> Vec<StackID> {
+fn parse_stack_ids(line: &str) -> Vec<StackID> {
 
     return line.chars().collect::<Vec<char>>()
         .chunks(4)
@@ -48,9 +63,9 @@ fn parse_stack_ids(line: &String) -> Vec<StackID> {
 
 }
 
-fn parse_instructions(line: &String, regex_capture_instruction: &Regex) -> Instruction  {
+fn parse_instructions(line: &str, regex_capture_instruction: &Regex) -> Instruction  {
 
-    let captures = regex_capture_instruction.captures(&line).unwrap();
+    let captures = regex_capture_instruction.captures(line).unwrap();
 
     let count: usize = captures.get(1).unwrap().as_str().parse().unwrap();
     let from_stack: StackID = captures.get(2).unwrap().as_str().parse().unwrap();
@@ -59,6 +74,7 @@ fn parse_instructions(line: &String, regex_capture_instruction: &Regex) -> Instr
     return Instruction { from_stack, to_stack, count };
 }
 
+/// Parses the drawing of the stacks and the list of moves.
 pub fn parse_input(input: &Vec<String>) -> (HashMap<StackID, Stack>, Vec<Instruction>) {
 
     let regex_stack_containers: Regex = Regex::new(r"^(\[[A-Z]\]|\s)+$").unwrap();
@@ -82,9 +98,9 @@ pub fn parse_input(input: &Vec<String>) -> (HashMap<StackID, Stack>, Vec<Instruc
 
     for containers_line in stack_containers.into_iter().rev() {
 
-        for (stack, container) in stacks.iter_mut().zip(containers_line.into_iter()) {
-            if container.is_some() {
-                stack.containers.push(container.unwrap());
+        for (stack, container) in stacks.iter_mut().zip(containers_line) {
+            if let Some(container) = container {
+                stack.containers.push(container);
             }
         }
 
@@ -97,8 +113,12 @@ pub fn parse_input(input: &Vec<String>) -> (HashMap<StackID, Stack>, Vec<Instruc
     return (stacks_mapped, instructions);
 }
 
+/// Applies a move with the CrateMover 9000, which lifts one crate at a time.
 pub fn make_iteration_9000(stacks: &mut HashMap<StackID, Stack>, instruction: &Instruction) {
 
+    let _span = tracing::debug_span!("make_iteration_9000",
+        count = instruction.count, from_stack = instruction.from_stack, to_stack = instruction.to_stack).entered();
+
     let from_stack: &mut Stack = stacks.get_mut(&instruction.from_stack).unwrap();
     let mut containers_removed: Vec<ContainerID> = Vec::new();
 
@@ -108,13 +128,19 @@ pub fn make_iteration_9000(stacks: &mut HashMap<StackID, Stack>, instruction: &I
         containers_removed.push(container);
     }
 
+    tracing::trace!(containers = %containers_removed.iter().collect::<String>(), "containers moved");
+
     let to_stack: &mut Stack = stacks.get_mut(&instruction.to_stack).unwrap();
     to_stack.containers.append(&mut containers_removed);
 
 }
 
+/// Applies a move with the CrateMover 9001, which lifts all the crates of the move at once.
 pub fn make_iteration_9001(stacks: &mut HashMap<StackID, Stack>, instruction: &Instruction) {
 
+    let _span = tracing::debug_span!("make_iteration_9001",
+        count = instruction.count, from_stack = instruction.from_stack, to_stack = instruction.to_stack).entered();
+
     let from_stack: &mut Stack = stacks.get_mut(&instruction.from_stack).unwrap();
     let mut containers_removed: Vec<ContainerID> = Vec::new();
 
@@ -125,6 +151,8 @@ pub fn make_iteration_9001(stacks: &mut HashMap<StackID, Stack>, instruction: &I
     }
 
     containers_removed = containers_removed.into_iter().rev().collect();
+    tracing::trace!(containers = %containers_removed.iter().collect::<String>(), "containers moved");
+
     let to_stack: &mut Stack = stacks.get_mut(&instruction.to_stack).unwrap();
     to_stack.containers.append(&mut containers_removed);
 }
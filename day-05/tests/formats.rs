@@ -0,0 +1,53 @@
+use std::collections::HashMap;
+
+use day_05::{Instruction, Stack, StackID};
+use day_05::formats::{instructions_from_csv, instructions_to_csv, procedure_from_json, procedure_to_json, stacks_from_csv, stacks_to_csv};
+
+const PROCEDURE: &str = r#"{"stacks":[{"stack_id":1,"containers":["Z","N"]},{"stack_id":2,"containers":["M","C","D"]},{"stack_id":3,"containers":["P"]},{"stack_id":4,"containers":[]}],"instructions":[{"from_stack":2,"to_stack":1,"count":1},{"from_stack":1,"to_stack":3,"count":3}]}"#;
+
+fn develop_containers(stacks: &HashMap<StackID, Stack>) -> Vec<(StackID, String)> {
+
+    let mut containers: Vec<(StackID, String)> = stacks.values()
+        .map(|stack| (stack.stack_id, stack.containers.iter().collect()))
+        .collect();
+    containers.sort();
+    return containers;
+}
+
+#[test]
+fn json_round_trip() {
+
+    let (stacks, instructions): (HashMap<StackID, Stack>, Vec<Instruction>) = procedure_from_json(PROCEDURE).unwrap();
+    assert_eq!(develop_containers(&stacks), vec![(1, "ZN".to_owned()), (2, "MCD".to_owned()), (3, "P".to_owned()), (4, String::new())]);
+    assert_eq!(instructions.len(), 2);
+
+    assert_eq!(procedure_to_json(&stacks, &instructions).unwrap(), PROCEDURE);
+}
+
+#[test]
+fn csv_round_trip() {
+
+    let (stacks, instructions): (HashMap<StackID, Stack>, Vec<Instruction>) = procedure_from_json(PROCEDURE).unwrap();
+
+    let stacks_csv: String = stacks_to_csv(&stacks).unwrap();
+    assert_eq!(stacks_csv, "stack_id,containers\n1,ZN\n2,MCD\n3,P\n4,\n");
+    let read_stacks: HashMap<StackID, Stack> = stacks_from_csv(&stacks_csv).unwrap();
+    assert_eq!(develop_containers(&read_stacks), develop_containers(&stacks));
+
+    let instructions_csv: String = instructions_to_csv(&instructions).unwrap();
+    assert_eq!(instructions_csv, "count,from_stack,to_stack\n1,2,1\n3,1,3\n");
+    let read_instructions: Vec<Instruction> = instructions_from_csv(&instructions_csv).unwrap();
+    assert_eq!(instructions_to_csv(&read_instructions).unwrap(), instructions_csv);
+
+    assert_eq!(procedure_to_json(&read_stacks, &read_instructions).unwrap(), PROCEDURE);
+}
+
+#[test]
+fn malformed_records_are_errors() {
+
+    assert!(procedure_from_json(r#"{"stacks": []}"#).is_err());
+    assert!(procedure_from_json(r#"{"stacks": [{"stack_id": 1, "containers": ["ZN"]}], "instructions": []}"#).is_err());
+    assert!(stacks_from_csv("stack_id,containers\none,ZN\n").is_err());
+    assert!(instructions_from_csv("count,from_stack,to_stack\n1,2\n").is_err());
+    assert!(instructions_from_csv("count,from_stack,to_stack\n-1,2,1\n").is_err());
+}
@@ -0,0 +1,82 @@
+//! Structured representations of the device.
+//!
+//! A device is a record with its `buffer` written as a string and, optionally, the
+//! `start_transmission_marker_size` and `start_message_marker_size` (4 and 14 when left out),
+//! e.g. `{"buffer": "mjqjpqmgbljsphdztnvjfqwrcgsmlb"}` in JSON. In CSV every row is a device.
+
+use serde::{Deserialize, Serialize};
+
+use crate::Device;
+
+// =========================================== TYPE AND STRUCT DEFINITIONS ===========================================
+
+#[derive(Serialize, Deserialize)]
+struct DeviceRecord {
+    buffer: String,
+    #[serde(default = "default_start_transmission_marker_size")]
+    start_transmission_marker_size: usize,
+    #[serde(default = "default_start_message_marker_size")]
+    start_message_marker_size: usize
+}
+
+// ================================================= IMPLEMENTATIONS =================================================
+
+impl From<DeviceRecord> for Device {
+
+    fn from(record: DeviceRecord) -> Device {
+        Device {
+            start_transmission_marker_size: record.start_transmission_marker_size,
+            start_message_marker_size: record.start_message_marker_size,
+            buffer: record.buffer.chars().collect()
+        }
+    }
+}
+
+impl From<&Device> for DeviceRecord {
+
+    fn from(device: &Device) -> DeviceRecord {
+        DeviceRecord {
+            buffer: device.buffer.iter().collect(),
+            start_transmission_marker_size: device.start_transmission_marker_size,
+            start_message_marker_size: device.start_message_marker_size
+        }
+    }
+}
+
+// =============================================== AUXILIARY FUNCTIONS ===============================================
+
+fn default_start_transmission_marker_size() -> usize { 4 }
+fn default_start_message_marker_size() -> usize { 14 }
+
+/// Reads a device from a JSON record.
+pub fn device_from_json(input: &str) -> serde_json::Result<Device> {
+
+    let record: DeviceRecord = serde_json::from_str(input)?;
+    return Ok(Device::from(record));
+}
+
+/// Writes a device as a JSON record.
+pub fn device_to_json(device: &Device) -> serde_json::Result<String> {
+    return serde_json::to_string(&DeviceRecord::from(device));
+}
+
+/// Reads one device per CSV row of `buffer,start_transmission_marker_size,start_message_marker_size`.
+pub fn devices_from_csv(input: &str) -> csv::Result<Vec<Device>> {
+
+    let mut reader = csv::Reader::from_reader(input.as_bytes());
+    return reader.deserialize()
+        .map(|record| record.map(|record: DeviceRecord| Device::from(record)))
+        .collect();
+}
+
+/// Writes one device per CSV row of `buffer,start_transmission_marker_size,start_message_marker_size`.
+pub fn devices_to_csv(devices: &[Device]) -> csv::Result<String> {
+
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    for device in devices.iter() {
+        writer.serialize(DeviceRecord::from(device))?;
+    }
+
+    let output: Vec<u8> = writer.into_inner().map_err(|error| error.into_error())?;
+    return Ok(String::from_utf8(output).unwrap());
+}
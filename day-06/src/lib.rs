@@ -1,16 +1,26 @@
+//! Tuning trouble (Advent of Code 2022, day 06).
+//!
+//! The device receives a stream of characters, and a marker is the end of the first window of
+//! distinct characters: 4 for the start of a transmission and 14 for the start of a message.
+//! The device can also be read from, and written to, JSON or CSV through [`formats`].
 
-use std::collections::{VecDeque, HashSet};
+use std::collections::{VecDeque, HashSet, HashMap};
+
+pub mod formats;
 
 // =========================================== TYPE AND STRUCT DEFINITIONS ===========================================
 
-type BufferElem = char;
-type Buffer = Vec<char>;
+/// A character received by the device.
+pub type BufferElem = char;
+/// The full datastream received by the device.
+pub type Buffer = Vec<char>;
 
 struct BufferWindow<'a> {
     capacity: usize,
     vector: VecDeque<&'a BufferElem>,
 }
 
+/// Communication device holding the received datastream.
 pub struct Device {
     start_transmission_marker_size: usize,
     start_message_marker_size: usize,
@@ -43,13 +53,14 @@ impl<'a> BufferWindow<'a> {
     fn develop_set(&self) -> HashSet<&'a BufferElem> {
 
         return self.vector.iter()
-            .map(|elem| *elem)
+            .copied()
             .collect();
     }
 }
 
 impl Device {
 
+    /// Creates a device with the usual marker sizes of 4 and 14.
     pub fn new(buffer: Buffer) -> Device {
         Device {
             start_transmission_marker_size: 4,
@@ -58,12 +69,38 @@ impl Device {
         }
     }
 
-    fn find_marker(&self, size: usize) -> Option<usize> {
+    /// Number of characters read up to the end of the first window of `size` distinct characters.
+    ///
+    /// Runs in a single pass by remembering where each character was last seen, so the window of
+    /// distinct characters only ever moves forward.
+    pub fn find_marker(&self, size: usize) -> Option<usize> {
+
+        let mut last_seen: HashMap<&BufferElem, usize> = HashMap::new();
+        let mut window_start: usize = 0;
+
+        for (buffer_index, buffer_elem) in self.buffer.iter().enumerate() {
+
+            if let Some(&last_index) = last_seen.get(buffer_elem) {
+                if last_index >= window_start { window_start = last_index + 1 }
+            }
+
+            last_seen.insert(buffer_elem, buffer_index);
+            if buffer_index + 1 - window_start >= size {
+                return Some(buffer_index + 1);
+            }
+        }
+
+        return None;
+    }
+
+    /// Straightforward version of [`Device::find_marker`], which rebuilds the set of the last
+    /// `size` characters at every position. Kept as a reference to check the faster version against.
+    pub fn find_marker_reference(&self, size: usize) -> Option<usize> {
 
         let mut buffer_window: BufferWindow = BufferWindow::new(size);
         for (buffer_index, buffer_elem) in self.buffer.iter().enumerate() {
 
-            buffer_window.push_elem(&buffer_elem);
+            buffer_window.push_elem(buffer_elem);
             if buffer_window.develop_set().len() == size {
                 return Some(buffer_index + 1);
             }
@@ -73,10 +110,12 @@ impl Device {
         return None;
     }
 
+    /// Number of characters read up to the end of the start-of-packet marker.
     pub fn find_marker_start_transmission(&self) -> Option<usize> {
         return self.find_marker(self.start_transmission_marker_size);        
     }
 
+    /// Number of characters read up to the end of the start-of-message marker.
     pub fn find_marker_start_message(&self) -> Option<usize> {
         return self.find_marker(self.start_message_marker_size);        
     }
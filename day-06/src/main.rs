@@ -1,6 +1,7 @@
 
 mod read;
-mod lib;
+
+use day_06::Device;
 
 fn main() {
 
@@ -8,7 +9,7 @@ fn main() {
         .pop().unwrap()
         .chars().collect();
     
-    let device: lib::Device = lib::Device::new(input);
+    let device: Device = Device::new(input);
 
     // Part 1
 
@@ -0,0 +1,42 @@
+use day_06::Device;
+use day_06::formats::{device_from_json, device_to_json, devices_from_csv, devices_to_csv};
+
+const BUFFER: &str = "mjqjpqmgbljsphdztnvjfqwrcgsmlb";
+
+#[test]
+fn json_round_trip() {
+
+    let device: Device = Device::new(BUFFER.chars().collect());
+    let json: String = device_to_json(&device).unwrap();
+    assert_eq!(json, r#"{"buffer":"mjqjpqmgbljsphdztnvjfqwrcgsmlb","start_transmission_marker_size":4,"start_message_marker_size":14}"#);
+
+    let read_device: Device = device_from_json(&json).unwrap();
+    assert_eq!(device_to_json(&read_device).unwrap(), json);
+    assert_eq!(read_device.find_marker_start_transmission(), Some(7));
+    assert_eq!(read_device.find_marker_start_message(), Some(19));
+
+    // The marker sizes can be left out, or changed
+    assert_eq!(device_to_json(&device_from_json(&format!(r#"{{"buffer": "{}"}}"#, BUFFER)).unwrap()).unwrap(), json);
+    let custom: Device = device_from_json(r#"{"buffer": "abcab", "start_transmission_marker_size": 3, "start_message_marker_size": 2}"#).unwrap();
+    assert_eq!((custom.find_marker_start_transmission(), custom.find_marker_start_message()), (Some(3), Some(2)));
+}
+
+#[test]
+fn csv_round_trip() {
+
+    let devices: Vec<Device> = vec![Device::new(BUFFER.chars().collect()), Device::new("a,\"b".chars().collect())];
+    let csv: String = devices_to_csv(&devices).unwrap();
+    assert_eq!(csv, "buffer,start_transmission_marker_size,start_message_marker_size\nmjqjpqmgbljsphdztnvjfqwrcgsmlb,4,14\n\"a,\"\"b\",4,14\n");
+
+    let read_devices: Vec<Device> = devices_from_csv(&csv).unwrap();
+    assert_eq!(devices_to_csv(&read_devices).unwrap(), csv);
+    assert_eq!(read_devices[0].find_marker_start_message(), Some(19));
+}
+
+#[test]
+fn malformed_records_are_errors() {
+
+    assert!(device_from_json(r#"{"start_transmission_marker_size": 4}"#).is_err());
+    assert!(device_from_json(r#"{"buffer": "abc", "start_message_marker_size": -1}"#).is_err());
+    assert!(devices_from_csv("buffer,start_transmission_marker_size,start_message_marker_size\nabc,four,14\n").is_err());
+}
@@ -0,0 +1,20 @@
+use proptest::prelude::*;
+
+use day_06::Device;
+
+proptest! {
+
+    #[test]
+    fn find_marker_matches_reference(buffer in prop::collection::vec(prop::char::range('a', 'h'), 0..200), size in 0usize..16) {
+
+        let device: Device = Device::new(buffer);
+        prop_assert_eq!(device.find_marker(size), device.find_marker_reference(size));
+    }
+
+    #[test]
+    fn find_marker_matches_reference_on_wide_alphabets(buffer in prop::collection::vec(any::<char>(), 0..200), size in 0usize..32) {
+
+        let device: Device = Device::new(buffer);
+        prop_assert_eq!(device.find_marker(size), device.find_marker_reference(size));
+    }
+}
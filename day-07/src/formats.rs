@@ -0,0 +1,213 @@
+//! Structured representations of the directory tree.
+//!
+//! In JSON the tree is nested, with folders holding their `children` and files their `size`, e.g.
+//! `{"name": "/", "children": [{"name": "a", "children": []}, {"name": "b.txt", "size": 14848514}]}`.
+//! In CSV every row is an object below the root with its `path` and, for files only, its `size`.
+
+use std::collections::HashMap;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{DirectoryObject, DirectoryObjectSize, File, FileSystem, Folder};
+
+// =========================================== TYPE AND STRUCT DEFINITIONS ===========================================
+
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum DirectoryRecord {
+    File { name: String, size: DirectoryObjectSize },
+    Folder { name: String, children: Vec<DirectoryRecord> }
+}
+
+#[derive(Serialize, Deserialize)]
+struct PathRecord {
+    path: String,
+    size: Option<DirectoryObjectSize>
+}
+
+// ================================================= IMPLEMENTATIONS =================================================
+
+impl Serialize for FileSystem {
+
+    /// Serializes the directory tree as the list of its objects, as in the CSV representation.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+
+        let mut records: Vec<PathRecord> = Vec::new();
+        develop_path_records(self, "", &mut records);
+        return records.serialize(serializer);
+    }
+}
+
+impl<'de> Deserialize<'de> for FileSystem {
+
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<FileSystem, D::Error> {
+
+        let records: Vec<PathRecord> = Vec::deserialize(deserializer)?;
+        return develop_filesystem(records).map_err(serde::de::Error::custom);
+    }
+}
+
+// =============================================== AUXILIARY FUNCTIONS ===============================================
+
+fn develop_empty_directory() -> HashMap<String, Box<dyn DirectoryObject>> {
+
+    let mut directory: HashMap<String, Box<dyn DirectoryObject>> = HashMap::new();
+    directory.insert("".to_owned(), Box::new(Folder {
+        path: "".to_owned(),
+        _parent_path: "".to_owned(),
+        child_paths: Vec::new()
+    }));
+
+    return directory;
+}
+
+/// Adds the object at `path` to the directory, along with any missing folder above it, or tells
+/// why it cannot be added when one of the objects above it is a file.
+fn attach_directory_object(directory: &mut HashMap<String, Box<dyn DirectoryObject>>, path: &[String], size: Option<DirectoryObjectSize>) -> Result<(), String> {
+
+    let (_, parent_path) = path.split_last().unwrap();
+    if ! directory.contains_key(&parent_path.join("/")) {
+        attach_directory_object(directory, parent_path, None)?;
+    }
+
+    let new_path: String = path.join("/");
+    if directory.contains_key(&new_path) { return Ok(()) }
+
+    let parent_folder: &mut Box<dyn DirectoryObject> = directory.get_mut(&parent_path.join("/")).unwrap();
+    if ! parent_folder.is_folder() {
+        return Err(format!("'{}' cannot be inside '{}', which is a file", new_path, parent_path.join("/")));
+    }
+
+    let mut child_paths: Vec<String> = parent_folder.get_child_paths().to_vec();
+    child_paths.push(new_path.clone());
+    parent_folder.set_child_paths(child_paths);
+
+    let new_directory_object: Box<dyn DirectoryObject> = match size {
+        Some(size) => Box::new(File { path: new_path.clone(), size, _parent_path: parent_path.join("/") }),
+        None => Box::new(Folder { path: new_path.clone(), _parent_path: parent_path.join("/"), child_paths: Vec::new() })
+    };
+
+    directory.insert(new_path, new_directory_object);
+    return Ok(());
+}
+
+fn attach_directory_record(directory: &mut HashMap<String, Box<dyn DirectoryObject>>, parent_path: &[String], record: DirectoryRecord) -> Result<(), String> {
+
+    match record {
+        DirectoryRecord::File { name, size } => {
+
+            let path: Vec<String> = [parent_path, &[name]].concat();
+            attach_directory_object(directory, &path, Some(size))?;
+        },
+
+        DirectoryRecord::Folder { name, children } => {
+
+            let path: Vec<String> = [parent_path, &[name]].concat();
+            attach_directory_object(directory, &path, None)?;
+            for child in children { attach_directory_record(directory, &path, child)? }
+        }
+    }
+
+    return Ok(());
+}
+
+fn develop_directory_record(filesystem: &FileSystem, path: &str, name: String) -> DirectoryRecord {
+
+    let directory_object: &dyn DirectoryObject = filesystem.directory.get(path).unwrap().as_ref();
+    if ! directory_object.is_folder() {
+        return DirectoryRecord::File { name, size: directory_object.get_directory_object_size(&filesystem.directory) };
+    }
+
+    return DirectoryRecord::Folder {
+        name,
+        children: directory_object.get_child_paths().iter()
+            .map(|child_path| develop_directory_record(filesystem, child_path, develop_name(child_path)))
+            .collect()
+    };
+}
+
+fn develop_path_records(filesystem: &FileSystem, path: &str, records: &mut Vec<PathRecord>) {
+
+    for child_path in filesystem.directory.get(path).unwrap().get_child_paths() {
+
+        let child_object: &dyn DirectoryObject = filesystem.directory.get(child_path).unwrap().as_ref();
+        if child_object.is_folder() {
+
+            records.push(PathRecord { path: child_path.clone(), size: None });
+            develop_path_records(filesystem, child_path, records);
+
+        } else {
+            records.push(PathRecord { path: child_path.clone(), size: Some(child_object.get_directory_object_size(&filesystem.directory)) });
+        }
+    }
+}
+
+fn develop_filesystem(records: Vec<PathRecord>) -> Result<FileSystem, String> {
+
+    let mut directory: HashMap<String, Box<dyn DirectoryObject>> = develop_empty_directory();
+    for record in records {
+
+        let path: Vec<String> = record.path.split('/')
+            .map(|split| split.to_owned())
+            .collect();
+
+        attach_directory_object(&mut directory, &path, record.size)?;
+    }
+
+    return Ok(FileSystem { _commands: Vec::new(), directory });
+}
+
+fn develop_name(path: &str) -> String {
+    return path.rsplit('/').next().unwrap().to_owned();
+}
+
+/// Reads the directory tree from a nested JSON record, whose root is the `/` folder.
+pub fn filesystem_from_json(input: &str) -> serde_json::Result<FileSystem> {
+
+    let mut directory: HashMap<String, Box<dyn DirectoryObject>> = develop_empty_directory();
+    match serde_json::from_str(input)? {
+        DirectoryRecord::Folder { children, .. } => {
+            for child in children {
+                attach_directory_record(&mut directory, &[], child).map_err(serde::de::Error::custom)?;
+            }
+        },
+        DirectoryRecord::File { .. } => {
+            return Err(serde::de::Error::custom("the root of the directory tree must be a folder"));
+        }
+    }
+
+    return Ok(FileSystem { _commands: Vec::new(), directory });
+}
+
+/// Writes the directory tree as a nested JSON record, whose root is the `/` folder.
+pub fn filesystem_to_json(filesystem: &FileSystem) -> serde_json::Result<String> {
+    return serde_json::to_string(&develop_directory_record(filesystem, "", "/".to_owned()));
+}
+
+/// Reads the directory tree from CSV rows of `path,size`, leaving `size` empty for folders.
+///
+/// Folders that only show up as part of a longer path are created along the way, and a path going
+/// through a file is an error.
+pub fn filesystem_from_csv(input: &str) -> csv::Result<FileSystem> {
+
+    let mut reader = csv::Reader::from_reader(input.as_bytes());
+    let records: Vec<PathRecord> = reader.deserialize()
+        .collect::<csv::Result<Vec<PathRecord>>>()?;
+
+    return develop_filesystem(records)
+        .map_err(|message| csv::Error::from(std::io::Error::new(std::io::ErrorKind::InvalidData, message)));
+}
+
+/// Writes the directory tree as CSV rows of `path,size`, with each folder before its contents.
+pub fn filesystem_to_csv(filesystem: &FileSystem) -> csv::Result<String> {
+
+    let mut records: Vec<PathRecord> = Vec::new();
+    develop_path_records(filesystem, "", &mut records);
+
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    for record in records.iter() {
+        writer.serialize(record)?;
+    }
+
+    let output: Vec<u8> = writer.into_inner().map_err(|error| error.into_error())?;
+    return Ok(String::from_utf8(output).unwrap());
+}
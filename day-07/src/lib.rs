@@ -1,8 +1,20 @@
+//! No space left on device (Advent of Code 2022, day 07).
+//!
+//! The input is a terminal session of `cd` and `ls` commands, from which the directory tree of
+//! the device is rebuilt to find which directories are worth deleting.
+//! The directory tree can also be read from, and written to, JSON or CSV through [`formats`],
+//! and kept between runs through `aoc_common::cache`.
+//! Each `cd` and `ls` replayed is recorded as a `tracing` span; with the `trace` feature the
+//! binary writes them as JSON lines through `aoc_common::trace`.
+
 use std::collections::{HashMap, HashSet};
 
+pub mod formats;
+
 // =========================================== TYPE AND STRUCT DEFINITIONS ===========================================
 
-type DirectoryObjectSize = usize;
+/// Size of a file, or total size of the files inside a folder.
+pub type DirectoryObjectSize = usize;
 
 enum CommandFunction {
     ChangeDirectory,
@@ -32,16 +44,19 @@ struct Folder {
 
 trait DirectoryObject {
 
+    #[allow(dead_code)]
     fn is_file(&self) -> bool;
     fn is_folder(&self) -> bool;
 
     fn get_path(&self) -> String;
 
     fn set_child_paths(&mut self, paths: Vec<String>);
+    fn get_child_paths(&self) -> &[String];
 
     fn get_directory_object_size(&self, directory: &HashMap<String, Box<dyn DirectoryObject>>) -> DirectoryObjectSize;
 }
 
+/// Directory tree of the device, rebuilt from the commands of the terminal session.
 pub struct FileSystem {
 
     _commands: Vec<Command>,
@@ -63,6 +78,7 @@ impl DirectoryObject for File {
 
     fn get_path(&self) -> String { self.path.clone() }
     fn set_child_paths(&mut self, _: Vec<String>) { panic!("🚨 Child paths cannot be added to a file!") }
+    fn get_child_paths(&self) -> &[String] { &[] }
 
     fn get_directory_object_size(&self, _directory: &HashMap<String, Box<dyn DirectoryObject>>) -> DirectoryObjectSize {
         return self.size;
@@ -76,6 +92,7 @@ impl DirectoryObject for Folder {
 
     fn get_path(&self) -> String { self.path.clone() }
     fn set_child_paths(&mut self, paths: Vec<String>) { self.child_paths = paths }
+    fn get_child_paths(&self) -> &[String] { &self.child_paths }
 
     fn get_directory_object_size(&self, directory: &HashMap<String, Box<dyn DirectoryObject>>) -> DirectoryObjectSize {
         return self.child_paths.iter()
@@ -87,7 +104,8 @@ impl DirectoryObject for Folder {
 
 impl FileSystem {
 
-    pub fn new(lines: &Vec<String>) -> FileSystem {
+    /// Replays the terminal session in `lines` to rebuild the directory tree.
+    pub fn new(lines: &[String]) -> FileSystem {
 
         let commands: Vec<Command> = FileSystem::develop_commands(lines);
         let directory: HashMap<String, Box<dyn DirectoryObject>> = FileSystem::develop_directory(&commands);
@@ -95,7 +113,7 @@ impl FileSystem {
         return FileSystem { _commands: commands, directory };
     }
 
-    fn develop_commands(lines: &Vec<String>) ->  Vec<Command> {
+    fn develop_commands(lines: &[String]) ->  Vec<Command> {
 
         let mut commands: Vec<Command> = Vec::new();
         let mut current_command: Option<Command> = None;
@@ -105,7 +123,7 @@ impl FileSystem {
             let new_command: bool = line.starts_with('$');
             if new_command {
 
-                if current_command.is_some() { commands.push(current_command.unwrap()) }
+                if let Some(current_command) = current_command { commands.push(current_command) }
                 let mut command_split: Vec<String> = line.split_whitespace()
                     .map(|split| split.to_owned())
                     .collect();
@@ -125,21 +143,18 @@ impl FileSystem {
 
             } else {
 
-                match current_command.as_mut() {
-                    Some(current_command_some) => {
-                        current_command_some.outputs.push(line.to_string());
-                    },
-                    None => (),
+                if let Some(current_command_some) = current_command.as_mut() {
+                    current_command_some.outputs.push(line.to_string());
                 }
             }
         }
 
-        if current_command.is_some() { commands.push(current_command.unwrap()) }
+        if let Some(current_command) = current_command { commands.push(current_command) }
         return commands;
 
     }
 
-    fn develop_directory(commands: &Vec<Command>) -> HashMap<String, Box<dyn DirectoryObject>> {
+    fn develop_directory(commands: &[Command]) -> HashMap<String, Box<dyn DirectoryObject>> {
 
         let mut current_path: Vec<String> = Vec::new();
         let mut directory: HashMap<String, Box<dyn DirectoryObject>> = HashMap::new();
@@ -155,15 +170,21 @@ impl FileSystem {
             match command.function {
                 CommandFunction::ChangeDirectory => {
 
-                    match command.arguments.get(0).unwrap().as_str() {
+                    let _span = tracing::debug_span!("cd", argument = %command.arguments.first().unwrap()).entered();
+
+                    match command.arguments.first().unwrap().as_str() {
                         "/" => { current_path = Vec::new(); },
                         ".." => { current_path.pop(); },
                         directory_sub_folder => { current_path.push(directory_sub_folder.to_owned()); }
                     }
+
+                    tracing::trace!(path = %current_path.join("/"), "directory changed");
                 },
 
                 CommandFunction::ListDirectory => {
 
+                    let _span = tracing::debug_span!("ls", path = %current_path.join("/")).entered();
+
                     let mut new_directory_objects: Vec<Box<dyn DirectoryObject>> = Vec::new();
                     for directory_info in command.outputs.iter() {
 
@@ -171,7 +192,7 @@ impl FileSystem {
                                 .map(|info_split| info_split.to_owned())
                                 .collect();
 
-                            if directory_info_split.get(0).unwrap() == "dir" {
+                            if directory_info_split.first().unwrap() == "dir" {
 
                                 let directory_name: String = directory_info_split.get(1).unwrap().to_string();
                                 
@@ -187,7 +208,7 @@ impl FileSystem {
 
                             } else {
 
-                                let file_size: DirectoryObjectSize = directory_info_split.get(0).unwrap().parse().unwrap();
+                                let file_size: DirectoryObjectSize = directory_info_split.first().unwrap().parse().unwrap();
                                 let file_name: String = directory_info_split.get(1).unwrap().to_owned();
 
                                 let mut new_path: Vec<String> = current_path.clone();
@@ -201,6 +222,8 @@ impl FileSystem {
                             }
                     }
 
+                    tracing::trace!(objects = new_directory_objects.len(), "directory listed");
+
                     let current_directory: &mut Box<dyn DirectoryObject> = directory.get_mut(&current_path.join("/")).unwrap();
                     current_directory.set_child_paths(new_directory_objects.iter()
                         .map(|directory_object| directory_object.get_path())
@@ -215,23 +238,61 @@ impl FileSystem {
         return directory;
     }
 
-    pub fn directories_under_threshold(&self, threshold: DirectoryObjectSize) -> HashSet<(&String, DirectoryObjectSize)> {
+    fn develop_directory_size<'a>(&'a self, path: &'a String, sizes: &mut HashMap<&'a String, DirectoryObjectSize>) -> DirectoryObjectSize {
+
+        if let Some(size) = sizes.get(path) { return *size }
+
+        let directory_object: &dyn DirectoryObject = self.directory.get(path).unwrap().as_ref();
+        if ! directory_object.is_folder() { return directory_object.get_directory_object_size(&self.directory) }
+
+        let size: DirectoryObjectSize = directory_object.get_child_paths().iter()
+            .map(|child_path| self.develop_directory_size(child_path, sizes))
+            .sum();
+
+        sizes.insert(path, size);
+        return size;
+    }
+
+    /// Total size of every folder, indexed by its path.
+    ///
+    /// Each folder is only summed once, reusing the sizes already computed for its sub-folders.
+    pub fn get_directory_sizes(&self) -> HashMap<&String, DirectoryObjectSize> {
+
+        let mut sizes: HashMap<&String, DirectoryObjectSize> = HashMap::new();
+        for (path, _) in self.directory.iter().filter(|(_, object)| object.is_folder()) {
+            self.develop_directory_size(path, &mut sizes);
+        }
+
+        return sizes;
+    }
+
+    /// Straightforward version of [`FileSystem::get_directory_sizes`], which walks the whole
+    /// sub-tree of every folder. Kept as a reference to check the faster version against.
+    pub fn get_directory_sizes_reference(&self) -> HashMap<&String, DirectoryObjectSize> {
 
         return self.directory.iter()
             .filter(|(_, object)| object.is_folder())
             .map(|(path, object)| (path, object.get_directory_object_size(&self.directory)))
+            .collect();
+    }
+
+    /// Folders whose total size is at most `threshold`, along with their sizes.
+    pub fn directories_under_threshold(&self, threshold: DirectoryObjectSize) -> HashSet<(&String, DirectoryObjectSize)> {
+
+        return self.get_directory_sizes()
+            .into_iter()
             .filter(|(_, size)| *size <= threshold)
             .collect();
     }
 
+    /// Smallest folder that frees enough space for the update once deleted.
     pub fn get_directory_to_delete_for_update(&self, total_disk_space: DirectoryObjectSize, update_size: DirectoryObjectSize) -> (&String, DirectoryObjectSize) {
 
-        let space_used: DirectoryObjectSize = self.directory.get("").unwrap().get_directory_object_size(&self.directory);
+        let directory_sizes: HashMap<&String, DirectoryObjectSize> = self.get_directory_sizes();
+        let space_used: DirectoryObjectSize = *directory_sizes.get(&"".to_owned()).unwrap();
         let space_to_free: DirectoryObjectSize = update_size - (total_disk_space - space_used);
 
-        return self.directory.iter()
-            .filter(|(_, object)| object.is_folder())
-            .map(|(path, object)| (path, object.get_directory_object_size(&self.directory)))
+        return directory_sizes.into_iter()
             .filter(|(_, size)| *size >= space_to_free)
             .min_by_key(|(_, size)| *size)
             .unwrap();
@@ -1,10 +1,19 @@
 mod read;
-mod lib;
+
+use day_07::FileSystem;
+use aoc_common::cache::{ModelCache, default_cache_directory};
 
 fn main() {
 
+    #[cfg(feature = "trace")]
+    aoc_common::trace::install_json_subscriber(&aoc_common::trace::default_trace_file()).unwrap();
+
     let input: Vec<String> = read::read_lines("input.txt".to_owned());
-    let filesystem: lib::FileSystem = lib::FileSystem::new(&input);
+
+    // A cached filesystem skips the replay of the commands, which is what the trace records
+    let filesystem: FileSystem = ModelCache::new(&default_cache_directory())
+        .with_bypass(cfg!(feature = "trace"))
+        .load_or_develop("filesystem", &input[..], FileSystem::new);
 
     // Part 1
 
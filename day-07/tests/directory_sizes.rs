@@ -0,0 +1,48 @@
+use proptest::prelude::*;
+
+use day_07::FileSystem;
+
+#[derive(Clone, Debug)]
+enum Node {
+    File(usize),
+    Folder(Vec<Node>)
+}
+
+fn node_strategy() -> impl Strategy<Value = Node> {
+
+    let leaf = (1usize..100000).prop_map(Node::File);
+    leaf.prop_recursive(4, 64, 6, |inner| prop::collection::vec(inner, 0..6).prop_map(Node::Folder))
+}
+
+fn develop_session(children: &[Node], lines: &mut Vec<String>) {
+
+    lines.push("$ ls".to_owned());
+    for (index, child) in children.iter().enumerate() {
+        match child {
+            Node::File(size) => lines.push(format!("{} f{}.txt", size, index)),
+            Node::Folder(_) => lines.push(format!("dir d{}", index)),
+        }
+    }
+
+    for (index, child) in children.iter().enumerate() {
+        if let Node::Folder(grandchildren) = child {
+
+            lines.push(format!("$ cd d{}", index));
+            develop_session(grandchildren, lines);
+            lines.push("$ cd ..".to_owned());
+        }
+    }
+}
+
+proptest! {
+
+    #[test]
+    fn directory_sizes_match_reference(root in prop::collection::vec(node_strategy(), 0..6)) {
+
+        let mut lines: Vec<String> = vec!["$ cd /".to_owned()];
+        develop_session(&root, &mut lines);
+
+        let filesystem: FileSystem = FileSystem::new(&lines);
+        prop_assert_eq!(filesystem.get_directory_sizes(), filesystem.get_directory_sizes_reference());
+    }
+}
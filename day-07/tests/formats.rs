@@ -0,0 +1,59 @@
+use std::collections::BTreeMap;
+
+use day_07::FileSystem;
+use day_07::formats::{filesystem_from_csv, filesystem_from_json, filesystem_to_csv, filesystem_to_json};
+
+const SESSION: [&str; 23] = [
+    "$ cd /", "$ ls", "dir a", "14848514 b.txt", "8504156 c.dat", "dir d",
+    "$ cd a", "$ ls", "dir e", "29116 f", "2557 g", "62596 h.lst",
+    "$ cd e", "$ ls", "584 i", "$ cd ..", "$ cd ..",
+    "$ cd d", "$ ls", "4060174 j", "8033020 d.log", "5626152 d.ext", "7214296 k"
+];
+
+fn develop_sizes(filesystem: &FileSystem) -> BTreeMap<String, usize> {
+
+    return filesystem.get_directory_sizes().into_iter()
+        .map(|(path, size)| (path.clone(), size))
+        .collect();
+}
+
+#[test]
+fn json_round_trip() {
+
+    let filesystem: FileSystem = FileSystem::new(&SESSION.map(|line| line.to_owned()));
+    let json: String = filesystem_to_json(&filesystem).unwrap();
+    assert!(json.starts_with(r#"{"name":"/","children":[{"name":"a","children":[{"name":"e","children":[{"name":"i","size":584}]},"#), "{}", json);
+
+    let read_filesystem: FileSystem = filesystem_from_json(&json).unwrap();
+    assert_eq!(filesystem_to_json(&read_filesystem).unwrap(), json);
+    assert_eq!(develop_sizes(&read_filesystem), develop_sizes(&filesystem));
+    assert_eq!(develop_sizes(&read_filesystem)[""], 48381165);
+}
+
+#[test]
+fn csv_round_trip() {
+
+    let filesystem: FileSystem = FileSystem::new(&SESSION.map(|line| line.to_owned()));
+    let csv: String = filesystem_to_csv(&filesystem).unwrap();
+    assert!(csv.starts_with("path,size\na,\na/e,\na/e/i,584\n"), "{}", csv);
+
+    let read_filesystem: FileSystem = filesystem_from_csv(&csv).unwrap();
+    assert_eq!(filesystem_to_csv(&read_filesystem).unwrap(), csv);
+    assert_eq!(develop_sizes(&read_filesystem), develop_sizes(&filesystem));
+
+    // Both formats describe the same tree
+    assert_eq!(filesystem_to_json(&read_filesystem).unwrap(), filesystem_to_json(&filesystem).unwrap());
+}
+
+#[test]
+fn malformed_records_are_errors() {
+
+    assert!(filesystem_from_json(r#"{"name": "/", "size": 10}"#).is_err());
+    assert!(filesystem_from_json(r#"{"name": "/", "children": [{"name": "a", "size": "big"}]}"#).is_err());
+    assert!(filesystem_from_csv("path,size\na/b,ten\n").is_err());
+
+    // Nothing can be inside a file
+    assert!(filesystem_from_csv("path,size\na,10\na/b,5\n").is_err());
+    assert!(filesystem_from_csv("path,size\na,10\na/b/c,5\n").is_err());
+    assert!(filesystem_from_json(r#"{"name": "/", "children": [{"name": "a", "size": 10}, {"name": "a", "children": [{"name": "b", "size": 5}]}]}"#).is_err());
+}
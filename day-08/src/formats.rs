@@ -0,0 +1,58 @@
+//! Structured representations of the grid of tree heights.
+//!
+//! In JSON the grid is a list of rows, e.g. `[[3, 0, 3, 7, 3], [2, 5, 5, 1, 2]]`.
+//! In CSV every row of the grid is a row of heights, without any header.
+
+use crate::{Coordinate, Forest, TreeSize};
+
+// =============================================== AUXILIARY FUNCTIONS ===============================================
+
+fn develop_trees_sizes(forest: &Forest) -> Vec<Vec<TreeSize>> {
+
+    if forest.trees.is_empty() { return Vec::new() }
+
+    return (forest.min_pos.y ..= forest.max_pos.y)
+        .map(|y| (forest.min_pos.x ..= forest.max_pos.x)
+            .map(|x| forest.trees.get(&Coordinate::new(x, y)).unwrap().size)
+            .collect())
+        .collect();
+}
+
+/// Reads the forest from a JSON list of rows of heights.
+pub fn forest_from_json(input: &str) -> serde_json::Result<Forest> {
+
+    let trees_sizes: Vec<Vec<TreeSize>> = serde_json::from_str(input)?;
+    return Ok(Forest::new(trees_sizes));
+}
+
+/// Writes the forest as a JSON list of rows of heights.
+pub fn forest_to_json(forest: &Forest) -> serde_json::Result<String> {
+    return serde_json::to_string(&develop_trees_sizes(forest));
+}
+
+/// Reads the forest from CSV rows of heights.
+pub fn forest_from_csv(input: &str) -> csv::Result<Forest> {
+
+    let mut reader = csv::ReaderBuilder::new()
+        .has_headers(false)
+        .from_reader(input.as_bytes());
+
+    let trees_sizes: Vec<Vec<TreeSize>> = reader.deserialize()
+        .collect::<csv::Result<Vec<Vec<TreeSize>>>>()?;
+    return Ok(Forest::new(trees_sizes));
+}
+
+/// Writes the forest as CSV rows of heights.
+pub fn forest_to_csv(forest: &Forest) -> csv::Result<String> {
+
+    let mut writer = csv::WriterBuilder::new()
+        .has_headers(false)
+        .from_writer(Vec::new());
+
+    for row_trees_sizes in develop_trees_sizes(forest) {
+        writer.serialize(row_trees_sizes)?;
+    }
+
+    let output: Vec<u8> = writer.into_inner().map_err(|error| error.into_error())?;
+    return Ok(String::from_utf8(output).unwrap());
+}
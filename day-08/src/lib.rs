@@ -1,20 +1,33 @@
-#![feature(is_some_and)]
+//! Treetop tree house (Advent of Code 2022, day 08).
+//!
+//! The input is a grid of tree heights. A tree is visible when every tree between it and an edge
+//! of the forest is shorter, and its scenic score multiplies how far it can see in each direction.
+//! The grid of heights can also be read from, and written to, JSON or CSV through [`formats`],
+//! and the forest kept between runs through `aoc_common::cache`.
 
 use std::ops::Add;
 use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+
+pub mod formats;
 
 // =========================================== TYPE AND STRUCT DEFINITIONS ===========================================
 
-type TreeSize = u32;
+/// Height of a tree.
+pub type TreeSize = u32;
 
-type CoordinateUnit = i32;
+/// A single component of a coordinate.
+pub type CoordinateUnit = i32;
 
-#[derive(PartialEq, Eq, Hash, Clone, Copy)]
+/// Position of a tree in the grid, with `x` growing to the right and `y` growing downwards.
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Serialize, Deserialize)]
 pub struct Coordinate {
     pub x: CoordinateUnit,
     pub y: CoordinateUnit
 }
 
+/// A tree, along with what has been computed about its visibility.
+#[derive(Serialize, Deserialize)]
 pub struct Tree {
     size: TreeSize,
 
@@ -29,15 +42,20 @@ pub struct Tree {
     visible_trees_west: Option<usize>,
 }
 
+/// Grid of trees, indexed by their position.
+#[derive(Serialize, Deserialize)]
 pub struct Forest {
     min_pos: Coordinate,
     max_pos: Coordinate,
     pub trees: HashMap<Coordinate, Tree>
 }
 
+type VisibilityCallback = Box<dyn FnMut(&mut Tree, bool)>;
+type VisibleTreesCallback = Box<dyn FnMut(&mut Tree, usize)>;
+
 // =============================================== AUXILIARY FUNCTIONS ===============================================
 
-fn check_visibility_direction(start_pos: Coordinate, iteration_delta: Coordinate, iteration_clear: Coordinate, forest: &mut Forest, mut callback: Box<dyn FnMut(&mut Tree, bool)>) {
+fn check_visibility_direction(start_pos: Coordinate, iteration_delta: Coordinate, iteration_clear: Coordinate, forest: &mut Forest, mut callback: VisibilityCallback) {
 
     let mut current_line: Coordinate = start_pos;
 
@@ -65,6 +83,7 @@ fn check_visibility_direction(start_pos: Coordinate, iteration_delta: Coordinate
     }
 }
 
+/// Computes, for every tree, whether it can be seen from outside the forest.
 pub fn check_visibility_outside_forest(forest: &mut Forest) {
     check_visibility_direction(Coordinate::new(forest.min_pos.x, forest.min_pos.y),
         Coordinate::new(0, 1),
@@ -88,7 +107,7 @@ pub fn check_visibility_outside_forest(forest: &mut Forest) {
         Box::new(|tree, visibility| tree.set_visible_west(visibility)));
 }
 
-fn check_visibility_position_direction(start_pos: Coordinate, iteration_delta: Coordinate, forest: &mut Forest, mut callback: Box<dyn FnMut(&mut Tree, usize)>) {
+fn check_visibility_position_direction(start_pos: Coordinate, iteration_delta: Coordinate, forest: &mut Forest, mut callback: VisibleTreesCallback) {
 
     let focus_tree_size: TreeSize = forest.trees.get_mut(&start_pos).unwrap().size;
     let mut current_pos: Coordinate = start_pos + iteration_delta;
@@ -101,7 +120,7 @@ fn check_visibility_position_direction(start_pos: Coordinate, iteration_delta: C
         (current_threshold.is_none() || current_threshold.is_some_and(|threshold| threshold < focus_tree_size)) {
 
             let current_tree: &Tree = forest.trees.get(&current_pos).unwrap();
-            count_visible_trees = count_visible_trees + 1;
+            count_visible_trees += 1;
             current_threshold = Some(current_tree.size);
 
             current_pos = current_pos + iteration_delta;
@@ -130,7 +149,73 @@ fn check_visibility_position(forest: &mut Forest, position: Coordinate) {
         Box::new(|tree, visibility| tree.set_visible_trees_west(visibility)));
 }
 
+fn check_visible_trees_direction(start_pos: Coordinate, iteration_delta: Coordinate, iteration_clear: Coordinate, forest: &mut Forest, mut callback: VisibleTreesCallback) {
+
+    let mut current_line: Coordinate = start_pos;
+
+    while current_line.x >= forest.min_pos.x && current_line.x <= forest.max_pos.x &&
+        current_line.y >= forest.min_pos.y && current_line.y <= forest.max_pos.y {
+
+            let mut current_pos: Coordinate = current_line;
+            let mut current_step: usize = 0;
+
+            // Trees already passed that can still block the view, with strictly decreasing sizes
+            let mut blocking_trees: Vec<(usize, TreeSize)> = Vec::new();
+
+            while current_pos.x >= forest.min_pos.x && current_pos.x <= forest.max_pos.x &&
+                current_pos.y >= forest.min_pos.y && current_pos.y <= forest.max_pos.y {
+
+                    let current_tree: &mut Tree = forest.trees.get_mut(&current_pos).unwrap();
+                    while blocking_trees.last().is_some_and(|&(_, size)| size < current_tree.size) {
+                        blocking_trees.pop();
+                    }
+
+                    let count_visible_trees: usize = match blocking_trees.last() {
+                        Some(&(blocking_step, _)) => current_step - blocking_step,
+                        None => current_step
+                    };
+
+                    callback(current_tree, count_visible_trees);
+                    blocking_trees.push((current_step, current_tree.size));
+
+                    current_pos = current_pos + iteration_delta;
+                    current_step += 1;
+            }
+
+            current_line = current_line + iteration_clear;
+    }
+}
+
+/// Computes, for every tree, how many trees it can see in each direction.
+///
+/// Every line of the forest is swept once per direction, keeping only the trees that can still
+/// block the view of the ones coming after them.
 pub fn check_visibility_inside_forest(forest: &mut Forest) {
+    check_visible_trees_direction(Coordinate::new(forest.min_pos.x, forest.min_pos.y),
+        Coordinate::new(0, 1),
+        Coordinate::new(1, 0),
+        forest,
+        Box::new(|tree, count| tree.set_visible_trees_north(count)));
+    check_visible_trees_direction(Coordinate::new(forest.min_pos.x, forest.max_pos.y),
+        Coordinate::new(0, -1),
+        Coordinate::new(1, 0),
+        forest,
+        Box::new(|tree, count| tree.set_visible_trees_south(count)));
+    check_visible_trees_direction(Coordinate::new(forest.max_pos.x, forest.min_pos.y),
+        Coordinate::new(-1, 0),
+        Coordinate::new(0, 1),
+        forest,
+        Box::new(|tree, count| tree.set_visible_trees_east(count)));
+    check_visible_trees_direction(Coordinate::new(forest.min_pos.x, forest.min_pos.y),
+        Coordinate::new(1, 0),
+        Coordinate::new(0, 1),
+        forest,
+        Box::new(|tree, count| tree.set_visible_trees_west(count)));
+}
+
+/// Straightforward version of [`check_visibility_inside_forest`], which walks from every tree
+/// until its view is blocked. Kept as a reference to check the faster version against.
+pub fn check_visibility_inside_forest_reference(forest: &mut Forest) {
 
     for x in forest.min_pos.x ..= forest.max_pos.x {
         for y in forest.min_pos.y ..= forest.max_pos.y {
@@ -165,7 +250,7 @@ impl Add for Coordinate {
 impl Tree {
 
     fn new(size: TreeSize) -> Tree {
-        Tree { size: size,
+        Tree { size,
             visible_north: None, visible_south: None, visible_east: None, visible_west: None,
             visible_trees_north: None, visible_trees_south: None, visible_trees_east: None, visible_trees_west: None }
     }
@@ -180,6 +265,9 @@ impl Tree {
     fn set_visible_trees_east(&mut self, count: usize) { self.visible_trees_east = Some(count) }
     fn set_visible_trees_west(&mut self, count: usize) { self.visible_trees_west = Some(count) }
 
+    /// Whether the tree can be seen from at least one edge of the forest.
+    ///
+    /// Only meaningful after [`check_visibility_outside_forest`] has been run.
     pub fn check_visible(&self) -> bool {
         return self.visible_north.is_some_and(|visible| visible) ||
             self.visible_south.is_some_and(|visible| visible) ||
@@ -187,6 +275,16 @@ impl Tree {
             self.visible_west.is_some_and(|visible| visible);
     }
 
+    /// Number of trees seen towards the north, south, east and west, in that order.
+    ///
+    /// Only meaningful after [`check_visibility_inside_forest`] has been run.
+    pub fn visible_trees(&self) -> (Option<usize>, Option<usize>, Option<usize>, Option<usize>) {
+        return (self.visible_trees_north, self.visible_trees_south, self.visible_trees_east, self.visible_trees_west);
+    }
+
+    /// Product of the number of trees seen in each direction.
+    ///
+    /// Only meaningful after [`check_visibility_inside_forest`] has been run.
     pub fn scenic_score(&self) -> usize {
         return self.visible_trees_north.unwrap() * self.visible_trees_south.unwrap() *
             self.visible_trees_east.unwrap() * self.visible_trees_west.unwrap();
@@ -195,6 +293,7 @@ impl Tree {
 
 impl Forest {
 
+    /// Creates the forest from the rows of tree heights.
     pub fn new(trees_sizes: Vec<Vec<TreeSize>>) -> Forest {
 
         let mut trees: HashMap<Coordinate, Tree> = HashMap::new();
@@ -1,16 +1,24 @@
-#![feature(is_some_and)]
-
 mod read;
-mod lib;
+
+use day_08::{Coordinate, Forest};
+use day_08::{check_visibility_outside_forest, check_visibility_inside_forest};
+use aoc_common::cache::{ModelCache, default_cache_directory};
 
 fn main() {
 
     let input: Vec<Vec<u32>> = read::read_digits("input.txt".to_owned());
-    let mut forest: lib::Forest = lib::Forest::new(input);
+
+    // Both visibility passes are part of the cached forest, so later runs only read the results
+    let forest: Forest = ModelCache::new(&default_cache_directory()).load_or_develop("forest", &input, |input| {
+
+        let mut forest: Forest = Forest::new(input.clone());
+        check_visibility_outside_forest(&mut forest);
+        check_visibility_inside_forest(&mut forest);
+        return forest;
+    });
 
     // Part 1
 
-    lib::check_visibility_outside_forest(&mut forest);
     let visible_trees: usize = forest.trees.iter()
         .filter(|&(_, tree)| tree.check_visible())
         .count();
@@ -18,8 +26,7 @@ fn main() {
     
     // Part 2
 
-    lib::check_visibility_inside_forest(&mut forest);
-    let best_scenic_score: (&lib::Coordinate, usize) = forest.trees.iter()
+    let best_scenic_score: (&Coordinate, usize) = forest.trees.iter()
         .map(|(position, tree)| (position, tree.scenic_score()))
         .max_by_key(|&(_, scenic_score)| scenic_score)
         .unwrap();
@@ -0,0 +1,36 @@
+use day_08::Forest;
+use day_08::formats::{forest_from_csv, forest_from_json, forest_to_csv, forest_to_json};
+
+const GRID: [[u32; 5]; 5] = [[3, 0, 3, 7, 3], [2, 5, 5, 1, 2], [6, 5, 3, 3, 2], [3, 3, 5, 4, 9], [3, 5, 3, 9, 0]];
+
+fn develop_forest() -> Forest {
+    return Forest::new(GRID.iter().map(|row| row.to_vec()).collect());
+}
+
+#[test]
+fn json_round_trip() {
+
+    let json: String = forest_to_json(&develop_forest()).unwrap();
+    assert_eq!(json, "[[3,0,3,7,3],[2,5,5,1,2],[6,5,3,3,2],[3,3,5,4,9],[3,5,3,9,0]]");
+    assert_eq!(forest_to_json(&forest_from_json(&json).unwrap()).unwrap(), json);
+    assert_eq!(forest_from_json(&json).unwrap().trees.len(), 25);
+}
+
+#[test]
+fn csv_round_trip() {
+
+    let csv: String = forest_to_csv(&develop_forest()).unwrap();
+    assert_eq!(csv, "3,0,3,7,3\n2,5,5,1,2\n6,5,3,3,2\n3,3,5,4,9\n3,5,3,9,0\n");
+    assert_eq!(forest_to_csv(&forest_from_csv(&csv).unwrap()).unwrap(), csv);
+
+    // Both formats describe the same grid
+    assert_eq!(forest_to_json(&forest_from_csv(&csv).unwrap()).unwrap(), forest_to_json(&develop_forest()).unwrap());
+}
+
+#[test]
+fn malformed_records_are_errors() {
+
+    assert!(forest_from_json("[[3, 0], [2, -5]]").is_err());
+    assert!(forest_from_json("[3, 0, 3]").is_err());
+    assert!(forest_from_csv("3,0,3\n2,five,5\n").is_err());
+}
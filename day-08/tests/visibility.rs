@@ -0,0 +1,28 @@
+use proptest::prelude::*;
+
+use day_08::Forest;
+use day_08::{check_visibility_inside_forest, check_visibility_inside_forest_reference};
+
+fn forest_strategy() -> impl Strategy<Value = Vec<Vec<u32>>> {
+
+    (1usize..12, 1usize..12).prop_flat_map(|(width, height)|
+        prop::collection::vec(prop::collection::vec(0u32..10, width), height))
+}
+
+proptest! {
+
+    #[test]
+    fn visible_trees_match_reference(trees_sizes in forest_strategy()) {
+
+        let mut forest: Forest = Forest::new(trees_sizes.clone());
+        let mut forest_reference: Forest = Forest::new(trees_sizes);
+
+        check_visibility_inside_forest(&mut forest);
+        check_visibility_inside_forest_reference(&mut forest_reference);
+
+        for (position, tree) in forest.trees.iter() {
+            let tree_reference = forest_reference.trees.get(position).unwrap();
+            prop_assert_eq!(tree.visible_trees(), tree_reference.visible_trees(), "at ({}, {})", position.x, position.y);
+        }
+    }
+}
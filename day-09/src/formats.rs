@@ -0,0 +1,48 @@
+//! Structured representations of the movements of the head and of the resulting rope.
+//!
+//! Each movement is a record with its `direction` (`U`, `D`, `R` or `L`) and its `units`, e.g.
+//! `[{"direction": "R", "units": 4}]` in JSON or `R,4` rows in CSV. A rope is written in JSON
+//! with the history of positions of each of its knots, starting from the head.
+
+use crate::{Movement, Rope};
+
+// =============================================== AUXILIARY FUNCTIONS ===============================================
+
+/// Reads the movements from a JSON list of records.
+pub fn movements_from_json(input: &str) -> serde_json::Result<Vec<Movement>> {
+    return serde_json::from_str(input);
+}
+
+/// Writes the movements as a JSON list of records.
+pub fn movements_to_json(movements: &[Movement]) -> serde_json::Result<String> {
+    return serde_json::to_string(movements);
+}
+
+/// Reads the movements from CSV rows of `direction,units`.
+pub fn movements_from_csv(input: &str) -> csv::Result<Vec<Movement>> {
+
+    let mut reader = csv::Reader::from_reader(input.as_bytes());
+    return reader.deserialize().collect();
+}
+
+/// Writes the movements as CSV rows of `direction,units`.
+pub fn movements_to_csv(movements: &[Movement]) -> csv::Result<String> {
+
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    for movement in movements.iter() {
+        writer.serialize(movement)?;
+    }
+
+    let output: Vec<u8> = writer.into_inner().map_err(|error| error.into_error())?;
+    return Ok(String::from_utf8(output).unwrap());
+}
+
+/// Reads a rope, with the positions visited by each of its knots, from JSON.
+pub fn rope_from_json(input: &str) -> serde_json::Result<Rope> {
+    return serde_json::from_str(input);
+}
+
+/// Writes a rope, with the positions visited by each of its knots, as JSON.
+pub fn rope_to_json(rope: &Rope) -> serde_json::Result<String> {
+    return serde_json::to_string(rope);
+}
@@ -1,33 +1,62 @@
+//! Rope bridge (Advent of Code 2022, day 09).
+//!
+//! The input moves the head of a rope around a grid, and every other knot follows the one before
+//! it so that they always stay touching. The puzzle counts the positions visited by the tail.
+//! The movements and the resulting rope can also be read from, and written to, JSON or CSV
+//! through [`formats`].
+//! Each step of the head is recorded as a `tracing` span with the knots it leaves behind; with the
+//! `trace` feature the binary writes them as JSON lines through `aoc_common::trace`.
+
 use std::{ops::Add, collections::HashSet};
+use serde::{Deserialize, Serialize};
+
+pub mod formats;
 
 // =========================================== TYPE AND STRUCT DEFINITIONS ===========================================
 
 type CoordinateUnit = i32;
 
-#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Serialize, Deserialize)]
 struct Coordinate {
     x: CoordinateUnit,
     y: CoordinateUnit
 }
 
-#[derive(Clone, Copy)]
-enum MovementDirection {
+/// Direction in which the head of the rope is moved.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub enum MovementDirection {
+    #[serde(rename = "U")]
     Up,
+    #[serde(rename = "D")]
     Down,
+    #[serde(rename = "R")]
     Right,
+    #[serde(rename = "L")]
     Left
 }
 
+/// A line of the input: move the head `units` steps towards `direction`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub struct Movement {
+    pub direction: MovementDirection,
+    pub units: u32
+}
+
+/// A rope and the history of positions of each of its knots, starting from the head.
+#[derive(PartialEq, Eq, Debug, Serialize, Deserialize)]
 pub struct Rope {
     knots: Vec<Vec<Coordinate>>
 }
 
+type KnotMovement = fn(&Coordinate, &Coordinate) -> Coordinate;
+
 // =============================================== AUXILIARY FUNCTIONS ===============================================
 
-fn convert_set_of_movements(movement_lines: &Vec<String>) -> Vec<MovementDirection> {
+/// Reads each line of the input, such as `R 4`, as a movement of the head.
+pub fn convert_input_to_movements(movement_lines: &[String]) -> Vec<Movement> {
 
-    let mut movements: Vec<MovementDirection> = Vec::new();
-    for line in movement_lines.into_iter() {
+    let mut movements: Vec<Movement> = Vec::new();
+    for line in movement_lines.iter() {
 
         let mut line_split: Vec<String> = line.split_whitespace()
             .map(|split| split.to_owned())
@@ -41,16 +70,37 @@ fn convert_set_of_movements(movement_lines: &Vec<String>) -> Vec<MovementDirecti
 
         let movement_units: u32 = line_split.remove(0).parse().unwrap();
 
-        for _ in 0 .. movement_units {
-            movements.push(movement_direction.clone());
-        }
+        movements.push(Movement { direction: movement_direction, units: movement_units });
     }
 
     return movements;
 }
 
+fn convert_set_of_movements(movements: &[Movement]) -> Vec<MovementDirection> {
+
+    let mut movement_steps: Vec<MovementDirection> = Vec::new();
+    for movement in movements.iter() {
+        for _ in 0 .. movement.units {
+            movement_steps.push(movement.direction);
+        }
+    }
+
+    return movement_steps;
+}
+
 fn get_knot_movement(new_head_knot: &Coordinate, current_tail_knot: &Coordinate) -> Coordinate {
 
+    let delta_x: CoordinateUnit = new_head_knot.x - current_tail_knot.x;
+    let delta_y: CoordinateUnit = new_head_knot.y - current_tail_knot.y;
+
+    // Still touching the head, including diagonally
+    if delta_x.abs() <= 1 && delta_y.abs() <= 1 { return *current_tail_knot }
+
+    return *current_tail_knot + Coordinate::new(delta_x.signum(), delta_y.signum());
+}
+
+fn get_knot_movement_reference(new_head_knot: &Coordinate, current_tail_knot: &Coordinate) -> Coordinate {
+
     let distance_tail_to_new_head: CoordinateUnit = new_head_knot.distance(current_tail_knot);
     let new_tail: Coordinate;
 
@@ -60,7 +110,7 @@ fn get_knot_movement(new_head_knot: &Coordinate, current_tail_knot: &Coordinate)
         for delta_to_test in vec![Coordinate::new(1, 0), Coordinate::new(-1, 0), Coordinate::new(0, 1), Coordinate::new(0, -1),
             Coordinate::new(1, 1), Coordinate::new(1, -1), Coordinate::new(-1, -1), Coordinate::new(-1, 1)].into_iter() {
 
-                let new_position = current_tail_knot.clone() + delta_to_test;
+                let new_position = *current_tail_knot + delta_to_test;
                 let distance: CoordinateUnit = new_head_knot.distance(&new_position);
                 if current_best.is_none() || current_best.unwrap().1 > distance {
                     current_best = Some((new_position, distance));
@@ -69,7 +119,7 @@ fn get_knot_movement(new_head_knot: &Coordinate, current_tail_knot: &Coordinate)
 
         new_tail = current_best.unwrap().0;
 
-    } else { new_tail = current_tail_knot.clone() }
+    } else { new_tail = *current_tail_knot }
 
     return new_tail;
 }
@@ -106,11 +156,11 @@ impl Add for Coordinate {
 impl MovementDirection {
 
     fn get_delta_direction(&self) -> Coordinate {
-        match self {
-            &MovementDirection::Up      => Coordinate::new( 0,  1),
-            &MovementDirection::Down    => Coordinate::new( 0, -1),
-            &MovementDirection::Right   => Coordinate::new( 1,  0),
-            &MovementDirection::Left    => Coordinate::new(-1,  0),
+        match *self {
+            MovementDirection::Up      => Coordinate::new( 0,  1),
+            MovementDirection::Down    => Coordinate::new( 0, -1),
+            MovementDirection::Right   => Coordinate::new( 1,  0),
+            MovementDirection::Left    => Coordinate::new(-1,  0),
         }
     }
 
@@ -127,43 +177,65 @@ impl MovementDirection {
 
 impl Rope {
 
-    pub fn new(movement_lines: &Vec<String>, number_knots: usize) -> Rope {
+    /// Creates a rope of `number_knots` knots at the origin and applies every movement of the input.
+    pub fn new(movement_lines: &[String], number_knots: usize) -> Rope {
+        return Rope::from_movements(&convert_input_to_movements(movement_lines), number_knots);
+    }
+
+    /// Creates a rope of `number_knots` knots at the origin and applies every movement given.
+    pub fn from_movements(movements: &[Movement], number_knots: usize) -> Rope {
+        return Rope::develop_rope(movements, number_knots, get_knot_movement);
+    }
+
+    /// Straightforward version of [`Rope::new`], where each knot tries every neighbouring position
+    /// and keeps the closest to the knot ahead. Kept as a reference to check the faster version against.
+    pub fn new_reference(movement_lines: &[String], number_knots: usize) -> Rope {
+        return Rope::develop_rope(&convert_input_to_movements(movement_lines), number_knots, get_knot_movement_reference);
+    }
 
-        let movements: Vec<MovementDirection> = convert_set_of_movements(movement_lines);
+    fn develop_rope(movements: &[Movement], number_knots: usize, knot_movement: KnotMovement) -> Rope {
+
+        let movements: Vec<MovementDirection> = convert_set_of_movements(movements);
         let mut rope: Rope = Rope {
-            knots: (0 .. number_knots).into_iter()
+            knots: (0 .. number_knots)
                 .map(|_| vec![Coordinate::new(0, 0)])
                 .collect()
         };
 
-        for movement in movements { rope.make_iteration(movement) }
+        for movement in movements { rope.make_iteration(movement, knot_movement) }
         return rope;
     }
 
-    fn make_iteration(&mut self, movement_head: MovementDirection) {
+    fn make_iteration(&mut self, movement_head: MovementDirection, knot_movement: KnotMovement) {
+
+        let _span = tracing::debug_span!("rope_step",
+            step = self.knots.first().unwrap().len(), direction = ?movement_head).entered();
 
         let current_head: &Coordinate = self.knots.first().unwrap()
             .last().unwrap();
         
         let movement_head_delta: Coordinate = movement_head.get_delta_direction();
-        let mut new_head: Coordinate = current_head.clone() + movement_head_delta;
+        let mut new_head: Coordinate = *current_head + movement_head_delta;
 
-        self.knots.first_mut().unwrap().push(new_head.clone());
+        self.knots.first_mut().unwrap().push(new_head);
 
         for knot_positions in self.knots.iter_mut().skip(1) {
 
             let current_tail: &Coordinate = knot_positions.last().unwrap();
-            let new_tail: Coordinate = get_knot_movement(&new_head, current_tail);
+            let new_tail: Coordinate = knot_movement(&new_head, current_tail);
 
-            knot_positions.push(new_tail.clone());
-            new_head = new_tail.clone();
+            knot_positions.push(new_tail);
+            new_head = new_tail;
         }
+
+        tracing::trace!(knots = ?self.knots.iter().map(|knot_positions| *knot_positions.last().unwrap()).collect::<Vec<Coordinate>>(), "knots moved");
     }
 
+    /// Number of distinct positions the tail has visited.
     pub fn get_tail_number_different_positions(&self) -> usize {
         self.knots.last().unwrap()
             .iter()
-            .map(|&coordinate| coordinate.clone())
+            .copied()
             .collect::<HashSet<Coordinate>>()
             .len()
     }
@@ -1,11 +1,15 @@
 mod read;
-mod lib;
+
+use day_09::Rope;
 
 fn main() {
 
+    #[cfg(feature = "trace")]
+    aoc_common::trace::install_json_subscriber(&aoc_common::trace::default_trace_file()).unwrap();
+
     let input: Vec<String> = read::read_lines("input.txt".to_owned());
-    let rope_2: lib::Rope = lib::Rope::new(&input, 2);
-    let rope_10: lib::Rope = lib::Rope::new(&input, 10);
+    let rope_2: Rope = Rope::new(&input, 2);
+    let rope_10: Rope = Rope::new(&input, 10);
 
     // Part 1
 
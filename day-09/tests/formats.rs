@@ -0,0 +1,38 @@
+use day_09::{Movement, Rope, convert_input_to_movements};
+use day_09::formats::{movements_from_csv, movements_from_json, movements_to_csv, movements_to_json, rope_from_json, rope_to_json};
+
+const MOVEMENTS: [&str; 8] = ["R 4", "U 4", "L 3", "D 1", "R 4", "D 1", "L 5", "R 2"];
+
+fn develop_movements() -> Vec<Movement> {
+    return convert_input_to_movements(&MOVEMENTS.map(|line| line.to_owned()));
+}
+
+#[test]
+fn json_round_trip() {
+
+    let json: String = movements_to_json(&develop_movements()[.. 2]).unwrap();
+    assert_eq!(json, r#"[{"direction":"R","units":4},{"direction":"U","units":4}]"#);
+    assert_eq!(movements_from_json(&movements_to_json(&develop_movements()).unwrap()).unwrap(), develop_movements());
+
+    let rope: Rope = Rope::from_movements(&develop_movements(), 2);
+    let read_rope: Rope = rope_from_json(&rope_to_json(&rope).unwrap()).unwrap();
+    assert_eq!(read_rope.get_tail_number_different_positions(), 13);
+    assert_eq!(read_rope, rope);
+}
+
+#[test]
+fn csv_round_trip() {
+
+    let csv: String = movements_to_csv(&develop_movements()).unwrap();
+    assert_eq!(csv, "direction,units\nR,4\nU,4\nL,3\nD,1\nR,4\nD,1\nL,5\nR,2\n");
+    assert_eq!(movements_from_csv(&csv).unwrap(), develop_movements());
+}
+
+#[test]
+fn malformed_records_are_errors() {
+
+    assert!(movements_from_json(r#"[{"direction": "X", "units": 4}]"#).is_err());
+    assert!(movements_from_json(r#"[{"direction": "R", "units": -4}]"#).is_err());
+    assert!(movements_from_csv("direction,units\nR\n").is_err());
+    assert!(movements_from_csv("direction,units\nUp,4\n").is_err());
+}
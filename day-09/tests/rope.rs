@@ -0,0 +1,22 @@
+use proptest::prelude::*;
+
+use day_09::Rope;
+
+fn movement_strategy() -> impl Strategy<Value = String> {
+
+    (prop::sample::select(vec!['U', 'D', 'R', 'L']), 1u32..10)
+        .prop_map(|(direction, units)| format!("{} {}", direction, units))
+}
+
+proptest! {
+
+    #[test]
+    fn rope_matches_reference(movement_lines in prop::collection::vec(movement_strategy(), 0..60), number_knots in 1usize..12) {
+
+        let rope: Rope = Rope::new(&movement_lines, number_knots);
+        let rope_reference: Rope = Rope::new_reference(&movement_lines, number_knots);
+
+        prop_assert_eq!(rope.get_tail_number_different_positions(), rope_reference.get_tail_number_different_positions());
+        prop_assert_eq!(rope, rope_reference);
+    }
+}
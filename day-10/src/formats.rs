@@ -0,0 +1,123 @@
+//! Structured representations of the program and the initial registers of the device.
+//!
+//! Each command is a record with its `command` name and, for `addx`, its `value`. In JSON the
+//! device is an object such as `{"registers": {"X": 1}, "commands": [{"command": "addx", "value": 3}]}`,
+//! while in CSV only the commands are written, as rows of `command,value`.
+//! Commands are checked while they are read, so an unknown command or a missing `addx` value is
+//! reported as an error of the format instead of a panic of the device.
+
+use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+
+use crate::{DeviceRegisterKey, DeviceRegisterValue, HandheldDeviceSetup};
+
+// =========================================== TYPE AND STRUCT DEFINITIONS ===========================================
+
+#[derive(Serialize, Deserialize)]
+#[serde(try_from = "CommandFields")]
+struct CommandRecord {
+    command: String,
+    #[serde(default)]
+    value: Option<DeviceRegisterValue>
+}
+
+/// Fields of a command as written, before checking that they make a command of the device.
+#[derive(Deserialize)]
+struct CommandFields {
+    command: String,
+    #[serde(default)]
+    value: Option<DeviceRegisterValue>
+}
+
+#[derive(Serialize, Deserialize)]
+struct DeviceRecord {
+    registers: HashMap<DeviceRegisterKey, DeviceRegisterValue>,
+    commands: Vec<CommandRecord>
+}
+
+// ================================================= IMPLEMENTATIONS =================================================
+
+impl CommandRecord {
+
+    fn from_command_line(line: String) -> CommandRecord {
+
+        let mut arguments = line.split_whitespace();
+        return CommandRecord {
+            command: arguments.next().unwrap().to_owned(),
+            value: arguments.next().map(|value| value.parse().unwrap())
+        };
+    }
+
+    fn develop_command_line(&self) -> String {
+
+        return match self.value {
+            Some(value) => format!("{} {}", self.command, value),
+            None => self.command.clone()
+        };
+    }
+}
+
+impl TryFrom<CommandFields> for CommandRecord {
+
+    type Error = String;
+
+    fn try_from(fields: CommandFields) -> Result<CommandRecord, String> {
+
+        return match (fields.command.as_str(), fields.value) {
+            ("addx", Some(_)) | ("noop", None) => Ok(CommandRecord { command: fields.command, value: fields.value }),
+            ("addx", None) => Err("command 'addx' needs a value".to_owned()),
+            ("noop", Some(value)) => Err(format!("command 'noop' takes no value, found {}", value)),
+            (command, _) => Err(format!("command '{}' not recognized", command))
+        };
+    }
+}
+
+// =============================================== AUXILIARY FUNCTIONS ===============================================
+
+fn develop_command_records(device: &HandheldDeviceSetup) -> Vec<CommandRecord> {
+
+    return device.commands.iter()
+        .map(|command| CommandRecord::from_command_line(command.develop_command_line()))
+        .collect();
+}
+
+/// Reads the program and the initial registers of the device from a JSON object.
+pub fn device_from_json(input: &str) -> serde_json::Result<HandheldDeviceSetup> {
+
+    let record: DeviceRecord = serde_json::from_str(input)?;
+    return Ok(HandheldDeviceSetup::new(
+        record.commands.iter().map(|command| command.develop_command_line()).collect(),
+        record.registers.into_iter().collect()));
+}
+
+/// Writes the program and the initial registers of the device as a JSON object.
+pub fn device_to_json(device: &HandheldDeviceSetup) -> serde_json::Result<String> {
+
+    return serde_json::to_string(&DeviceRecord {
+        registers: device.registers.clone(),
+        commands: develop_command_records(device)
+    });
+}
+
+/// Reads the program from CSV rows of `command,value`, starting from the given registers.
+pub fn device_from_csv(input: &str, registers: Vec<(DeviceRegisterKey, DeviceRegisterValue)>) -> csv::Result<HandheldDeviceSetup> {
+
+    let mut reader = csv::Reader::from_reader(input.as_bytes());
+    let command_lines: Vec<String> = reader.deserialize()
+        .map(|record| record.map(|record: CommandRecord| record.develop_command_line()))
+        .collect::<csv::Result<Vec<String>>>()?;
+
+    return Ok(HandheldDeviceSetup::new(command_lines, registers));
+}
+
+/// Writes the program of the device as CSV rows of `command,value`.
+pub fn device_to_csv(device: &HandheldDeviceSetup) -> csv::Result<String> {
+
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    for record in develop_command_records(device) {
+        writer.serialize(record)?;
+    }
+
+    let output: Vec<u8> = writer.into_inner().map_err(|error| error.into_error())?;
+    return Ok(String::from_utf8(output).unwrap());
+}
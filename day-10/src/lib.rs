@@ -1,5 +1,15 @@
+//! Cathode-ray tube (Advent of Code 2022, day 10).
+//!
+//! The input is a program for the handheld device's CPU, whose `X` register drives both the
+//! signal strength and the position of the sprite drawn on the screen.
+//! The program can also be read from, and written to, JSON or CSV through [`formats`].
+//! Each CPU cycle is recorded as a `tracing` event; with the `trace` feature the binary writes
+//! them as JSON lines through `aoc_common::trace`.
+
 use std::collections::HashMap;
 
+pub mod formats;
+
 // =========================================== TYPE AND STRUCT DEFINITIONS ===========================================
 
 trait Command {
@@ -7,6 +17,8 @@ trait Command {
     fn is_completed(&self) -> bool;
     fn make_iteration(&mut self, registers: &mut HashMap<DeviceRegisterKey, DeviceRegisterValue>);
     fn reset_command(&mut self);
+
+    fn develop_command_line(&self) -> String;
 }
 
 struct CommandAddX {
@@ -26,10 +38,14 @@ struct CommandNoOp {
     current_steps: DeviceTime,
 }
 
-type DeviceTime = u32;
-type DeviceRegisterKey = char;
-type DeviceRegisterValue = i32;
+/// Clock cycle of the device, starting at 1.
+pub type DeviceTime = u32;
+/// Name of a register.
+pub type DeviceRegisterKey = char;
+/// Value held by a register.
+pub type DeviceRegisterValue = i32;
 
+/// The CPU of the handheld device, loaded with a program and its initial registers.
 pub struct HandheldDeviceSetup {
 
     time: DeviceTime,
@@ -82,15 +98,17 @@ impl Command for CommandAddX {
 
     fn make_iteration(&mut self, registers: &mut HashMap<DeviceRegisterKey, DeviceRegisterValue>) {
         
-        self.current_steps = self.current_steps + 1;
+        self.current_steps += 1;
         if self.current_steps == self.steps_taken {
 
             let register_x: &mut DeviceRegisterValue = registers.get_mut(&self.register).unwrap();
-            *register_x = *register_x + self.register_change;
+            *register_x += self.register_change;
         }
     }
 
     fn reset_command(&mut self) { self.current_steps = 0 }
+
+    fn develop_command_line(&self) -> String { format!("addx {}", self.register_change) }
 }
 
 impl CommandNoOp {
@@ -111,14 +129,17 @@ impl Command for CommandNoOp {
 
     fn make_iteration(&mut self, _: &mut HashMap<DeviceRegisterKey, DeviceRegisterValue>) {
 
-        self.current_steps = self.current_steps + 1;
+        self.current_steps += 1;
     }
 
     fn reset_command(&mut self) { self.current_steps = 0 }
+
+    fn develop_command_line(&self) -> String { "noop".to_owned() }
 }
 
 impl HandheldDeviceSetup {
 
+    /// Loads the program in `command_lines` with the given initial register values.
     pub fn new(command_lines: Vec<String>, registers: Vec<(DeviceRegisterKey, DeviceRegisterValue)>) -> HandheldDeviceSetup {
 
         HandheldDeviceSetup {
@@ -138,8 +159,10 @@ impl HandheldDeviceSetup {
         self.time = 1;
     }
 
+    /// Sum of the signal strengths (cycle times `register`) at cycle `initial` and every `step` cycles after.
     pub fn get_signal_strength(&mut self, initial: DeviceTime, step: DeviceTime, register: char) -> DeviceRegisterValue {
 
+        let _span = tracing::debug_span!("get_signal_strength").entered();
         self.reset_machine();
 
         let mut signal_strength: DeviceRegisterValue = 0;
@@ -147,10 +170,12 @@ impl HandheldDeviceSetup {
         for command in self.commands.iter_mut() {
             while ! command.is_completed() {
 
-                self.time = self.time + 1;
+                self.time += 1;
                 command.make_iteration(&mut self.current_registers);
+                tracing::trace!(cycle = self.time, command = %command.develop_command_line(),
+                    register = *self.current_registers.get(&register).unwrap(), "cycle");
 
-                if self.time >= initial && (self.time - initial) % step == 0 {
+                if self.time >= initial && (self.time - initial).is_multiple_of(step) {
                     let register_value: DeviceRegisterValue = *self.current_registers.get(&register).unwrap();
                     signal_strength += self.time as DeviceRegisterValue * register_value;
                 }
@@ -161,8 +186,10 @@ impl HandheldDeviceSetup {
         return signal_strength;
     }
 
+    /// Pixels drawn on the screen, one row of `line_size` pixels at a time.
     pub fn display_screen(&mut self, line_size: usize, sprite_radius: usize, register: char) -> Vec<Vec<char>> {
 
+        let _span = tracing::debug_span!("display_screen").entered();
         self.reset_machine();
 
         let mut lines: Vec<Vec<char>> = Vec::new();
@@ -173,8 +200,10 @@ impl HandheldDeviceSetup {
         for command in self.commands.iter_mut() {
             while ! command.is_completed() {
 
-                self.time = self.time + 1;
+                self.time += 1;
                 command.make_iteration(&mut self.current_registers);
+                tracing::trace!(cycle = self.time, command = %command.develop_command_line(),
+                    register = *self.current_registers.get(&register).unwrap(), "cycle");
 
                 let register_value: DeviceRegisterValue = *self.current_registers.get(&register).unwrap();
 
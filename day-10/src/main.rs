@@ -1,10 +1,14 @@
 mod read;
-mod lib;
+
+use day_10::HandheldDeviceSetup;
 
 fn main() {
 
+    #[cfg(feature = "trace")]
+    aoc_common::trace::install_json_subscriber(&aoc_common::trace::default_trace_file()).unwrap();
+
     let input: Vec<String> = read::read_lines("input.txt".to_owned());
-    let mut device: lib::HandheldDeviceSetup = lib::HandheldDeviceSetup::new(input, vec![('X', 1)]);
+    let mut device: HandheldDeviceSetup = HandheldDeviceSetup::new(input, vec![('X', 1)]);
     // Part 1
 
     let signal_strength: i32 = device.get_signal_strength(20, 40, 'X');
@@ -1,4 +1,4 @@
-#[warn(dead_code)]
+#![warn(dead_code)]
 
 use std::fs::File;
 use std::io::{BufRead, BufReader, Error, ErrorKind};
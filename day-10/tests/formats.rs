@@ -0,0 +1,66 @@
+use day_10::HandheldDeviceSetup;
+use day_10::formats::{device_from_csv, device_from_json, device_to_csv, device_to_json};
+
+const PROGRAM: [&str; 6] = ["noop", "addx 3", "addx -5", "noop", "addx 12", "noop"];
+
+fn develop_device() -> HandheldDeviceSetup {
+    return HandheldDeviceSetup::new(PROGRAM.iter().map(|line| line.to_string()).collect(), vec![('X', 1)]);
+}
+
+#[test]
+fn json_round_trip() {
+
+    let mut device: HandheldDeviceSetup = develop_device();
+    let json: String = device_to_json(&device).unwrap();
+    assert_eq!(json, r#"{"registers":{"X":1},"commands":[{"command":"noop","value":null},{"command":"addx","value":3},{"command":"addx","value":-5},{"command":"noop","value":null},{"command":"addx","value":12},{"command":"noop","value":null}]}"#);
+
+    let mut read_device: HandheldDeviceSetup = device_from_json(&json).unwrap();
+    assert_eq!(device_to_json(&read_device).unwrap(), json);
+    assert_eq!(read_device.get_signal_strength(2, 3, 'X'), device.get_signal_strength(2, 3, 'X'));
+    assert_eq!(read_device.display_screen(5, 1, 'X'), device.display_screen(5, 1, 'X'));
+
+    // The value can be left out of the commands that take none
+    let short: &str = r#"{"registers": {"X": 1}, "commands": [{"command": "noop"}, {"command": "addx", "value": 3}]}"#;
+    assert_eq!(device_to_json(&device_from_json(short).unwrap()).unwrap(),
+        r#"{"registers":{"X":1},"commands":[{"command":"noop","value":null},{"command":"addx","value":3}]}"#);
+}
+
+#[test]
+fn csv_round_trip() {
+
+    let mut device: HandheldDeviceSetup = develop_device();
+    let csv: String = device_to_csv(&device).unwrap();
+    assert_eq!(csv, "command,value\nnoop,\naddx,3\naddx,-5\nnoop,\naddx,12\nnoop,\n");
+
+    let mut read_device: HandheldDeviceSetup = device_from_csv(&csv, vec![('X', 1)]).unwrap();
+    assert_eq!(device_to_csv(&read_device).unwrap(), csv);
+    assert_eq!(read_device.get_signal_strength(2, 3, 'X'), device.get_signal_strength(2, 3, 'X'));
+    assert_eq!(read_device.display_screen(5, 1, 'X'), device.display_screen(5, 1, 'X'));
+}
+
+#[test]
+fn invalid_commands_are_format_errors() {
+
+    for (command, message) in [
+        (r#"{"command": "jmp", "value": 3}"#, "command 'jmp' not recognized"),
+        (r#"{"command": "addx"}"#, "command 'addx' needs a value"),
+        (r#"{"command": "addx", "value": null}"#, "command 'addx' needs a value"),
+        (r#"{"command": "noop", "value": 1}"#, "command 'noop' takes no value, found 1"),
+        (r#"{"command": "addx", "value": "three"}"#, "invalid type")
+    ] {
+        let json: String = format!(r#"{{"registers": {{"X": 1}}, "commands": [{{"command": "noop"}}, {}]}}"#, command);
+        let error: String = device_from_json(&json).err().unwrap().to_string();
+        assert!(error.contains(message), "{}: {}", command, error);
+    }
+
+    for (rows, message) in [
+        ("jmp,3", "command 'jmp' not recognized"),
+        ("addx,", "command 'addx' needs a value"),
+        ("noop,1", "command 'noop' takes no value, found 1"),
+        ("addx,three", "invalid digit")
+    ] {
+        let csv: String = format!("command,value\nnoop,\n{}\n", rows);
+        let error: String = device_from_csv(&csv, vec![('X', 1)]).err().unwrap().to_string();
+        assert!(error.contains(message) && error.contains("line: 3"), "{}: {}", rows, error);
+    }
+}